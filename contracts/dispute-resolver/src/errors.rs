@@ -4,6 +4,7 @@
 //! All errors are exposed as a `ContractError` enum that maps to Soroban
 //! `contracterror` integer values consumable by clients.
 
+use conduit_common::CouncilError;
 use soroban_sdk::contracterror;
 
 /// Contract error codes returned by the Dispute Resolver contract.
@@ -42,4 +43,44 @@ pub enum ContractError {
     NotInitialized = 13,
     /// Thrown when initialization is attempted after the contract is already initialized.
     AlreadyInitialized = 14,
+    /// Thrown when a proposal is executed before enough council members approved it.
+    InsufficientApprovals = 15,
+    /// Thrown when the caller is not a member of the admin council.
+    NotCouncilMember = 16,
+    /// Thrown when the referenced proposal ID does not exist in storage.
+    ProposalNotFound = 17,
+    /// Thrown when a proposal has already been executed or cancelled.
+    ProposalNotPending = 18,
+    /// Thrown when a proposal's approval window has passed.
+    ProposalExpired = 19,
+    /// Thrown when a council member approves the same proposal twice.
+    AlreadyApproved = 20,
+
+    /// Thrown when stored data was written by a newer schema version than this build supports.
+    UnsupportedSchemaVersion = 21,
+
+    /// Thrown when the called function is paused by the council.
+    Paused = 22,
+
+    /// Thrown when no Ed25519 public key is known for a disputing party.
+    PublicKeyNotRegistered = 23,
+
+    /// The queued action's timelock delay has not passed yet.
+    TimelockActive = 24,
+
+    /// The proposal is not waiting in the timelock queue.
+    ProposalNotQueued = 25,
+}
+
+impl From<CouncilError> for ContractError {
+    fn from(err: CouncilError) -> Self {
+        match err {
+            CouncilError::InvalidCouncilConfig => ContractError::InvalidCouncilConfig,
+            CouncilError::NotCouncilMember => ContractError::NotCouncilMember,
+            CouncilError::AlreadyApproved => ContractError::AlreadyApproved,
+            CouncilError::InsufficientApprovals => ContractError::InsufficientApprovals,
+            CouncilError::ProposalNotPending => ContractError::ProposalNotPending,
+            CouncilError::ProposalExpired => ContractError::ProposalExpired,
+        }
+    }
 }
@@ -10,6 +10,7 @@
 //! - Evaluate competing cryptographic proofs deterministically
 //! - Issue a final ruling and trigger appropriate fund recovery
 //! - Penalize the relay node that submitted the invalid transaction
+//! - M-of-N council management through proposals (`propose`, `approve`, `execute`)
 //!
 //! ## Functions
 //! - `raise_dispute(env, initiator, tx_id, proof)` — Submit a new dispute with a relay chain proof
@@ -18,6 +19,10 @@
 //! - `get_dispute(env, dispute_id)` — Fetch dispute details and current status
 //! - `get_ruling(env, dispute_id)` — Fetch the final ruling for a resolved dispute
 //! - `initialize(env, admin, resolution_window)` — One-time setup for the contract
+//! - `propose(env, proposer, action)` — Open a council proposal for a council change
+//! - `approve(env, approver, proposal_id)` — Approve a pending proposal as a council member
+//! - `execute(env, proposal_id)` — Apply a proposal once approvals reach the council threshold
+//! - `cancel(env, proposer, proposal_id)` — Withdraw a pending proposal
 //!
 //! ## See also
 //! - `types.rs` — Data structures (Dispute, DisputeStatus, Ruling, RelayChainProof)
@@ -28,17 +33,124 @@
 
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, String};
+use soroban_sdk::{
+    contract, contractimpl, vec, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Vec,
+};
 
 pub mod errors;
 pub mod storage;
 pub mod types;
 
+use conduit_common::audit::log_page;
+use conduit_common::council::{proposal_window, require_open, timelock_eta};
+use conduit_common::ContractEvents;
+
 use crate::errors::ContractError;
 use crate::types::{
-    AdminCouncil, Dispute, DisputeStatus, OptionalRelayChainProof, RelayChainProof, Ruling,
+    ActionLogEntry, AdminCouncil, Dispute, DisputeStatus, OptionalRelayChainProof, PausableFn,
+    Proposal, ProposalAction, ProposalStatus, RelayChainProof, Ruling,
 };
 
+const EVENTS: ContractEvents = ContractEvents::new("dispute_resolver");
+
+/// Append an applied privileged action to the action log.
+fn log_action(env: &Env, proposal_id: u64, action: ProposalAction, approvals: Vec<Address>) {
+    storage::set_action_log_entry(
+        env,
+        &ActionLogEntry {
+            id: storage::next_action_log_id(env),
+            proposal_id,
+            action,
+            approvals,
+            ledger: env.ledger().sequence() as u64,
+        },
+    );
+}
+
+/// Load a proposal that can still collect approvals or be executed.
+fn load_pending_proposal(env: &Env, proposal_id: u64) -> Result<Proposal, ContractError> {
+    let proposal =
+        storage::get_proposal(env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+    require_open(env, &proposal.status, proposal.expires_at)?;
+    Ok(proposal)
+}
+
+/// The Ed25519 key `party` signed proofs with at ledger `timestamp`.
+///
+/// Keys registered in the relay registry, including rotated-out ones, take
+/// precedence over keys stored in this contract.
+fn signing_key(env: &Env, party: &Address, timestamp: u64) -> Result<BytesN<32>, ContractError> {
+    if let Some(registry) = storage::get_registry(env) {
+        let key = env.invoke_contract::<Option<BytesN<32>>>(
+            &registry,
+            &Symbol::new(env, "get_signing_key_at"),
+            vec![env, party.into_val(env), timestamp.into_val(env)],
+        );
+        if let Some(key) = key {
+            return Ok(key);
+        }
+    }
+    storage::get_public_key(env, party).ok_or(ContractError::PublicKeyNotRegistered)
+}
+
+/// Count `party` in one more unresolved dispute.
+fn open_dispute_for(env: &Env, party: &Address) {
+    let count = storage::get_open_disputes(env, party)
+        .checked_add(1)
+        .expect("open dispute count overflow");
+    storage::set_open_disputes(env, party, count);
+}
+
+/// Release the parties of a resolved dispute.
+fn close_dispute(env: &Env, dispute: &Dispute) {
+    let mut parties = Vec::from_array(env, [dispute.initiator.clone()]);
+    if let Some(respondent) = dispute.respondent.clone() {
+        parties.push_back(respondent);
+    }
+    for party in parties.iter() {
+        // Disputes raised before schema version 2 were never counted.
+        let count = storage::get_open_disputes(env, &party).saturating_sub(1);
+        storage::set_open_disputes(env, &party, count);
+    }
+}
+
+/// With a relay registry set, report a ruling for the parties' reputation.
+///
+/// The report is best effort: the registry may not have granted this contract
+/// `DisputeReporter`, and a failed report must not keep the dispute open.
+fn report_ruling(env: &Env, ruling: &Ruling) {
+    if let Some(registry) = storage::get_registry(env) {
+        let _ = env.try_invoke_contract::<(), soroban_sdk::Error>(
+            &registry,
+            &Symbol::new(env, "record_dispute"),
+            vec![
+                env,
+                env.current_contract_address().into_val(env),
+                ruling.winner.into_val(env),
+                Some(ruling.loser.clone()).into_val(env),
+            ],
+        );
+    }
+}
+
+/// Fail with `ContractError::Paused` while the council has `function` paused.
+fn require_not_paused(env: &Env, function: PausableFn) -> Result<(), ContractError> {
+    if storage::is_paused(env, &function) {
+        return Err(ContractError::Paused);
+    }
+    Ok(())
+}
+
+/// Rewrite stored data from schema `version` to `version + 1`.
+fn migrate_step(_env: &Env, version: u32) -> Result<(), ContractError> {
+    match version {
+        // Disputes cannot be enumerated by party, so disputes raised before
+        // version 2 are simply not counted in `OpenDisputes`.
+        1 => Ok(()),
+        _ => Err(ContractError::UnsupportedSchemaVersion),
+    }
+}
+
 #[contract]
 pub struct DisputeResolverContract;
 
@@ -57,6 +169,7 @@ impl DisputeResolverContract {
     /// The newly assigned `dispute_id` (`u64`) for tracking the dispute.
     ///
     /// # Errors
+    /// - `ContractError::Paused` if the council has paused this function.
     /// - `ContractError::DuplicateDispute` if a dispute for this `tx_id` already exists.
     pub fn raise_dispute(
         env: Env,
@@ -65,6 +178,7 @@ impl DisputeResolverContract {
         proof: RelayChainProof,
     ) -> Result<u64, ContractError> {
         storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::RaiseDispute)?;
         initiator.require_auth();
 
         // Guard against duplicate disputes for the same tx_id.
@@ -94,15 +208,10 @@ impl DisputeResolverContract {
         // Persist the dispute and record the tx → dispute_id mapping.
         storage::set_dispute(&env, dispute_id, &dispute);
         storage::set_dispute_by_tx(&env, &tx_id, dispute_id);
+        open_dispute_for(&env, &initiator);
 
         // Emit event for off-chain indexers.
-        env.events().publish(
-            (
-                soroban_sdk::Symbol::new(&env, "dispute_resolver"),
-                soroban_sdk::Symbol::new(&env, "raise"),
-            ),
-            (initiator, dispute_id, tx_id),
-        );
+        EVENTS.publish(&env, "raise", (initiator, dispute_id, tx_id));
 
         Ok(dispute_id)
     }
@@ -116,6 +225,7 @@ impl DisputeResolverContract {
     /// - `proof`: The respondent's cryptographic relay chain proof.
     ///
     /// # Errors
+    /// - `ContractError::Paused` if the council has paused this function.
     /// - `ContractError::DisputeNotFound` if no dispute exists for this ID.
     /// - `ContractError::NotOpen` if the dispute is not in `Open` status.
     /// - `ContractError::ResolutionWindowExpired` if the response deadline has passed.
@@ -126,6 +236,7 @@ impl DisputeResolverContract {
         proof: RelayChainProof,
     ) -> Result<(), ContractError> {
         storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::Respond)?;
         respondent.require_auth();
 
         let mut dispute =
@@ -146,14 +257,9 @@ impl DisputeResolverContract {
         dispute.status = DisputeStatus::Responded;
 
         storage::set_dispute(&env, dispute_id, &dispute);
+        open_dispute_for(&env, &respondent);
 
-        env.events().publish(
-            (
-                soroban_sdk::Symbol::new(&env, "dispute_resolver"),
-                soroban_sdk::Symbol::new(&env, "respond"),
-            ),
-            (respondent, dispute_id),
-        );
+        EVENTS.publish(&env, "respond", (respondent, dispute_id));
 
         Ok(())
     }
@@ -171,12 +277,21 @@ impl DisputeResolverContract {
     /// The final `Ruling` struct.
     ///
     /// # Errors
+    /// - `ContractError::Paused` if the council has paused this function.
     /// - `ContractError::DisputeNotFound` if no dispute exists for this ID.
     /// - `ContractError::DisputeAlreadyResolved` if the dispute is already resolved.
     /// - `ContractError::ResolutionWindowActive` if the dispute is still Open and the window hasn't expired.
     /// - `ContractError::NotResponded` if the dispute status is unexpected.
+    /// - `ContractError::PublicKeyNotRegistered` if no signing key is known for a party.
+    ///
+    /// With a relay registry set, a ruling where exactly one proof failed
+    /// signature verification, between two distinct parties, is reported to the
+    /// registry's `record_dispute`. This needs the registry's `DisputeReporter`
+    /// role; a failed report is ignored. Default wins and sequence tiebreaks
+    /// are not reported.
     pub fn resolve(env: Env, dispute_id: u64) -> Result<Ruling, ContractError> {
         storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::Resolve)?;
         let mut dispute =
             storage::get_dispute(&env, dispute_id).ok_or(ContractError::DisputeNotFound)?;
 
@@ -206,11 +321,10 @@ impl DisputeResolverContract {
             dispute.status = DisputeStatus::Resolved;
             storage::set_dispute(&env, dispute_id, &dispute);
             storage::set_ruling(&env, dispute_id, &ruling);
-            env.events().publish(
-                (
-                    soroban_sdk::Symbol::new(&env, "dispute_resolver"),
-                    soroban_sdk::Symbol::new(&env, "resolve"),
-                ),
+            close_dispute(&env, &dispute);
+            EVENTS.publish(
+                &env,
+                "resolve",
                 (dispute_id, ruling.winner.clone(), ruling.loser.clone()),
             );
             return Ok(ruling);
@@ -232,13 +346,18 @@ impl DisputeResolverContract {
         };
 
         // ── Ed25519 signature verification ────────────────────────────────────
-        // Retrieve pre-stored Ed25519 public keys for both parties.
-        let initiator_key = storage::get_public_key(&env, &dispute.initiator);
-        let respondent_key = storage::get_public_key(&env, &respondent);
+        // Both proofs predate the dispute, so check each against the key its
+        // signer held when the dispute was raised.
+        let initiator_key = signing_key(&env, &dispute.initiator, dispute.raised_at)?;
+        let respondent_key = signing_key(&env, &respondent, dispute.raised_at)?;
 
         let initiator_valid = Self::verify_proof(&env, &initiator_key, &dispute.initiator_proof);
         let respondent_valid = Self::verify_proof(&env, &respondent_key, &respondent_proof);
 
+        // Only a ruling where one party's proof failed verification says
+        // something about the parties, and only against a distinct respondent.
+        let reported = initiator_valid != respondent_valid && respondent != dispute.initiator;
+
         // Four-case ruling tree:
         let (winner, loser, reason) = match (initiator_valid, respondent_valid) {
             // Only initiator's proof is cryptographically valid.
@@ -293,12 +412,14 @@ impl DisputeResolverContract {
         dispute.status = DisputeStatus::Resolved;
         storage::set_dispute(&env, dispute_id, &dispute);
         storage::set_ruling(&env, dispute_id, &ruling);
+        close_dispute(&env, &dispute);
+        if reported {
+            report_ruling(&env, &ruling);
+        }
 
-        env.events().publish(
-            (
-                soroban_sdk::Symbol::new(&env, "dispute_resolver"),
-                soroban_sdk::Symbol::new(&env, "resolve"),
-            ),
+        EVENTS.publish(
+            &env,
+            "resolve",
             (dispute_id, ruling.winner.clone(), ruling.loser.clone()),
         );
 
@@ -387,15 +508,444 @@ impl DisputeResolverContract {
             return Err(ContractError::InvalidConfig);
         }
 
-        if council.threshold == 0 || council.members.len() < council.threshold {
-            return Err(ContractError::InvalidCouncilConfig);
-        }
+        council.validate()?;
 
         storage::set_admin_council(&env, &council);
         storage::set_resolution_window(&env, resolution_window);
+        storage::set_schema_version(&env, storage::SCHEMA_VERSION);
 
         Ok(())
     }
+
+    /// Add a new member to the admin council.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::AddMember`).
+    ///
+    /// # Errors
+    /// - `ContractError::InvalidCouncilConfig` if `member` is already on the council or the
+    ///   council would exceed `MAX_COUNCIL_MEMBERS`.
+    fn add_member(env: Env, member: Address) -> Result<(), ContractError> {
+        let mut council = storage::get_admin_council(&env);
+        council.add_member(&member)?;
+        storage::set_admin_council(&env, &council);
+
+        EVENTS.member_added(&env, &member, council.members.len());
+
+        Ok(())
+    }
+
+    /// Remove a member from the admin council.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::RemoveMember`).
+    ///
+    /// # Errors
+    /// - `ContractError::NotCouncilMember` if `member` is not on the council.
+    /// - `ContractError::InvalidCouncilConfig` if the threshold would exceed the remaining members.
+    fn remove_member(env: Env, member: Address) -> Result<(), ContractError> {
+        let mut council = storage::get_admin_council(&env);
+        council.remove_member(&member)?;
+        storage::set_admin_council(&env, &council);
+
+        EVENTS.member_removed(&env, &member, council.members.len());
+
+        Ok(())
+    }
+
+    /// Swap a council member for a new address, e.g. after a key loss or compromise.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::ReplaceMember`).
+    ///
+    /// # Errors
+    /// - `ContractError::NotCouncilMember` if `old_member` is not on the council.
+    /// - `ContractError::InvalidCouncilConfig` if `new_member` is already on the council.
+    fn replace_member(
+        env: Env,
+        old_member: Address,
+        new_member: Address,
+    ) -> Result<(), ContractError> {
+        let mut council = storage::get_admin_council(&env);
+        council.replace_member(&old_member, &new_member)?;
+        storage::set_admin_council(&env, &council);
+
+        EVENTS.member_replaced(&env, &old_member, &new_member);
+
+        Ok(())
+    }
+
+    /// Change the number of approvals required to execute a council proposal.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::SetThreshold`).
+    ///
+    /// # Errors
+    /// - `ContractError::InvalidCouncilConfig` if `threshold` is zero or exceeds the member count.
+    fn set_threshold(env: Env, threshold: u32) -> Result<(), ContractError> {
+        let mut council = storage::get_admin_council(&env);
+        council.set_threshold(threshold)?;
+        storage::set_admin_council(&env, &council);
+
+        EVENTS.threshold_changed(&env, threshold);
+
+        Ok(())
+    }
+
+    /// Replace the contract's WASM with a previously uploaded build.
+    ///
+    /// The new code takes effect once this invocation completes; `migrate` must
+    /// then be called to bring storage up to the new build's `SCHEMA_VERSION`.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::Upgrade`).
+    fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), ContractError> {
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        EVENTS.upgraded(&env, &new_wasm_hash, storage::get_schema_version(&env));
+
+        Ok(())
+    }
+
+    /// Set the relay registry that parties' signing keys are read from.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::SetRegistry`).
+    fn set_registry(env: Env, registry: Address) -> Result<(), ContractError> {
+        storage::set_registry(&env, &registry);
+
+        EVENTS.publish(&env, "set_registry", (registry,));
+
+        Ok(())
+    }
+
+    /// Whether `party` raised or responded to a dispute that is not resolved yet.
+    ///
+    /// The relay registry checks this before letting a node exit.
+    pub fn has_open_dispute(env: Env, party: Address) -> bool {
+        storage::extend_instance_ttl(&env);
+        storage::get_open_disputes(&env, &party) > 0
+    }
+
+    /// Relay registry used to look up signing keys, if set.
+    pub fn get_registry(env: Env) -> Option<Address> {
+        storage::extend_instance_ttl(&env);
+        storage::get_registry(&env)
+    }
+
+    /// Immediately halt one or more entrypoints in an emergency.
+    ///
+    /// Any single council member may pause, so a halt does not wait for a
+    /// proposal to collect approvals. Lifting a pause requires an executed
+    /// `ProposalAction::Unpause`. Entrypoints that are not listed keep working,
+    /// so users can still exit while the affected path is halted.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `member`: Council member triggering the pause. Must authorize this call.
+    /// - `functions`: Entrypoints to pause.
+    ///
+    /// # Errors
+    /// - `ContractError::NotCouncilMember` if `member` is not on the admin council.
+    pub fn pause(
+        env: Env,
+        member: Address,
+        functions: Vec<PausableFn>,
+    ) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        member.require_auth();
+        storage::get_admin_council(&env).require_member(&member)?;
+
+        for function in functions.iter() {
+            storage::set_paused(&env, &function, true);
+        }
+
+        EVENTS.paused(&env, &member, functions);
+
+        Ok(())
+    }
+
+    /// Cancel a queued timelocked proposal before it is executed.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::CancelQueued`).
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    /// - `ContractError::ProposalNotQueued` if the proposal is not waiting in the queue.
+    fn cancel_queued(env: Env, proposal_id: u64) -> Result<(), ContractError> {
+        let mut proposal =
+            storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+        if proposal.status != ProposalStatus::Queued {
+            return Err(ContractError::ProposalNotQueued);
+        }
+
+        proposal.status = ProposalStatus::Cancelled;
+        storage::set_proposal(&env, &proposal);
+
+        EVENTS.queued_cancelled(&env, proposal_id);
+
+        Ok(())
+    }
+
+    /// Lift an emergency pause on the listed entrypoints.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::Unpause`).
+    fn unpause(env: Env, functions: Vec<PausableFn>) -> Result<(), ContractError> {
+        for function in functions.iter() {
+            storage::set_paused(&env, &function, false);
+        }
+
+        EVENTS.unpaused(&env, functions);
+
+        Ok(())
+    }
+
+    /// A page of the action log, oldest first, starting at entry `start_id`.
+    ///
+    /// Returns at most `limit` entries, capped at `MAX_LOG_PAGE_SIZE`; an empty
+    /// page means `start_id` is past the end of the log.
+    pub fn get_action_log(env: Env, start_id: u64, limit: u32) -> Vec<ActionLogEntry> {
+        storage::extend_instance_ttl(&env);
+        let mut page = Vec::new(&env);
+        for id in log_page(start_id, limit, storage::get_action_log_count(&env)) {
+            if let Some(entry) = storage::get_action_log_entry(&env, id) {
+                page.push_back(entry);
+            }
+        }
+        page
+    }
+
+    /// Number of entries in the action log.
+    pub fn get_action_log_count(env: Env) -> u64 {
+        storage::extend_instance_ttl(&env);
+        storage::get_action_log_count(&env)
+    }
+
+    /// Whether `function` is currently paused. Intended for off-chain relays to poll.
+    pub fn is_paused(env: Env, function: PausableFn) -> bool {
+        storage::extend_instance_ttl(&env);
+        storage::is_paused(&env, &function)
+    }
+
+    /// Open a council proposal for a privileged action.
+    ///
+    /// The proposer's approval is recorded immediately, so with a threshold of 1
+    /// the proposal can be executed right away.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `proposer`: Council member creating the proposal. Must authorize this call.
+    /// - `action`: The privileged action to apply once the proposal is approved.
+    ///
+    /// # Returns
+    /// The newly assigned `proposal_id`.
+    ///
+    /// # Errors
+    /// - `ContractError::NotCouncilMember` if `proposer` is not on the admin council.
+    pub fn propose(
+        env: Env,
+        proposer: Address,
+        action: ProposalAction,
+    ) -> Result<u64, ContractError> {
+        storage::extend_instance_ttl(&env);
+        proposer.require_auth();
+        storage::get_admin_council(&env).require_member(&proposer)?;
+
+        let (created_at, expires_at) = proposal_window(&env);
+        let proposal = Proposal {
+            id: storage::next_proposal_id(&env),
+            proposer: proposer.clone(),
+            action,
+            approvals: Vec::from_array(&env, [proposer.clone()]),
+            status: ProposalStatus::Pending,
+            created_at,
+            expires_at,
+        };
+        storage::set_proposal(&env, &proposal);
+
+        EVENTS.proposal_created(&env, &proposer, proposal.id, proposal.action);
+
+        Ok(proposal.id)
+    }
+
+    /// Approve a pending council proposal.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `approver`: Council member approving the proposal. Must authorize this call.
+    /// - `proposal_id`: ID of the proposal to approve.
+    ///
+    /// # Errors
+    /// - `ContractError::NotCouncilMember` if `approver` is not on the admin council.
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    /// - `ContractError::ProposalNotPending` if the proposal was executed or cancelled.
+    /// - `ContractError::ProposalExpired` if the approval window has passed.
+    /// - `ContractError::AlreadyApproved` if `approver` already approved this proposal.
+    pub fn approve(env: Env, approver: Address, proposal_id: u64) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        approver.require_auth();
+        let council = storage::get_admin_council(&env);
+        council.require_member(&approver)?;
+
+        let mut proposal = load_pending_proposal(&env, proposal_id)?;
+        council.record_approval(&mut proposal.approvals, &approver)?;
+        storage::set_proposal(&env, &proposal);
+
+        EVENTS.proposal_approved(&env, &approver, proposal_id, proposal.approvals.len());
+
+        Ok(())
+    }
+
+    /// Apply a council proposal once enough distinct members have approved it.
+    ///
+    /// Anyone may call this; only approvals from addresses that are still on the
+    /// council count towards the threshold. Timelocked actions (see
+    /// `ProposalAction::is_timelocked`) take two calls: the first queues the
+    /// proposal with an eta `TIMELOCK_DELAY` ledgers out, and a call at or after
+    /// the eta applies it.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `proposal_id`: ID of the proposal to execute.
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    /// - `ContractError::ProposalNotPending` if the proposal was executed or cancelled.
+    /// - `ContractError::ProposalExpired` if the approval window has passed.
+    /// - `ContractError::InsufficientApprovals` if the council threshold is not met.
+    /// - `ContractError::TimelockActive` if the proposal is queued and its eta has not been reached.
+    /// - Any error returned by the underlying action.
+    pub fn execute(env: Env, proposal_id: u64) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        let mut proposal =
+            storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+
+        if proposal.status == ProposalStatus::Queued {
+            let eta = storage::get_proposal_eta(&env, proposal_id)
+                .ok_or(ContractError::ProposalNotQueued)?;
+            if (env.ledger().sequence() as u64) < eta {
+                return Err(ContractError::TimelockActive);
+            }
+        } else {
+            require_open(&env, &proposal.status, proposal.expires_at)?;
+            storage::get_admin_council(&env).require_threshold(&proposal.approvals)?;
+
+            if proposal.action.is_timelocked() {
+                let eta = timelock_eta(&env);
+                proposal.status = ProposalStatus::Queued;
+                storage::set_proposal(&env, &proposal);
+                storage::set_proposal_eta(&env, proposal_id, eta);
+
+                EVENTS.proposal_queued(&env, proposal_id, eta);
+
+                return Ok(());
+            }
+        }
+
+        proposal.status = ProposalStatus::Executed;
+        storage::set_proposal(&env, &proposal);
+
+        match proposal.action.clone() {
+            ProposalAction::AddMember(member) => Self::add_member(env.clone(), member)?,
+            ProposalAction::RemoveMember(member) => Self::remove_member(env.clone(), member)?,
+            ProposalAction::ReplaceMember(old_member, new_member) => {
+                Self::replace_member(env.clone(), old_member, new_member)?
+            }
+            ProposalAction::SetThreshold(threshold) => Self::set_threshold(env.clone(), threshold)?,
+            ProposalAction::Upgrade(new_wasm_hash) => Self::upgrade(env.clone(), new_wasm_hash)?,
+            ProposalAction::Unpause(functions) => Self::unpause(env.clone(), functions)?,
+            ProposalAction::SetRegistry(registry) => Self::set_registry(env.clone(), registry)?,
+            ProposalAction::CancelQueued(queued_id) => Self::cancel_queued(env.clone(), queued_id)?,
+        }
+
+        log_action(&env, proposal_id, proposal.action, proposal.approvals);
+
+        EVENTS.proposal_executed(&env, proposal_id);
+
+        Ok(())
+    }
+
+    /// Withdraw a pending proposal. Only the original proposer may cancel it.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `proposer`: The address that created the proposal. Must authorize this call.
+    /// - `proposal_id`: ID of the proposal to cancel.
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    /// - `ContractError::ProposalNotPending` if the proposal was executed or cancelled.
+    /// - `ContractError::Unauthorized` if `proposer` did not create the proposal.
+    pub fn cancel(env: Env, proposer: Address, proposal_id: u64) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        proposer.require_auth();
+
+        let mut proposal =
+            storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+        if proposal.status != ProposalStatus::Pending {
+            return Err(ContractError::ProposalNotPending);
+        }
+        if proposal.proposer != proposer {
+            return Err(ContractError::Unauthorized);
+        }
+
+        proposal.status = ProposalStatus::Cancelled;
+        storage::set_proposal(&env, &proposal);
+
+        EVENTS.proposal_cancelled(&env, &proposer, proposal_id);
+
+        Ok(())
+    }
+
+    /// Fetch a council proposal by its ID.
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Result<Proposal, ContractError> {
+        storage::extend_instance_ttl(&env);
+        storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)
+    }
+
+    /// Earliest ledger sequence at which a queued proposal can be executed.
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotQueued` if the proposal was never queued.
+    pub fn get_proposal_eta(env: Env, proposal_id: u64) -> Result<u64, ContractError> {
+        storage::extend_instance_ttl(&env);
+        storage::get_proposal_eta(&env, proposal_id).ok_or(ContractError::ProposalNotQueued)
+    }
+
+    /// Bring stored data up to this build's `SCHEMA_VERSION` after an upgrade.
+    ///
+    /// Permissionless and idempotent: each step rewrites one schema version into
+    /// the next, and calling it on an up-to-date contract changes nothing.
+    ///
+    /// # Returns
+    /// The schema version after migration.
+    ///
+    /// # Errors
+    /// - `ContractError::UnsupportedSchemaVersion` if storage was written by a newer build.
+    pub fn migrate(env: Env) -> Result<u32, ContractError> {
+        storage::extend_instance_ttl(&env);
+        let from = storage::get_schema_version(&env);
+        if from > storage::SCHEMA_VERSION {
+            return Err(ContractError::UnsupportedSchemaVersion);
+        }
+
+        let mut version = from;
+        while version < storage::SCHEMA_VERSION {
+            migrate_step(&env, version)?;
+            version += 1;
+        }
+
+        if version != from {
+            storage::set_schema_version(&env, version);
+            EVENTS.migrated(&env, from, version);
+        }
+
+        Ok(version)
+    }
+
+    /// Storage schema version currently recorded by the contract.
+    pub fn version(env: Env) -> u32 {
+        storage::extend_instance_ttl(&env);
+        storage::get_schema_version(&env)
+    }
 }
 
 #[cfg(test)]
@@ -9,6 +9,7 @@
 //! - `DataKey::DisputeCount` — Monotonically incrementing dispute ID counter
 //! - `DataKey::ResolutionWindow` — Number of ledgers allowed for dispute response
 //! - `DataKey::Admin` — Address authorized to configure the contract
+//! - `DataKey::Proposal(u64)` — Stores a council `Proposal` keyed by proposal_id
 //!
 //! ## Functions to implement
 //! - `get_dispute(env, dispute_id) -> Option<Dispute>` — Load a dispute from storage
@@ -22,18 +23,16 @@
 
 use soroban_sdk::{contracttype, Address, BytesN, Env};
 
-use crate::types::{Dispute, Ruling};
+use crate::types::{ActionLogEntry, Dispute, PausableFn, Proposal, Ruling};
 
-// Bump by ~30 days (assuming ~5 seconds per ledger)
-const LEDGER_BUMP_AMOUNT: u32 = 518_400;
-// Bump if remaining life is less than ~15 days
-const LEDGER_BUMP_THRESHOLD: u32 = 259_200;
+pub use conduit_common::ttl::extend_instance_ttl;
+use conduit_common::ttl::extend_persistent_ttl;
 
-pub fn extend_instance_ttl(env: &Env) {
-    env.storage()
-        .instance()
-        .extend_ttl(LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
-}
+/// Storage layout version written by this build. Bump it together with a new
+/// step in `migrate_step` whenever a persisted layout changes.
+///
+/// - 2: open disputes are counted per party (`OpenDisputes`).
+pub const SCHEMA_VERSION: u32 = 2;
 
 #[contracttype]
 #[derive(Clone)]
@@ -46,15 +45,31 @@ pub enum DataKey {
     TxDispute(BytesN<32>),
     /// Stores the raw 32-byte Ed25519 public key for an Address.
     PublicKey(Address),
+    /// Stores a council `Proposal` keyed by proposal_id.
+    Proposal(u64),
+    /// Monotonically incrementing proposal ID counter.
+    ProposalCount,
+    /// Earliest execution ledger of a queued proposal, keyed by proposal_id.
+    ProposalEta(u64),
+    /// Storage schema version of the persisted data.
+    SchemaVersion,
+    /// Set while the given entrypoint is paused.
+    Paused(PausableFn),
+    /// An applied privileged action, keyed by its position in the action log.
+    ActionLog(u64),
+    /// Number of entries in the action log.
+    ActionLogCount,
+    /// Relay registry that signing keys are read from.
+    Registry,
+    /// Number of unresolved disputes an address is a party to.
+    OpenDisputes(Address),
 }
 
 /// Load a dispute by its ID. Returns None if not found.
 pub fn get_dispute(env: &Env, dispute_id: u64) -> Option<Dispute> {
     let key = DataKey::Dispute(dispute_id);
     if let Some(dispute) = env.storage().persistent().get::<_, Dispute>(&key) {
-        env.storage()
-            .persistent()
-            .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+        extend_persistent_ttl(env, &key);
         Some(dispute)
     } else {
         None
@@ -65,9 +80,7 @@ pub fn get_dispute(env: &Env, dispute_id: u64) -> Option<Dispute> {
 pub fn set_dispute(env: &Env, dispute_id: u64, dispute: &Dispute) {
     let key = DataKey::Dispute(dispute_id);
     env.storage().persistent().set(&key, dispute);
-    env.storage()
-        .persistent()
-        .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+    extend_persistent_ttl(env, &key);
 }
 
 /// Get the current dispute count. Returns 0 if none exist.
@@ -90,9 +103,7 @@ pub fn get_next_dispute_id(env: &Env) -> u64 {
 pub fn get_ruling(env: &Env, dispute_id: u64) -> Option<Ruling> {
     let key = DataKey::Ruling(dispute_id);
     if let Some(ruling) = env.storage().persistent().get::<_, Ruling>(&key) {
-        env.storage()
-            .persistent()
-            .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+        extend_persistent_ttl(env, &key);
         Some(ruling)
     } else {
         None
@@ -103,9 +114,7 @@ pub fn get_ruling(env: &Env, dispute_id: u64) -> Option<Ruling> {
 pub fn set_ruling(env: &Env, dispute_id: u64, ruling: &Ruling) {
     let key = DataKey::Ruling(dispute_id);
     env.storage().persistent().set(&key, ruling);
-    env.storage()
-        .persistent()
-        .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+    extend_persistent_ttl(env, &key);
 }
 
 /// Get the resolution window in ledgers.
@@ -147,9 +156,7 @@ pub fn set_admin_council(env: &Env, council: &crate::types::AdminCouncil) {
 pub fn get_dispute_by_tx(env: &Env, tx_id: &BytesN<32>) -> Option<u64> {
     let key = DataKey::TxDispute(tx_id.clone());
     if let Some(id) = env.storage().persistent().get::<_, u64>(&key) {
-        env.storage()
-            .persistent()
-            .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+        extend_persistent_ttl(env, &key);
         Some(id)
     } else {
         None
@@ -160,35 +167,165 @@ pub fn get_dispute_by_tx(env: &Env, tx_id: &BytesN<32>) -> Option<u64> {
 pub fn set_dispute_by_tx(env: &Env, tx_id: &BytesN<32>, dispute_id: u64) {
     let key = DataKey::TxDispute(tx_id.clone());
     env.storage().persistent().set(&key, &dispute_id);
-    env.storage()
-        .persistent()
-        .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+    extend_persistent_ttl(env, &key);
 }
 
-/// Load the raw 32-byte Ed25519 public key for an address.
-///
-/// Panics if the key has not been registered via `set_public_key`.
-pub fn get_public_key(env: &Env, address: &Address) -> BytesN<32> {
+/// Load the raw 32-byte Ed25519 public key stored locally for an address.
+/// Returns None if no key was stored via `set_public_key`.
+pub fn get_public_key(env: &Env, address: &Address) -> Option<BytesN<32>> {
     let key = DataKey::PublicKey(address.clone());
-    let pk = env
-        .storage()
-        .persistent()
-        .get::<_, BytesN<32>>(&key)
-        .expect("public key not registered for address");
-    env.storage()
-        .persistent()
-        .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+    let pk = env.storage().persistent().get::<_, BytesN<32>>(&key);
+    if pk.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
     pk
 }
 
-/// Register the raw 32-byte Ed25519 public key for an address.
+/// Store the raw 32-byte Ed25519 public key for an address locally.
 ///
-/// Must be called before `raise_dispute` or `respond` so that `resolve`
-/// can perform signature verification.
+/// Keys registered in the relay registry take precedence; a local key is
+/// only used for parties the registry has no key for.
 pub fn set_public_key(env: &Env, address: &Address, public_key: &BytesN<32>) {
     let key = DataKey::PublicKey(address.clone());
     env.storage().persistent().set(&key, public_key);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Load a council proposal by its ID. Returns None if not found.
+pub fn get_proposal(env: &Env, proposal_id: u64) -> Option<Proposal> {
+    let key = DataKey::Proposal(proposal_id);
+    if let Some(proposal) = env.storage().persistent().get::<_, Proposal>(&key) {
+        extend_persistent_ttl(env, &key);
+        Some(proposal)
+    } else {
+        None
+    }
+}
+
+/// Persist a council proposal under its own ID.
+pub fn set_proposal(env: &Env, proposal: &Proposal) {
+    let key = DataKey::Proposal(proposal.id);
+    env.storage().persistent().set(&key, proposal);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn get_proposal_eta(env: &Env, proposal_id: u64) -> Option<u64> {
+    let key = DataKey::ProposalEta(proposal_id);
+    let eta = env.storage().persistent().get::<_, u64>(&key);
+    if eta.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    eta
+}
+
+pub fn set_proposal_eta(env: &Env, proposal_id: u64, eta: u64) {
+    let key = DataKey::ProposalEta(proposal_id);
+    env.storage().persistent().set(&key, &eta);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Increment and return the next available proposal ID.
+pub fn next_proposal_id(env: &Env) -> u64 {
+    let next = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&DataKey::ProposalCount)
+        .unwrap_or(0)
+        .checked_add(1)
+        .expect("proposal count overflow");
+    env.storage().instance().set(&DataKey::ProposalCount, &next);
+    next
+}
+
+/// Number of unresolved disputes `party` raised or responded to.
+pub fn get_open_disputes(env: &Env, party: &Address) -> u32 {
+    let key = DataKey::OpenDisputes(party.clone());
+    let count = env.storage().persistent().get::<_, u32>(&key);
+    if count.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    count.unwrap_or(0)
+}
+
+/// Store the open dispute count of `party`, removing the entry at zero.
+pub fn set_open_disputes(env: &Env, party: &Address, count: u32) {
+    let key = DataKey::OpenDisputes(party.clone());
+    if count == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &count);
+        extend_persistent_ttl(env, &key);
+    }
+}
+
+/// Load the relay registry address, if set.
+pub fn get_registry(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Registry)
+}
+
+/// Set the relay registry address.
+pub fn set_registry(env: &Env, address: &Address) {
+    env.storage().instance().set(&DataKey::Registry, address);
+}
+
+/// Schema version of the stored data. Deployments that predate version
+/// tracking use the version 1 layout.
+pub fn get_schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(1)
+}
+
+pub fn set_schema_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SchemaVersion, &version);
+}
+
+pub fn is_paused(env: &Env, function: &PausableFn) -> bool {
+    env.storage()
+        .instance()
+        .has(&DataKey::Paused(function.clone()))
+}
+
+pub fn set_paused(env: &Env, function: &PausableFn, paused: bool) {
+    let key = DataKey::Paused(function.clone());
+    if paused {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+}
+
+pub fn get_action_log_entry(env: &Env, id: u64) -> Option<ActionLogEntry> {
+    let key = DataKey::ActionLog(id);
+    let entry = env.storage().persistent().get::<_, ActionLogEntry>(&key);
+    if entry.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    entry
+}
+
+pub fn set_action_log_entry(env: &Env, entry: &ActionLogEntry) {
+    let key = DataKey::ActionLog(entry.id);
+    env.storage().persistent().set(&key, entry);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn get_action_log_count(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ActionLogCount)
+        .unwrap_or(0)
+}
+
+pub fn next_action_log_id(env: &Env) -> u64 {
+    let next = get_action_log_count(env)
+        .checked_add(1)
+        .expect("action log count overflow");
     env.storage()
-        .persistent()
-        .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+        .instance()
+        .set(&DataKey::ActionLogCount, &next);
+    next
 }
@@ -6,15 +6,7 @@
 
 use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
 
-/// A multi-signature admin council requiring threshold approvals.
-#[contracttype]
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct AdminCouncil {
-    /// List of council member addresses (max 10)
-    pub members: Vec<Address>,
-    /// Minimum number of members required to authorize a sensitive action
-    pub threshold: u32,
-}
+pub use conduit_common::council::{AdminCouncil, ProposalStatus, MAX_COUNCIL_MEMBERS};
 
 /// Lifecycle status of a dispute.
 #[contracttype]
@@ -91,3 +83,87 @@ pub struct Ruling {
     /// Ledger timestamp when the ruling was issued.
     pub resolved_at: u64,
 }
+
+/// An entrypoint that the council can pause in an emergency.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PausableFn {
+    /// `raise_dispute`: Open a dispute.
+    RaiseDispute,
+    /// `respond`: Submit a counter-proof to a dispute.
+    Respond,
+    /// `resolve`: Resolve a dispute into a ruling.
+    Resolve,
+}
+
+/// A privileged action that can only be performed through an approved council proposal.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProposalAction {
+    /// Add a member to the admin council.
+    AddMember(Address),
+    /// Remove a member from the admin council.
+    RemoveMember(Address),
+    /// Replace an existing council member with a new address.
+    ReplaceMember(Address, Address),
+    /// Change the number of approvals required to execute a proposal.
+    SetThreshold(u32),
+    /// Replace the contract WASM with an uploaded build, identified by its hash.
+    Upgrade(BytesN<32>),
+    /// Lift an emergency pause on the listed entrypoints.
+    Unpause(Vec<PausableFn>),
+    /// Read relay signing keys from this relay registry.
+    SetRegistry(Address),
+    /// Cancel a queued timelocked action before it is executed.
+    CancelQueued(u64),
+}
+
+impl ProposalAction {
+    /// Whether the action waits out `TIMELOCK_DELAY` after approval before it applies.
+    pub fn is_timelocked(&self) -> bool {
+        matches!(
+            self,
+            ProposalAction::Upgrade(_)
+                | ProposalAction::AddMember(_)
+                | ProposalAction::RemoveMember(_)
+                | ProposalAction::ReplaceMember(..)
+                | ProposalAction::SetThreshold(_)
+        )
+    }
+}
+
+/// A council proposal for a privileged action, approved by distinct council members.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proposal {
+    /// Unique monotonic identifier for this proposal.
+    pub id: u64,
+    /// Council member that created the proposal.
+    pub proposer: Address,
+    /// The action applied when the proposal is executed.
+    pub action: ProposalAction,
+    /// Distinct council members that approved the proposal (includes the proposer).
+    pub approvals: Vec<Address>,
+    /// Current lifecycle status of the proposal.
+    pub status: ProposalStatus,
+    /// Ledger sequence when the proposal was created.
+    pub created_at: u64,
+    /// Last ledger sequence at which the proposal can be approved or executed.
+    pub expires_at: u64,
+}
+
+/// A privileged action the contract has applied, kept as an append-only audit record.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ActionLogEntry {
+    /// Position in the action log, starting at 1.
+    pub id: u64,
+    /// Proposal that carried the action.
+    pub proposal_id: u64,
+    /// The applied action, including its arguments.
+    pub action: ProposalAction,
+    /// Addresses that approved the proposal, proposer included.
+    pub approvals: Vec<Address>,
+    /// Ledger sequence at which the action was applied.
+    pub ledger: u64,
+}
@@ -1,13 +1,15 @@
 #![cfg(test)]
 
+use conduit_common::council::TIMELOCK_DELAY;
 use dispute_resolver::{
+    errors::ContractError,
     storage,
-    types::{AdminCouncil, RelayChainProof},
+    types::{AdminCouncil, PausableFn, ProposalAction, ProposalStatus, RelayChainProof},
     DisputeResolverContract, DisputeResolverContractClient,
 };
 use soroban_sdk::{
     testutils::{Address as _, Ledger},
-    Address, BytesN, Env,
+    Address, BytesN, Env, Vec,
 };
 
 fn setup<'a>() -> (Env, DisputeResolverContractClient<'a>, Address) {
@@ -441,3 +443,316 @@ fn test_get_ruling_not_yet_resolved() {
     // Attempt to get ruling before resolution
     client.get_ruling(&dispute_id);
 }
+
+#[test]
+fn test_replace_council_member_via_proposal() {
+    let (env, client, admin) = setup();
+    let replacement = Address::generate(&env);
+
+    let proposal_id = client.propose(
+        &admin,
+        &ProposalAction::ReplaceMember(admin.clone(), replacement.clone()),
+    );
+    client.execute(&proposal_id);
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        ProposalStatus::Queued
+    );
+    assert_eq!(
+        client.try_execute(&proposal_id),
+        Err(Ok(ContractError::TimelockActive))
+    );
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    client.execute(&proposal_id);
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        ProposalStatus::Executed
+    );
+
+    let res = client.try_propose(&admin, &ProposalAction::SetThreshold(1));
+    assert_eq!(res, Err(Ok(ContractError::NotCouncilMember)));
+
+    let proposal_id = client.propose(&replacement, &ProposalAction::SetThreshold(1));
+    client.execute(&proposal_id);
+}
+
+#[test]
+fn test_upgrade_is_queued_and_can_be_cancelled() {
+    let (env, client, admin) = setup();
+    let upgrade = ProposalAction::Upgrade(BytesN::from_array(&env, &[7u8; 32]));
+
+    let proposal_id = client.propose(&admin, &upgrade);
+    client.execute(&proposal_id);
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        ProposalStatus::Queued
+    );
+    assert_eq!(
+        client.get_proposal_eta(&proposal_id),
+        env.ledger().sequence() as u64 + TIMELOCK_DELAY as u64
+    );
+    assert_eq!(
+        client.try_execute(&proposal_id),
+        Err(Ok(ContractError::TimelockActive))
+    );
+
+    let cancel_id = client.propose(&admin, &ProposalAction::CancelQueued(proposal_id));
+    client.execute(&cancel_id);
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        ProposalStatus::Cancelled
+    );
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    assert_eq!(
+        client.try_execute(&proposal_id),
+        Err(Ok(ContractError::ProposalNotPending))
+    );
+}
+
+#[test]
+fn test_version_and_migrate() {
+    let (env, client, _) = setup();
+    assert_eq!(client.version(), storage::SCHEMA_VERSION);
+    assert_eq!(client.migrate(), storage::SCHEMA_VERSION);
+
+    env.as_contract(&client.address, || {
+        storage::set_schema_version(&env, storage::SCHEMA_VERSION + 1)
+    });
+    assert_eq!(
+        client.try_migrate(),
+        Err(Ok(ContractError::UnsupportedSchemaVersion))
+    );
+}
+
+#[test]
+fn test_pause_raise_dispute() {
+    let (env, client, admin) = setup();
+    let (initiator, _, init_sk, _) = setup_disputants(&env, &client);
+    let tx_id = BytesN::from_array(&env, &[9u8; 32]);
+    let init_proof = create_proof(&env, &init_sk, &[8u8; 32], 10);
+
+    let halted = soroban_sdk::Vec::from_array(&env, [PausableFn::RaiseDispute]);
+    client.pause(&admin, &halted);
+    assert!(client.is_paused(&PausableFn::RaiseDispute));
+    assert_eq!(
+        client.try_raise_dispute(&initiator, &tx_id, &init_proof),
+        Err(Ok(ContractError::Paused))
+    );
+
+    let proposal_id = client.propose(&admin, &ProposalAction::Unpause(halted));
+    client.execute(&proposal_id);
+    assert_eq!(client.raise_dispute(&initiator, &tx_id, &init_proof), 1);
+}
+
+/// Deploy a relay registry and point the resolver at it. Returns the registry
+/// and its council admin.
+fn setup_registry<'a>(
+    env: &Env,
+    client: &DisputeResolverContractClient,
+    admin: &Address,
+) -> (relay_registry::RelayRegistryContractClient<'a>, Address) {
+    let registry_id = env.register(relay_registry::RelayRegistryContract, ());
+    let registry = relay_registry::RelayRegistryContractClient::new(env, &registry_id);
+    let registry_admin = Address::generate(env);
+    let registry_council = AdminCouncil {
+        members: soroban_sdk::Vec::from_array(env, [registry_admin.clone()]),
+        threshold: 1,
+    };
+    let stake_token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    registry.initialize(
+        &registry_council,
+        &stake_token,
+        &100,
+        &10,
+        &relay_registry::types::LockUnit::Seconds,
+    );
+
+    let proposal_id = client.propose(admin, &ProposalAction::SetRegistry(registry_id.clone()));
+    client.execute(&proposal_id);
+    assert_eq!(client.get_registry(), Some(registry_id));
+    (registry, registry_admin)
+}
+
+fn registry_metadata(env: &Env) -> relay_registry::types::NodeMetadata {
+    relay_registry::types::NodeMetadata {
+        region: soroban_sdk::String::from_str(env, "us-east"),
+        capacity: 1000,
+        uptime_commitment: 99,
+        endpoint: None,
+        software_version: None,
+        transports: Vec::new(env),
+        contact_hash: None,
+    }
+}
+
+#[test]
+fn test_resolve_uses_registry_key_active_when_raised() {
+    let (env, client, admin) = setup();
+    let (registry, registry_admin) = setup_registry(&env, &client, &admin);
+    let grant = registry.propose(
+        &registry_admin,
+        &relay_registry::types::ProposalAction::GrantRole(
+            relay_registry::types::Role::DisputeReporter,
+            client.address.clone(),
+        ),
+    );
+    registry.execute(&grant);
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    registry.execute(&grant);
+
+    let metadata = registry_metadata(&env);
+    let register_key = |node: &Address, sk: &ed25519_dalek::SigningKey, rotate: bool| {
+        use ed25519_dalek::Signer;
+        let public_key = BytesN::from_array(&env, &sk.verifying_key().to_bytes());
+        let digest = registry.get_key_proof_digest(node, &public_key);
+        let signature = BytesN::from_array(&env, &sk.sign(&digest.to_array()).to_bytes());
+        if rotate {
+            registry.rotate_signing_key(node, &public_key, &signature);
+        } else {
+            registry.register_signing_key(node, &public_key, &signature);
+        }
+    };
+
+    let initiator = Address::generate(&env);
+    let respondent = Address::generate(&env);
+    let init_sk = ed25519_dalek::SigningKey::from_bytes(&[1u8; 32]);
+    let resp_sk = ed25519_dalek::SigningKey::from_bytes(&[2u8; 32]);
+    for (node, sk) in [(&initiator, &init_sk), (&respondent, &resp_sk)] {
+        registry.register(node, &metadata);
+        register_key(node, sk, false);
+    }
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    let tx_id = BytesN::from_array(&env, &[9u8; 32]);
+    let chain_hash = [8u8; 32];
+    let init_proof = create_proof(&env, &init_sk, &chain_hash, 10);
+    let dispute_id = client.raise_dispute(&initiator, &tx_id, &init_proof);
+    let resp_proof = create_proof(&env, &resp_sk, &chain_hash, 15);
+    client.respond(&respondent, &dispute_id, &resp_proof);
+
+    // Rotating after the dispute was raised does not invalidate the earlier proof.
+    env.ledger().with_mut(|l| l.timestamp = 2_000);
+    register_key(
+        &initiator,
+        &ed25519_dalek::SigningKey::from_bytes(&[3u8; 32]),
+        true,
+    );
+
+    let ruling = client.resolve(&dispute_id);
+    assert_eq!(ruling.winner, initiator);
+    assert_eq!(ruling.loser, respondent);
+
+    // Both proofs verified, so the sequence tiebreak decided the ruling, which
+    // says nothing about either node and is not reported.
+    assert_eq!(registry.get_reputation(&initiator), None);
+    assert_eq!(registry.get_reputation(&respondent), None);
+}
+
+#[test]
+fn test_key_closed_by_transfer_no_longer_verifies() {
+    use ed25519_dalek::Signer;
+    let (env, client, admin) = setup();
+    let (registry, _) = setup_registry(&env, &client, &admin);
+    let metadata = registry_metadata(&env);
+
+    let initiator = Address::generate(&env);
+    let respondent = Address::generate(&env);
+    let init_sk = ed25519_dalek::SigningKey::from_bytes(&[1u8; 32]);
+    let resp_sk = ed25519_dalek::SigningKey::from_bytes(&[2u8; 32]);
+    for (node, sk) in [(&initiator, &init_sk), (&respondent, &resp_sk)] {
+        registry.register(node, &metadata);
+        let public_key = BytesN::from_array(&env, &sk.verifying_key().to_bytes());
+        let digest = registry.get_key_proof_digest(node, &public_key);
+        let signature = BytesN::from_array(&env, &sk.sign(&digest.to_array()).to_bytes());
+        registry.register_signing_key(node, &public_key, &signature);
+    }
+
+    // The initiator's node moves to a new operator, closing its old key.
+    env.ledger().with_mut(|l| {
+        l.timestamp = 1_000;
+        l.sequence_number += 1;
+    });
+    let buyer = Address::generate(&env);
+    registry.propose_transfer(&initiator, &buyer);
+    registry.accept_transfer(&initiator, &buyer);
+    assert!(registry.get_signing_key_at(&initiator, &999).is_some());
+    assert_eq!(registry.get_signing_key_at(&initiator, &1_000), None);
+
+    // A proof signed with the closed key after the transfer cannot be checked.
+    env.ledger().with_mut(|l| l.timestamp = 2_000);
+    let tx_id = BytesN::from_array(&env, &[9u8; 32]);
+    let chain_hash = [8u8; 32];
+    let init_proof = create_proof(&env, &init_sk, &chain_hash, 10);
+    let dispute_id = client.raise_dispute(&initiator, &tx_id, &init_proof);
+    let resp_proof = create_proof(&env, &resp_sk, &chain_hash, 15);
+    client.respond(&respondent, &dispute_id, &resp_proof);
+    assert_eq!(
+        client.try_resolve(&dispute_id),
+        Err(Ok(ContractError::PublicKeyNotRegistered))
+    );
+}
+
+#[test]
+fn test_default_win_is_not_reported() {
+    let (env, client, admin) = setup();
+    // The registry never granted the resolver `DisputeReporter`.
+    let (registry, _) = setup_registry(&env, &client, &admin);
+    let (initiator, _, init_sk, _) = setup_disputants(&env, &client);
+    registry.register(&initiator, &registry_metadata(&env));
+
+    let tx_id = BytesN::from_array(&env, &[9u8; 32]);
+    let init_proof = create_proof(&env, &init_sk, &[8u8; 32], 10);
+    let dispute_id = client.raise_dispute(&initiator, &tx_id, &init_proof);
+    assert!(client.has_open_dispute(&initiator));
+
+    // A default win earns the initiator nothing and releases it.
+    env.ledger().with_mut(|l| l.sequence_number += 101);
+    assert_eq!(client.resolve(&dispute_id).winner, initiator);
+    assert!(!client.has_open_dispute(&initiator));
+    assert_eq!(registry.get_reputation(&initiator), None);
+}
+
+#[test]
+fn test_resolve_without_known_key_fails() {
+    let (env, client, _) = setup();
+    let (initiator, _, init_sk, resp_sk) = setup_disputants(&env, &client);
+    let stranger = Address::generate(&env);
+
+    let tx_id = BytesN::from_array(&env, &[9u8; 32]);
+    let chain_hash = [8u8; 32];
+    let init_proof = create_proof(&env, &init_sk, &chain_hash, 10);
+    let dispute_id = client.raise_dispute(&initiator, &tx_id, &init_proof);
+    let resp_proof = create_proof(&env, &resp_sk, &chain_hash, 15);
+    client.respond(&stranger, &dispute_id, &resp_proof);
+
+    assert_eq!(
+        client.try_resolve(&dispute_id),
+        Err(Ok(ContractError::PublicKeyNotRegistered))
+    );
+}
+
+#[test]
+fn test_open_disputes_tracked_until_resolved() {
+    let (env, client, _) = setup();
+    let (initiator, respondent, init_sk, resp_sk) = setup_disputants(&env, &client);
+
+    let tx_id = BytesN::from_array(&env, &[9u8; 32]);
+    let chain_hash = [8u8; 32];
+    let init_proof = create_proof(&env, &init_sk, &chain_hash, 10);
+    let dispute_id = client.raise_dispute(&initiator, &tx_id, &init_proof);
+    assert!(client.has_open_dispute(&initiator));
+    assert!(!client.has_open_dispute(&respondent));
+
+    let resp_proof = create_proof(&env, &resp_sk, &chain_hash, 15);
+    client.respond(&respondent, &dispute_id, &resp_proof);
+    assert!(client.has_open_dispute(&respondent));
+
+    client.resolve(&dispute_id);
+    assert!(!client.has_open_dispute(&initiator));
+    assert!(!client.has_open_dispute(&respondent));
+}
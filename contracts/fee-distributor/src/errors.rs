@@ -4,6 +4,7 @@
 //! All errors are exposed as a `ContractError` enum that maps to Soroban
 //! `contracterror` integer values consumable by clients.
 
+use conduit_common::{CouncilError, RoleError};
 use soroban_sdk::contracterror;
 
 /// All error codes returned by the Fee Distributor contract.
@@ -43,4 +44,71 @@ pub enum ContractError {
 
     /// Council config is invalid (threshold > members, etc.).
     InvalidCouncilConfig = 11,
+
+    /// Caller is not a member of the admin council.
+    NotCouncilMember = 12,
+
+    /// The referenced proposal does not exist.
+    ProposalNotFound = 13,
+
+    /// The proposal has already been executed or cancelled.
+    ProposalNotPending = 14,
+
+    /// The proposal's approval window has passed.
+    ProposalExpired = 15,
+
+    /// The council member has already approved this proposal.
+    AlreadyApproved = 16,
+
+    /// Stored data was written by a newer schema version than this build supports.
+    UnsupportedSchemaVersion = 17,
+
+    /// The called function is paused by the council.
+    Paused = 18,
+
+    /// The queued action's timelock delay has not passed yet.
+    TimelockActive = 19,
+
+    /// The proposal is not waiting in the timelock queue.
+    ProposalNotQueued = 20,
+
+    /// The address already holds the role.
+    RoleAlreadyGranted = 21,
+
+    /// The address does not hold the role.
+    RoleNotHeld = 22,
+
+    /// The relay was not among the relays the registry assigned to the batch.
+    RelayNotAssigned = 23,
+
+    /// The registry does not forward the relay to a new address.
+    NotForwarded = 24,
+
+    /// Assignments are checked and the batch was never opened with `open_batch`.
+    BatchNotOpened = 25,
+
+    /// The batch has already been opened.
+    BatchAlreadyOpened = 26,
+}
+
+impl From<CouncilError> for ContractError {
+    fn from(err: CouncilError) -> Self {
+        match err {
+            CouncilError::InvalidCouncilConfig => ContractError::InvalidCouncilConfig,
+            CouncilError::NotCouncilMember => ContractError::NotCouncilMember,
+            CouncilError::AlreadyApproved => ContractError::AlreadyApproved,
+            CouncilError::InsufficientApprovals => ContractError::InsufficientApprovals,
+            CouncilError::ProposalNotPending => ContractError::ProposalNotPending,
+            CouncilError::ProposalExpired => ContractError::ProposalExpired,
+        }
+    }
+}
+
+impl From<RoleError> for ContractError {
+    fn from(err: RoleError) -> Self {
+        match err {
+            RoleError::RoleAlreadyGranted => ContractError::RoleAlreadyGranted,
+            RoleError::RoleNotHeld => ContractError::RoleNotHeld,
+        }
+    }
 }
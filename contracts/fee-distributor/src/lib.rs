@@ -10,13 +10,21 @@
 //! - Allocate a protocol treasury share from collected fees
 //! - Track cumulative fee earnings per relay node
 //! - Handle delayed fee claims for relay nodes
+//! - M-of-N council approvals for fee configuration changes (`propose`, `approve`, `execute`)
 //!
 //! ## Functions to implement
+//! - `open_batch(env, opener, batch_id)` — Fix the relay assignment of a batch before it settles
 //! - `distribute(env, relay_address, batch_id)` — Distribute fee for a settled transaction batch
 //! - `calculate_fee(env, batch_size)` — Calculate the fee for a given batch of transactions
-//! - `claim(env, relay_address)` — Claim accumulated, unclaimed fees for a relay node
+//! - `claim(env, relay_address)` — Claim accumulated, unclaimed fees for a relay node or delegator
 //! - `get_earnings(env, relay_address)` — View total lifetime earnings for a relay node
+//! - `migrate_earnings(env, relay_address)` — Move earnings to the address a relay transferred to
 //! - `set_fee_rate(env, rate)` — Update the protocol fee rate (governance-only)
+//! - `get_assigned_relays(env, batch_id)` — Relays the registry assigned to a batch when it was opened
+//! - `propose(env, proposer, action)` — Open a council proposal for a privileged action
+//! - `approve(env, approver, proposal_id)` — Approve a pending proposal as a council member
+//! - `execute(env, proposal_id)` — Apply a proposal once approvals reach the council threshold
+//! - `cancel(env, proposer, proposal_id)` — Withdraw a pending proposal
 //!
 //! ## See also
 //! - `types.rs` — Data structures (FeeEntry, EarningsRecord, FeeConfig)
@@ -27,7 +35,9 @@
 
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, token, Address, Env, IntoVal};
+use soroban_sdk::{
+    contract, contractimpl, token, vec, xdr::ToXdr, Address, BytesN, Env, IntoVal, Symbol, Vec,
+};
 
 pub mod errors;
 pub mod storage;
@@ -36,22 +46,91 @@ pub mod types;
 #[cfg(test)]
 mod test;
 
+use conduit_common::audit::log_page;
+use conduit_common::council::{proposal_window, require_open, timelock_eta};
+use conduit_common::roles;
+use conduit_common::ContractEvents;
+
 use crate::errors::ContractError;
-use crate::types::AdminCouncil;
+use crate::types::{
+    ActionLogEntry, AdminCouncil, PausableFn, Proposal, ProposalAction, ProposalStatus, Role,
+};
 
-fn require_council_auth(_env: &Env, council: &AdminCouncil) {
-    let mut authorized = 0u32;
-    for member in council.members.iter() {
-        member.require_auth();
-        authorized += 1;
-        if authorized >= council.threshold {
-            break;
-        }
-    }
+const EVENTS: ContractEvents = ContractEvents::new("fee_distributor");
+
+/// Relays `registry` assigns to a batch: a `select_relays` sample of `size`
+/// nodes seeded with the seed `open_batch` fixed for it, drawn and stored by
+/// `open_batch`.
+fn assigned_relays(env: &Env, registry: &Address, seed: BytesN<32>, size: u32) -> Vec<Address> {
+    env.invoke_contract(
+        registry,
+        &Symbol::new(env, "select_relays"),
+        vec![
+            env,
+            Some(seed).into_val(env),
+            size.into_val(env),
+            Option::<soroban_sdk::String>::None.into_val(env),
+        ],
+    )
+}
+
+/// Append an applied privileged action to the action log.
+fn log_action(env: &Env, proposal_id: u64, action: ProposalAction, approvals: Vec<Address>) {
+    storage::set_action_log_entry(
+        env,
+        &ActionLogEntry {
+            id: storage::next_action_log_id(env),
+            proposal_id,
+            action,
+            approvals,
+            ledger: env.ledger().sequence() as u64,
+        },
+    );
+}
+
+/// Load a proposal that can still collect approvals or be executed.
+fn load_pending_proposal(env: &Env, proposal_id: u64) -> Result<Proposal, ContractError> {
+    let proposal =
+        storage::get_proposal(env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+    require_open(env, &proposal.status, proposal.expires_at)?;
+    Ok(proposal)
+}
 
-    if authorized < council.threshold {
-        panic!("Insufficient approvals");
+/// Whether `account` holds the role that lets it perform `action` without council approvals.
+fn has_role_for(env: &Env, action: &ProposalAction, account: &Address) -> bool {
+    action
+        .required_role()
+        .is_some_and(|role| storage::has_role(env, &role, account))
+}
+
+/// Add `amount` to the unclaimed earnings of `address`.
+fn credit_earnings(env: &Env, address: &Address, amount: i128) -> Result<(), ContractError> {
+    let mut record = storage::get_earnings(env, address);
+    record.total_earned = record
+        .total_earned
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    record.unclaimed = record
+        .unclaimed
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    storage::set_earnings(env, address, &record);
+    Ok(())
+}
+
+/// Fail with `ContractError::Paused` while the council has `function` paused.
+fn require_not_paused(env: &Env, function: PausableFn) -> Result<(), ContractError> {
+    if storage::is_paused(env, &function) {
+        return Err(ContractError::Paused);
     }
+    Ok(())
+}
+
+/// Rewrite stored data from schema `version` to `version + 1`.
+fn migrate_step(_env: &Env, _version: u32) -> Result<(), ContractError> {
+    // No layout changes yet. Each schema bump adds the step for the version it
+    // replaces here.
+    Err(ContractError::UnsupportedSchemaVersion)
 }
 
 #[contract]
@@ -94,9 +173,7 @@ impl FeeDistributorContract {
             return Err(ContractError::InvalidFeeRate);
         }
 
-        if council.threshold == 0 || council.members.len() < council.threshold {
-            return Err(ContractError::InvalidCouncilConfig);
-        }
+        council.validate()?;
 
         // Persist config
         let config = crate::types::FeeConfig {
@@ -107,6 +184,7 @@ impl FeeDistributorContract {
         storage::set_fee_config(&env, &config);
         storage::set_treasury_address(&env, &treasury);
         storage::set_token_address(&env, &token);
+        storage::set_schema_version(&env, storage::SCHEMA_VERSION);
 
         Ok(())
     }
@@ -149,21 +227,84 @@ impl FeeDistributorContract {
         Ok(fee)
     }
 
+    /// Open `batch_id` for settlement, fixing the relays it is assigned to.
+    ///
+    /// The seed hashes the batch ID with output of the Soroban PRNG, which is
+    /// derived from ledger data, so neither the opener nor a relay can pick
+    /// which relays a batch is assigned to. With a registry and an assignment
+    /// size set, the registry's `select_relays` draws the relays from that seed
+    /// here and they are stored with the batch, so stake moved once the seed
+    /// is public cannot change them. While an assignment size is set,
+    /// `distribute` only pays opened batches, and only to their stored relays.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment.
+    /// - `opener`: Holder of `Role::BatchOpener`. Must authorize this call.
+    /// - `batch_id`: Identifier of the batch about to be relayed.
+    ///
+    /// # Errors
+    /// - `ContractError::Paused` if the council has paused this function.
+    /// - `ContractError::Unauthorized` if `opener` does not hold `Role::BatchOpener`.
+    /// - `ContractError::BatchAlreadyDistributed` if `batch_id` has already been processed.
+    /// - `ContractError::BatchAlreadyOpened` if `batch_id` has already been opened.
+    pub fn open_batch(env: Env, opener: Address, batch_id: u64) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::OpenBatch)?;
+        opener.require_auth();
+        if !storage::has_role(&env, &Role::BatchOpener, &opener) {
+            return Err(ContractError::Unauthorized);
+        }
+        if storage::get_fee_entry(&env, batch_id).is_some() {
+            return Err(ContractError::BatchAlreadyDistributed);
+        }
+        if storage::get_batch_seed(&env, batch_id).is_some() {
+            return Err(ContractError::BatchAlreadyOpened);
+        }
+
+        let mut material = batch_id.to_xdr(&env);
+        material.append(&env.prng().gen::<BytesN<32>>().into());
+        let seed: BytesN<32> = env.crypto().sha256(&material).into();
+        storage::set_batch_seed(&env, batch_id, &seed);
+
+        let assignment_size = storage::get_assignment_size(&env);
+        if let Some(registry) = storage::get_registry(&env).filter(|_| assignment_size > 0) {
+            let relays = assigned_relays(&env, &registry, seed.clone(), assignment_size);
+            storage::set_batch_assignment(&env, batch_id, &relays);
+        }
+
+        EVENTS.publish(&env, "batch_opened", (batch_id, seed));
+
+        Ok(())
+    }
+
     /// Distribute the fee for a successfully settled transaction batch.
     ///
     /// This function calculates the fee, credits the relay node's earnings,
     /// allocates the protocol treasury share, and permanently records the
     /// distribution event. The treasury share is automatically transferred
-    /// to the treasury contract via cross-contract call.
+    /// to the treasury contract via cross-contract call. Once a relay registry
+    /// is set, the relay's payout is split with its delegators according to
+    /// the registry's `split_rewards`, and each share is credited to its
+    /// recipient's earnings, claimable with `claim`. A batch that paid a fee is
+    /// also reported to the registry's `record_batch` for the relay's
+    /// reputation, which needs this contract to hold the registry's
+    /// `BatchReporter` role; a failed report is ignored so it never holds up the
+    /// payout. With an assignment size set, the batch must have been opened
+    /// with `open_batch` and the relay must be one of the relays assigned to it
+    /// there, as returned by `get_assigned_relays`. A batch opened while no
+    /// assignment size was set has no assigned relays.
     ///
     /// # Parameters
     /// - `env`: Soroban environment.
-    /// - `relay_address`: Address of the relay node that settled the batch.
+    /// - `relay_address`: Address of the relay node that settled the batch. Must authorize this call.
     /// - `batch_id`: Unique identifier of the settled transaction batch.
     /// - `batch_size`: Number of transactions in the batch.
     ///
     /// # Errors
+    /// - `ContractError::Paused` if the council has paused this function.
     /// - `ContractError::BatchAlreadyDistributed` if `batch_id` has already been processed.
+    /// - `ContractError::BatchNotOpened` if assignments are checked and the batch was never opened.
+    /// - `ContractError::RelayNotAssigned` if assignments are checked and the relay was not assigned the batch.
     /// - `ContractError::InvalidBatchSize` if `batch_size` is zero.
     /// - `ContractError::Overflow` if fee/split calculation overflows.
     /// - `ContractError::TreasuryTransferFailed` if the treasury deposit fails.
@@ -174,10 +315,22 @@ impl FeeDistributorContract {
         batch_size: u32,
     ) -> Result<(), ContractError> {
         storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::Distribute)?;
+        relay_address.require_auth();
         if storage::get_fee_entry(&env, batch_id).is_some() {
             return Err(ContractError::BatchAlreadyDistributed);
         }
 
+        let assignment_size = storage::get_assignment_size(&env);
+        if storage::get_registry(&env).is_some() && assignment_size > 0 {
+            if storage::get_batch_seed(&env, batch_id).is_none() {
+                return Err(ContractError::BatchNotOpened);
+            }
+            if !storage::get_batch_assignment(&env, batch_id).contains(&relay_address) {
+                return Err(ContractError::RelayNotAssigned);
+            }
+        }
+
         let fee = Self::calculate_fee(env.clone(), batch_size)?;
         let config = storage::get_fee_config(&env);
 
@@ -191,17 +344,24 @@ impl FeeDistributorContract {
             .checked_sub(treasury_share)
             .ok_or(ContractError::Overflow)?;
 
-        let mut record = storage::get_earnings(&env, &relay_address);
-        record.total_earned = record
-            .total_earned
-            .checked_add(relay_payout)
-            .ok_or(ContractError::Overflow)?;
-        record.unclaimed = record
-            .unclaimed
-            .checked_add(relay_payout)
-            .ok_or(ContractError::Overflow)?;
-
-        storage::set_earnings(&env, &relay_address, &record);
+        // With a registry set, the relay's delegators share in the payout.
+        match storage::get_registry(&env) {
+            Some(registry) if relay_payout > 0 => {
+                let shares = env.invoke_contract::<Vec<(Address, i128)>>(
+                    &registry,
+                    &Symbol::new(&env, "split_rewards"),
+                    vec![
+                        &env,
+                        relay_address.into_val(&env),
+                        relay_payout.into_val(&env),
+                    ],
+                );
+                for (address, amount) in shares.iter() {
+                    credit_earnings(&env, &address, amount)?;
+                }
+            }
+            _ => credit_earnings(&env, &relay_address, relay_payout)?,
+        }
 
         let entry = crate::types::FeeEntry {
             batch_id,
@@ -212,6 +372,21 @@ impl FeeDistributorContract {
         };
         storage::set_fee_entry(&env, batch_id, &entry);
 
+        // A settled batch that paid a fee counts towards the relay's reputation.
+        // The report is best effort: the registry may not have granted this
+        // contract `BatchReporter`, or may not know the relay.
+        if let Some(registry) = storage::get_registry(&env).filter(|_| fee > 0) {
+            let _ = env.try_invoke_contract::<(), soroban_sdk::Error>(
+                &registry,
+                &Symbol::new(&env, "record_batch"),
+                vec![
+                    &env,
+                    env.current_contract_address().into_val(&env),
+                    relay_address.into_val(&env),
+                ],
+            );
+        }
+
         // Transfer treasury share to treasury contract
         if treasury_share > 0 {
             let treasury_addr = storage::get_treasury_address(&env);
@@ -236,11 +411,9 @@ impl FeeDistributorContract {
             );
         }
 
-        env.events().publish(
-            (
-                soroban_sdk::Symbol::new(&env, "fee_distributor"),
-                soroban_sdk::Symbol::new(&env, "distribute"),
-            ),
+        EVENTS.publish(
+            &env,
+            "distribute",
             (
                 relay_address.clone(),
                 batch_id,
@@ -266,10 +439,12 @@ impl FeeDistributorContract {
     /// The total amount of fees claimed in this transaction.
     ///
     /// # Errors
+    /// - `ContractError::Paused` if the council has paused this function.
     /// - `ContractError::NothingToClaim` if the relay node has no unclaimed earnings.
     /// - `ContractError::Overflow` if the arithmetic for updating `total_claimed` overflows.
     pub fn claim(env: Env, relay_address: Address) -> Result<i128, ContractError> {
         storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::Claim)?;
         relay_address.require_auth();
 
         let mut record = storage::get_earnings(&env, &relay_address);
@@ -288,13 +463,7 @@ impl FeeDistributorContract {
 
         storage::set_earnings(&env, &relay_address, &record);
 
-        env.events().publish(
-            (
-                soroban_sdk::Symbol::new(&env, "fee_distributor"),
-                soroban_sdk::Symbol::new(&env, "claim"),
-            ),
-            (relay_address.clone(), payout),
-        );
+        EVENTS.publish(&env, "claim", (relay_address.clone(), payout));
 
         // TODO: SAC transfer payout to relay_address
         Ok(payout)
@@ -317,9 +486,110 @@ impl FeeDistributorContract {
         storage::get_earnings(&env, &relay_address)
     }
 
+    /// Move the earnings recorded for `relay_address` to the address the
+    /// registry now resolves it to, after the relay's ownership was transferred.
+    ///
+    /// Permissionless: the earnings can only follow the registry's forwarding
+    /// record. Lifetime totals and the unclaimed balance are added to the new
+    /// address's record and the old record is removed. Returns the new address.
+    ///
+    /// # Errors
+    /// - `ContractError::NotForwarded` if no registry is set, or it does not
+    ///   forward `relay_address` to a different registered node.
+    /// - `ContractError::Overflow` if merging the records overflows.
+    pub fn migrate_earnings(env: Env, relay_address: Address) -> Result<Address, ContractError> {
+        storage::extend_instance_ttl(&env);
+        let registry = storage::get_registry(&env).ok_or(ContractError::NotForwarded)?;
+        let new_address = env
+            .invoke_contract::<Option<Address>>(
+                &registry,
+                &Symbol::new(&env, "resolve_node"),
+                vec![&env, relay_address.into_val(&env)],
+            )
+            .filter(|new_address| *new_address != relay_address)
+            .ok_or(ContractError::NotForwarded)?;
+
+        let old = storage::get_earnings(&env, &relay_address);
+        let mut record = storage::get_earnings(&env, &new_address);
+        record.total_earned = record
+            .total_earned
+            .checked_add(old.total_earned)
+            .ok_or(ContractError::Overflow)?;
+        record.total_claimed = record
+            .total_claimed
+            .checked_add(old.total_claimed)
+            .ok_or(ContractError::Overflow)?;
+        record.unclaimed = record
+            .unclaimed
+            .checked_add(old.unclaimed)
+            .ok_or(ContractError::Overflow)?;
+        storage::set_earnings(&env, &new_address, &record);
+        storage::remove_earnings(&env, &relay_address);
+
+        EVENTS.publish(
+            &env,
+            "migrate_earnings",
+            (relay_address, new_address.clone(), old.unclaimed),
+        );
+
+        Ok(new_address)
+    }
+
+    /// Set the relay registry whose `split_rewards` divides each relay payout
+    /// between the operator and its delegators.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::SetRegistry`).
+    fn set_registry(env: Env, registry: Address) -> Result<(), ContractError> {
+        storage::set_registry(&env, &registry);
+
+        EVENTS.publish(&env, "set_registry", (registry,));
+
+        Ok(())
+    }
+
+    /// Relay registry used to share payouts with delegators, if set.
+    pub fn get_registry(env: Env) -> Option<Address> {
+        storage::extend_instance_ttl(&env);
+        storage::get_registry(&env)
+    }
+
+    /// Set how many relays the registry assigns to each batch. Once set, and
+    /// while a registry is configured, `distribute` only pays for batches
+    /// opened with `open_batch`, and only a relay that is among the batch's
+    /// assigned relays; 0 turns the check off.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::SetAssignmentSize`).
+    fn set_assignment_size(env: Env, size: u32) -> Result<(), ContractError> {
+        storage::set_assignment_size(&env, size);
+
+        EVENTS.publish(&env, "set_assignment_size", (size,));
+
+        Ok(())
+    }
+
+    /// Number of relays assigned to each batch; 0 while assignments are not checked.
+    pub fn get_assignment_size(env: Env) -> u32 {
+        storage::extend_instance_ttl(&env);
+        storage::get_assignment_size(&env)
+    }
+
+    /// Relays the registry assigned to `batch_id` when it was opened, in draw
+    /// order. Empty if the batch has not been opened, or was opened while no
+    /// registry or assignment size was set.
+    pub fn get_assigned_relays(env: Env, batch_id: u64) -> Vec<Address> {
+        storage::extend_instance_ttl(&env);
+        storage::get_batch_assignment(&env, batch_id)
+    }
+
+    /// Assignment seed `open_batch` fixed for `batch_id`, if it was opened.
+    pub fn get_batch_seed(env: Env, batch_id: u64) -> Option<BytesN<32>> {
+        storage::extend_instance_ttl(&env);
+        storage::get_batch_seed(&env, batch_id)
+    }
+
     /// Update the protocol fee rate.
     ///
-    /// This function can only be called by the configured admin address.
+    /// Only reachable through an executed council proposal (`ProposalAction::SetFeeRate`).
     /// The fee rate is specified in basis points (bps), where 10000 = 100%.
     ///
     /// # Parameters
@@ -327,14 +597,10 @@ impl FeeDistributorContract {
     /// - `new_fee_rate_bps`: The new fee rate in basis points (1 to 10000).
     ///
     /// # Errors
-    /// - Auth error if caller is not the admin.
     /// - `ContractError::InvalidFeeRate` if the rate is 0 or greater than 10000.
-    pub fn set_fee_rate(env: Env, new_fee_rate_bps: u32) -> Result<(), ContractError> {
-        storage::extend_instance_ttl(&env);
+    fn set_fee_rate(env: Env, new_fee_rate_bps: u32) -> Result<(), ContractError> {
         let mut config = storage::get_fee_config(&env);
 
-        require_council_auth(&env, &config.council);
-
         if new_fee_rate_bps == 0 || new_fee_rate_bps > 10_000 {
             return Err(ContractError::InvalidFeeRate);
         }
@@ -342,14 +608,478 @@ impl FeeDistributorContract {
         config.fee_rate_bps = new_fee_rate_bps;
         storage::set_fee_config(&env, &config);
 
-        env.events().publish(
-            (
-                soroban_sdk::Symbol::new(&env, "fee_distributor"),
-                soroban_sdk::Symbol::new(&env, "set_fee_rate"),
-            ),
-            (new_fee_rate_bps,),
-        );
+        EVENTS.publish(&env, "set_fee_rate", (new_fee_rate_bps,));
+
+        Ok(())
+    }
+
+    /// Add a new member to the admin council.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::AddMember`).
+    ///
+    /// # Errors
+    /// - `ContractError::InvalidCouncilConfig` if `member` is already on the council or the
+    ///   council would exceed `MAX_COUNCIL_MEMBERS`.
+    fn add_member(env: Env, member: Address) -> Result<(), ContractError> {
+        let mut council = storage::get_admin_council(&env);
+        council.add_member(&member)?;
+        storage::set_admin_council(&env, &council);
+
+        EVENTS.member_added(&env, &member, council.members.len());
+
+        Ok(())
+    }
+
+    /// Remove a member from the admin council.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::RemoveMember`).
+    ///
+    /// # Errors
+    /// - `ContractError::NotCouncilMember` if `member` is not on the council.
+    /// - `ContractError::InvalidCouncilConfig` if the threshold would exceed the remaining members.
+    fn remove_member(env: Env, member: Address) -> Result<(), ContractError> {
+        let mut council = storage::get_admin_council(&env);
+        council.remove_member(&member)?;
+        storage::set_admin_council(&env, &council);
+
+        EVENTS.member_removed(&env, &member, council.members.len());
+
+        Ok(())
+    }
+
+    /// Swap a council member for a new address, e.g. after a key loss or compromise.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::ReplaceMember`).
+    ///
+    /// # Errors
+    /// - `ContractError::NotCouncilMember` if `old_member` is not on the council.
+    /// - `ContractError::InvalidCouncilConfig` if `new_member` is already on the council.
+    fn replace_member(
+        env: Env,
+        old_member: Address,
+        new_member: Address,
+    ) -> Result<(), ContractError> {
+        let mut council = storage::get_admin_council(&env);
+        council.replace_member(&old_member, &new_member)?;
+        storage::set_admin_council(&env, &council);
+
+        EVENTS.member_replaced(&env, &old_member, &new_member);
+
+        Ok(())
+    }
+
+    /// Change the number of approvals required to execute a council proposal.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::SetThreshold`).
+    ///
+    /// # Errors
+    /// - `ContractError::InvalidCouncilConfig` if `threshold` is zero or exceeds the member count.
+    fn set_threshold(env: Env, threshold: u32) -> Result<(), ContractError> {
+        let mut council = storage::get_admin_council(&env);
+        council.set_threshold(threshold)?;
+        storage::set_admin_council(&env, &council);
+
+        EVENTS.threshold_changed(&env, threshold);
+
+        Ok(())
+    }
+
+    /// Replace the contract's WASM with a previously uploaded build.
+    ///
+    /// The new code takes effect once this invocation completes; `migrate` must
+    /// then be called to bring storage up to the new build's `SCHEMA_VERSION`.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::Upgrade`).
+    fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), ContractError> {
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        EVENTS.upgraded(&env, &new_wasm_hash, storage::get_schema_version(&env));
+
+        Ok(())
+    }
+
+    /// Immediately halt one or more entrypoints in an emergency.
+    ///
+    /// Any single council member may pause, so a halt does not wait for a
+    /// proposal to collect approvals. Lifting a pause requires an executed
+    /// `ProposalAction::Unpause`. Entrypoints that are not listed keep working,
+    /// so users can still exit while the affected path is halted.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `member`: Council member triggering the pause. Must authorize this call.
+    /// - `functions`: Entrypoints to pause.
+    ///
+    /// # Errors
+    /// - `ContractError::NotCouncilMember` if `member` is not on the admin council.
+    pub fn pause(
+        env: Env,
+        member: Address,
+        functions: Vec<PausableFn>,
+    ) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        member.require_auth();
+        storage::get_admin_council(&env).require_member(&member)?;
+
+        for function in functions.iter() {
+            storage::set_paused(&env, &function, true);
+        }
+
+        EVENTS.paused(&env, &member, functions);
+
+        Ok(())
+    }
+
+    /// Lift an emergency pause on the listed entrypoints.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::Unpause`).
+    fn unpause(env: Env, functions: Vec<PausableFn>) -> Result<(), ContractError> {
+        for function in functions.iter() {
+            storage::set_paused(&env, &function, false);
+        }
+
+        EVENTS.unpaused(&env, functions);
+
+        Ok(())
+    }
+
+    /// Drop a queued timelocked action before it is executed.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::CancelQueued`).
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    /// - `ContractError::ProposalNotQueued` if the proposal is not waiting in the queue.
+    fn cancel_queued(env: Env, proposal_id: u64) -> Result<(), ContractError> {
+        let mut proposal =
+            storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+        if proposal.status != ProposalStatus::Queued {
+            return Err(ContractError::ProposalNotQueued);
+        }
+
+        proposal.status = ProposalStatus::Cancelled;
+        storage::set_proposal(&env, &proposal);
+
+        EVENTS.queued_cancelled(&env, proposal_id);
+
+        Ok(())
+    }
+
+    /// Grant `role` to `account`.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::GrantRole`).
+    ///
+    /// # Errors
+    /// - `ContractError::RoleAlreadyGranted` if `account` already holds the role.
+    fn grant_role(env: Env, role: Role, account: Address) -> Result<(), ContractError> {
+        let mut holders = storage::get_role_holders(&env, &role);
+        roles::grant_role(&mut holders, &account)?;
+        storage::set_role_holders(&env, &role, &holders);
+
+        EVENTS.role_granted(&env, role, &account);
+
+        Ok(())
+    }
+
+    /// Revoke `role` from `account`.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::RevokeRole`).
+    ///
+    /// # Errors
+    /// - `ContractError::RoleNotHeld` if `account` does not hold the role.
+    fn revoke_role(env: Env, role: Role, account: Address) -> Result<(), ContractError> {
+        let mut holders = storage::get_role_holders(&env, &role);
+        roles::revoke_role(&mut holders, &account)?;
+        storage::set_role_holders(&env, &role, &holders);
+
+        EVENTS.role_revoked(&env, role, &account);
 
         Ok(())
     }
+
+    /// Addresses currently holding `role`.
+    pub fn get_role_holders(env: Env, role: Role) -> Vec<Address> {
+        storage::extend_instance_ttl(&env);
+        storage::get_role_holders(&env, &role)
+    }
+
+    /// Whether `account` currently holds `role`.
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        storage::extend_instance_ttl(&env);
+        storage::has_role(&env, &role, &account)
+    }
+
+    /// A page of the action log, oldest first, starting at entry `start_id`.
+    ///
+    /// Returns at most `limit` entries, capped at `MAX_LOG_PAGE_SIZE`; an empty
+    /// page means `start_id` is past the end of the log.
+    pub fn get_action_log(env: Env, start_id: u64, limit: u32) -> Vec<ActionLogEntry> {
+        storage::extend_instance_ttl(&env);
+        let mut page = Vec::new(&env);
+        for id in log_page(start_id, limit, storage::get_action_log_count(&env)) {
+            if let Some(entry) = storage::get_action_log_entry(&env, id) {
+                page.push_back(entry);
+            }
+        }
+        page
+    }
+
+    /// Number of entries in the action log.
+    pub fn get_action_log_count(env: Env) -> u64 {
+        storage::extend_instance_ttl(&env);
+        storage::get_action_log_count(&env)
+    }
+
+    /// Whether `function` is currently paused. Intended for off-chain relays to poll.
+    pub fn is_paused(env: Env, function: PausableFn) -> bool {
+        storage::extend_instance_ttl(&env);
+        storage::is_paused(&env, &function)
+    }
+
+    /// Open a council proposal for a privileged action.
+    ///
+    /// The proposer's approval is recorded immediately, so with a threshold of 1
+    /// the proposal can be executed right away. Holders of the role covering
+    /// `action` (see `ProposalAction::required_role`) may also propose it; such a
+    /// proposal needs no further approvals.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `proposer`: Council member or role holder creating the proposal. Must authorize this call.
+    /// - `action`: The privileged action to apply once the proposal is approved.
+    ///
+    /// # Returns
+    /// The newly assigned `proposal_id`.
+    ///
+    /// # Errors
+    /// - `ContractError::NotCouncilMember` if `proposer` is neither on the admin council
+    ///   nor holds the role covering `action`.
+    pub fn propose(
+        env: Env,
+        proposer: Address,
+        action: ProposalAction,
+    ) -> Result<u64, ContractError> {
+        storage::extend_instance_ttl(&env);
+        proposer.require_auth();
+        if !has_role_for(&env, &action, &proposer) {
+            storage::get_admin_council(&env).require_member(&proposer)?;
+        }
+
+        let (created_at, expires_at) = proposal_window(&env);
+        let proposal = Proposal {
+            id: storage::next_proposal_id(&env),
+            proposer: proposer.clone(),
+            action,
+            approvals: Vec::from_array(&env, [proposer.clone()]),
+            status: ProposalStatus::Pending,
+            created_at,
+            expires_at,
+        };
+        storage::set_proposal(&env, &proposal);
+
+        EVENTS.proposal_created(&env, &proposer, proposal.id, proposal.action);
+
+        Ok(proposal.id)
+    }
+
+    /// Approve a pending council proposal.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `approver`: Council member approving the proposal. Must authorize this call.
+    /// - `proposal_id`: ID of the proposal to approve.
+    ///
+    /// # Errors
+    /// - `ContractError::NotCouncilMember` if `approver` is not on the admin council.
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    /// - `ContractError::ProposalNotPending` if the proposal was executed or cancelled.
+    /// - `ContractError::ProposalExpired` if the approval window has passed.
+    /// - `ContractError::AlreadyApproved` if `approver` already approved this proposal.
+    pub fn approve(env: Env, approver: Address, proposal_id: u64) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        approver.require_auth();
+        let council = storage::get_admin_council(&env);
+        council.require_member(&approver)?;
+
+        let mut proposal = load_pending_proposal(&env, proposal_id)?;
+        council.record_approval(&mut proposal.approvals, &approver)?;
+        storage::set_proposal(&env, &proposal);
+
+        EVENTS.proposal_approved(&env, &approver, proposal_id, proposal.approvals.len());
+
+        Ok(())
+    }
+
+    /// Apply a council proposal once enough distinct members have approved it.
+    ///
+    /// Anyone may call this; only approvals from addresses that are still on the
+    /// council count towards the threshold. A proposal whose proposer still holds
+    /// the role covering its action skips the threshold check. Timelocked actions (see
+    /// `ProposalAction::is_timelocked`) take two calls: the first queues the
+    /// proposal with an eta `TIMELOCK_DELAY` ledgers out, and a call at or after
+    /// the eta applies it.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `proposal_id`: ID of the proposal to execute.
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    /// - `ContractError::ProposalNotPending` if the proposal was executed or cancelled.
+    /// - `ContractError::ProposalExpired` if the approval window has passed.
+    /// - `ContractError::InsufficientApprovals` if the council threshold is not met and
+    ///   the proposer does not hold the role covering the action.
+    /// - `ContractError::TimelockActive` if the proposal is queued and its eta has not been reached.
+    /// - Any error returned by the underlying action.
+    pub fn execute(env: Env, proposal_id: u64) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        let mut proposal =
+            storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+
+        if proposal.status == ProposalStatus::Queued {
+            let eta = storage::get_proposal_eta(&env, proposal_id)
+                .ok_or(ContractError::ProposalNotQueued)?;
+            if (env.ledger().sequence() as u64) < eta {
+                return Err(ContractError::TimelockActive);
+            }
+        } else {
+            require_open(&env, &proposal.status, proposal.expires_at)?;
+            if !has_role_for(&env, &proposal.action, &proposal.proposer) {
+                storage::get_admin_council(&env).require_threshold(&proposal.approvals)?;
+            }
+
+            if proposal.action.is_timelocked() {
+                let eta = timelock_eta(&env);
+                proposal.status = ProposalStatus::Queued;
+                storage::set_proposal(&env, &proposal);
+                storage::set_proposal_eta(&env, proposal_id, eta);
+
+                EVENTS.proposal_queued(&env, proposal_id, eta);
+
+                return Ok(());
+            }
+        }
+
+        proposal.status = ProposalStatus::Executed;
+        storage::set_proposal(&env, &proposal);
+
+        match proposal.action.clone() {
+            ProposalAction::SetFeeRate(new_fee_rate_bps) => {
+                Self::set_fee_rate(env.clone(), new_fee_rate_bps)?
+            }
+            ProposalAction::AddMember(member) => Self::add_member(env.clone(), member)?,
+            ProposalAction::RemoveMember(member) => Self::remove_member(env.clone(), member)?,
+            ProposalAction::ReplaceMember(old_member, new_member) => {
+                Self::replace_member(env.clone(), old_member, new_member)?
+            }
+            ProposalAction::SetThreshold(threshold) => Self::set_threshold(env.clone(), threshold)?,
+            ProposalAction::Upgrade(new_wasm_hash) => Self::upgrade(env.clone(), new_wasm_hash)?,
+            ProposalAction::Unpause(functions) => Self::unpause(env.clone(), functions)?,
+            ProposalAction::CancelQueued(queued_id) => Self::cancel_queued(env.clone(), queued_id)?,
+            ProposalAction::GrantRole(role, account) => {
+                Self::grant_role(env.clone(), role, account)?
+            }
+            ProposalAction::RevokeRole(role, account) => {
+                Self::revoke_role(env.clone(), role, account)?
+            }
+            ProposalAction::SetRegistry(registry) => Self::set_registry(env.clone(), registry)?,
+            ProposalAction::SetAssignmentSize(size) => {
+                Self::set_assignment_size(env.clone(), size)?
+            }
+        }
+
+        log_action(&env, proposal_id, proposal.action, proposal.approvals);
+
+        EVENTS.proposal_executed(&env, proposal_id);
+
+        Ok(())
+    }
+
+    /// Withdraw a pending proposal. Only the original proposer may cancel it.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `proposer`: The address that created the proposal. Must authorize this call.
+    /// - `proposal_id`: ID of the proposal to cancel.
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    /// - `ContractError::ProposalNotPending` if the proposal was executed or cancelled.
+    /// - `ContractError::Unauthorized` if `proposer` did not create the proposal.
+    pub fn cancel(env: Env, proposer: Address, proposal_id: u64) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        proposer.require_auth();
+
+        let mut proposal =
+            storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+        if proposal.status != ProposalStatus::Pending {
+            return Err(ContractError::ProposalNotPending);
+        }
+        if proposal.proposer != proposer {
+            return Err(ContractError::Unauthorized);
+        }
+
+        proposal.status = ProposalStatus::Cancelled;
+        storage::set_proposal(&env, &proposal);
+
+        EVENTS.proposal_cancelled(&env, &proposer, proposal_id);
+
+        Ok(())
+    }
+
+    /// Fetch a council proposal by its ID.
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Result<Proposal, ContractError> {
+        storage::extend_instance_ttl(&env);
+        storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)
+    }
+
+    /// Earliest ledger sequence at which a queued proposal can be executed.
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotQueued` if the proposal was never queued.
+    pub fn get_proposal_eta(env: Env, proposal_id: u64) -> Result<u64, ContractError> {
+        storage::extend_instance_ttl(&env);
+        storage::get_proposal_eta(&env, proposal_id).ok_or(ContractError::ProposalNotQueued)
+    }
+
+    /// Bring stored data up to this build's `SCHEMA_VERSION` after an upgrade.
+    ///
+    /// Permissionless and idempotent: each step rewrites one schema version into
+    /// the next, and calling it on an up-to-date contract changes nothing.
+    ///
+    /// # Returns
+    /// The schema version after migration.
+    ///
+    /// # Errors
+    /// - `ContractError::UnsupportedSchemaVersion` if storage was written by a newer build.
+    pub fn migrate(env: Env) -> Result<u32, ContractError> {
+        storage::extend_instance_ttl(&env);
+        let from = storage::get_schema_version(&env);
+        if from > storage::SCHEMA_VERSION {
+            return Err(ContractError::UnsupportedSchemaVersion);
+        }
+
+        let mut version = from;
+        while version < storage::SCHEMA_VERSION {
+            migrate_step(&env, version)?;
+            version += 1;
+        }
+
+        if version != from {
+            storage::set_schema_version(&env, version);
+            EVENTS.migrated(&env, from, version);
+        }
+
+        Ok(version)
+    }
+
+    /// Storage schema version currently recorded by the contract.
+    pub fn version(env: Env) -> u32 {
+        storage::extend_instance_ttl(&env);
+        storage::get_schema_version(&env)
+    }
 }
@@ -6,20 +6,18 @@
 //! All storage access is isolated to this module. Contract functions use these
 //! helpers to read and write data, keeping raw storage operations centralized.
 
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Vec};
 
-use crate::types::{EarningsRecord, FeeConfig, FeeEntry};
+use crate::types::{
+    ActionLogEntry, AdminCouncil, EarningsRecord, FeeConfig, FeeEntry, PausableFn, Proposal, Role,
+};
 
-// Bump by ~30 days (assuming ~5 seconds per ledger)
-const LEDGER_BUMP_AMOUNT: u32 = 518_400;
-// Bump if remaining life is less than ~15 days
-const LEDGER_BUMP_THRESHOLD: u32 = 259_200;
+pub use conduit_common::ttl::extend_instance_ttl;
+use conduit_common::ttl::extend_persistent_ttl;
 
-pub fn extend_instance_ttl(env: &Env) {
-    env.storage()
-        .instance()
-        .extend_ttl(LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
-}
+/// Storage layout version written by this build. Bump it together with a new
+/// step in `migrate_step` whenever a persisted layout changes.
+pub const SCHEMA_VERSION: u32 = 1;
 
 /// Storage key enum for the Fee Distributor contract.
 ///
@@ -39,6 +37,30 @@ pub enum DataKey {
     TreasuryAddress,
     /// Stores the token contract address.
     TokenAddress,
+    /// Stores a council `Proposal` keyed by proposal ID.
+    Proposal(u64),
+    /// Monotonically incrementing proposal ID counter.
+    ProposalCount,
+    /// Earliest execution ledger of a queued proposal, keyed by proposal_id.
+    ProposalEta(u64),
+    /// Storage schema version of the persisted data.
+    SchemaVersion,
+    /// Set while the given entrypoint is paused.
+    Paused(PausableFn),
+    /// Addresses holding the given role.
+    RoleHolders(Role),
+    /// An applied privileged action, keyed by its position in the action log.
+    ActionLog(u64),
+    /// Number of entries in the action log.
+    ActionLogCount,
+    /// Stores the relay registry address used to split payouts with delegators.
+    Registry,
+    /// Number of relays the registry assigns to each batch; absent or 0 means any relay.
+    AssignmentSize,
+    /// Seed of the relay assignment fixed by `open_batch`, keyed by batch ID.
+    BatchSeed(u64),
+    /// Relays assigned to a batch when `open_batch` ran, keyed by batch ID.
+    BatchAssignment(u64),
 }
 
 /// Load the earnings record for a relay node. Returns a zeroed record if not found.
@@ -53,9 +75,7 @@ pub enum DataKey {
 pub fn get_earnings(env: &Env, address: &Address) -> EarningsRecord {
     let key = DataKey::Earnings(address.clone());
     if let Some(record) = env.storage().persistent().get::<_, EarningsRecord>(&key) {
-        env.storage()
-            .persistent()
-            .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+        extend_persistent_ttl(env, &key);
         record
     } else {
         EarningsRecord {
@@ -75,9 +95,14 @@ pub fn get_earnings(env: &Env, address: &Address) -> EarningsRecord {
 pub fn set_earnings(env: &Env, address: &Address, record: &EarningsRecord) {
     let key = DataKey::Earnings(address.clone());
     env.storage().persistent().set(&key, record);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Remove the earnings record of an address whose earnings moved elsewhere.
+pub fn remove_earnings(env: &Env, address: &Address) {
     env.storage()
         .persistent()
-        .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+        .remove(&DataKey::Earnings(address.clone()));
 }
 
 /// Load a fee entry by batch ID. Returns None if not found.
@@ -92,9 +117,7 @@ pub fn set_earnings(env: &Env, address: &Address, record: &EarningsRecord) {
 pub fn get_fee_entry(env: &Env, batch_id: u64) -> Option<FeeEntry> {
     let key = DataKey::FeeEntry(batch_id);
     if let Some(entry) = env.storage().persistent().get::<_, FeeEntry>(&key) {
-        env.storage()
-            .persistent()
-            .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+        extend_persistent_ttl(env, &key);
         Some(entry)
     } else {
         None
@@ -110,9 +133,7 @@ pub fn get_fee_entry(env: &Env, batch_id: u64) -> Option<FeeEntry> {
 pub fn set_fee_entry(env: &Env, batch_id: u64, entry: &FeeEntry) {
     let key = DataKey::FeeEntry(batch_id);
     env.storage().persistent().set(&key, entry);
-    env.storage()
-        .persistent()
-        .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+    extend_persistent_ttl(env, &key);
 }
 
 /// Load the global fee configuration.
@@ -141,6 +162,25 @@ pub fn set_fee_config(env: &Env, config: &FeeConfig) {
     env.storage().instance().set(&DataKey::FeeConfig, config);
 }
 
+/// Load the admin council stored in the fee configuration.
+///
+/// # Panics
+/// Panics if the fee configuration has not been initialized.
+pub fn get_admin_council(env: &Env) -> AdminCouncil {
+    get_fee_config(env).council
+}
+
+/// Replace the admin council stored in the fee configuration.
+///
+/// # Parameters
+/// - `env`: Soroban environment.
+/// - `council`: The new admin council.
+pub fn set_admin_council(env: &Env, council: &AdminCouncil) {
+    let mut config = get_fee_config(env);
+    config.council = council.clone();
+    set_fee_config(env, &config);
+}
+
 /// Load the treasury contract address.
 ///
 /// # Parameters
@@ -169,6 +209,68 @@ pub fn set_treasury_address(env: &Env, address: &Address) {
         .set(&DataKey::TreasuryAddress, address);
 }
 
+/// Load the relay registry address, if one has been set.
+pub fn get_registry(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Registry)
+}
+
+/// Set the relay registry address.
+pub fn set_registry(env: &Env, address: &Address) {
+    env.storage().instance().set(&DataKey::Registry, address);
+}
+
+/// Load how many relays are assigned to each batch. 0 means assignments are not checked.
+pub fn get_assignment_size(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::AssignmentSize)
+        .unwrap_or(0)
+}
+
+/// Set how many relays are assigned to each batch.
+pub fn set_assignment_size(env: &Env, size: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AssignmentSize, &size);
+}
+
+/// Load the assignment seed `open_batch` fixed for `batch_id`, if the batch was opened.
+pub fn get_batch_seed(env: &Env, batch_id: u64) -> Option<BytesN<32>> {
+    let key = DataKey::BatchSeed(batch_id);
+    let seed = env.storage().persistent().get::<_, BytesN<32>>(&key);
+    if seed.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    seed
+}
+
+/// Persist the assignment seed of `batch_id`.
+pub fn set_batch_seed(env: &Env, batch_id: u64, seed: &BytesN<32>) {
+    let key = DataKey::BatchSeed(batch_id);
+    env.storage().persistent().set(&key, seed);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Load the relays `open_batch` assigned to `batch_id`. Empty if the batch was
+/// not opened, or was opened while no assignment size was set.
+pub fn get_batch_assignment(env: &Env, batch_id: u64) -> Vec<Address> {
+    let key = DataKey::BatchAssignment(batch_id);
+    match env.storage().persistent().get::<_, Vec<Address>>(&key) {
+        Some(relays) => {
+            extend_persistent_ttl(env, &key);
+            relays
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Persist the relays assigned to `batch_id`.
+pub fn set_batch_assignment(env: &Env, batch_id: u64, relays: &Vec<Address>) {
+    let key = DataKey::BatchAssignment(batch_id);
+    env.storage().persistent().set(&key, relays);
+    extend_persistent_ttl(env, &key);
+}
+
 /// Load the token contract address.
 ///
 /// # Parameters
@@ -196,3 +298,140 @@ pub fn set_token_address(env: &Env, address: &Address) {
         .instance()
         .set(&DataKey::TokenAddress, address);
 }
+
+/// Load a council proposal by ID. Returns None if not found.
+///
+/// # Parameters
+/// - `env`: Soroban environment.
+/// - `proposal_id`: Unique identifier of the proposal.
+pub fn get_proposal(env: &Env, proposal_id: u64) -> Option<Proposal> {
+    let key = DataKey::Proposal(proposal_id);
+    if let Some(proposal) = env.storage().persistent().get::<_, Proposal>(&key) {
+        extend_persistent_ttl(env, &key);
+        Some(proposal)
+    } else {
+        None
+    }
+}
+
+/// Persist a council proposal under its own ID.
+///
+/// # Parameters
+/// - `env`: Soroban environment.
+/// - `proposal`: The proposal to store.
+pub fn set_proposal(env: &Env, proposal: &Proposal) {
+    let key = DataKey::Proposal(proposal.id);
+    env.storage().persistent().set(&key, proposal);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Increment and return the next available proposal ID (starts at 1).
+///
+/// # Parameters
+/// - `env`: Soroban environment.
+pub fn get_proposal_eta(env: &Env, proposal_id: u64) -> Option<u64> {
+    let key = DataKey::ProposalEta(proposal_id);
+    let eta = env.storage().persistent().get::<_, u64>(&key);
+    if eta.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    eta
+}
+
+pub fn set_proposal_eta(env: &Env, proposal_id: u64, eta: u64) {
+    let key = DataKey::ProposalEta(proposal_id);
+    env.storage().persistent().set(&key, &eta);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn next_proposal_id(env: &Env) -> u64 {
+    let next = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&DataKey::ProposalCount)
+        .unwrap_or(0)
+        .checked_add(1)
+        .expect("proposal count overflow");
+    env.storage().instance().set(&DataKey::ProposalCount, &next);
+    next
+}
+
+/// Schema version of the stored data. Deployments that predate version
+/// tracking use the version 1 layout.
+pub fn get_schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(1)
+}
+
+pub fn set_schema_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SchemaVersion, &version);
+}
+
+pub fn is_paused(env: &Env, function: &PausableFn) -> bool {
+    env.storage()
+        .instance()
+        .has(&DataKey::Paused(function.clone()))
+}
+
+pub fn set_paused(env: &Env, function: &PausableFn, paused: bool) {
+    let key = DataKey::Paused(function.clone());
+    if paused {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+}
+
+/// Addresses holding `role`, in the order they were granted it.
+pub fn get_role_holders(env: &Env, role: &Role) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::RoleHolders(role.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_role_holders(env: &Env, role: &Role, holders: &Vec<Address>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RoleHolders(role.clone()), holders);
+}
+
+pub fn has_role(env: &Env, role: &Role, account: &Address) -> bool {
+    get_role_holders(env, role).contains(account)
+}
+
+pub fn get_action_log_entry(env: &Env, id: u64) -> Option<ActionLogEntry> {
+    let key = DataKey::ActionLog(id);
+    let entry = env.storage().persistent().get::<_, ActionLogEntry>(&key);
+    if entry.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    entry
+}
+
+pub fn set_action_log_entry(env: &Env, entry: &ActionLogEntry) {
+    let key = DataKey::ActionLog(entry.id);
+    env.storage().persistent().set(&key, entry);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn get_action_log_count(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ActionLogCount)
+        .unwrap_or(0)
+}
+
+pub fn next_action_log_id(env: &Env) -> u64 {
+    let next = get_action_log_count(env)
+        .checked_add(1)
+        .expect("action log count overflow");
+    env.storage()
+        .instance()
+        .set(&DataKey::ActionLogCount, &next);
+    next
+}
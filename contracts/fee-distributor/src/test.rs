@@ -5,9 +5,59 @@
 
 extern crate std;
 
-use soroban_sdk::{testutils::Address as _, token, Address, Env};
+use conduit_common::council::TIMELOCK_DELAY;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token,
+    xdr::ToXdr,
+    Address, BytesN, Env, String, Vec,
+};
+
+use crate::{
+    errors::ContractError,
+    storage,
+    types::{PausableFn, ProposalAction, Role},
+    FeeDistributorContract, FeeDistributorContractClient,
+};
+
+/// The first member of the contract's admin council.
+fn council_admin(env: &Env, client: &FeeDistributorContractClient) -> Address {
+    env.as_contract(&client.address, || {
+        storage::get_fee_config(env).council.members.get(0).unwrap()
+    })
+}
+
+/// Propose a fee-rate change as the first council member and try to execute it.
+fn try_set_fee_rate(
+    env: &Env,
+    client: &FeeDistributorContractClient,
+    new_fee_rate_bps: u32,
+) -> Result<(), ContractError> {
+    let admin = council_admin(env, client);
+    let proposal_id = client.propose(&admin, &ProposalAction::SetFeeRate(new_fee_rate_bps));
+    execute_after_timelock(env, client, proposal_id)
+}
+
+/// Queue a fee-rate proposal, wait out the timelock and try to apply it.
+fn execute_after_timelock(
+    env: &Env,
+    client: &FeeDistributorContractClient,
+    proposal_id: u64,
+) -> Result<(), ContractError> {
+    client.execute(&proposal_id);
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    match client.try_execute(&proposal_id) {
+        Ok(_) => Ok(()),
+        Err(Ok(err)) => Err(err),
+        Err(Err(err)) => panic!("unexpected invoke error: {:?}", err),
+    }
+}
 
-use crate::{errors::ContractError, FeeDistributorContract, FeeDistributorContractClient};
+/// Propose and execute a fee-rate change, panicking on failure.
+fn set_fee_rate(env: &Env, client: &FeeDistributorContractClient, new_fee_rate_bps: u32) {
+    try_set_fee_rate(env, client, new_fee_rate_bps).unwrap();
+}
 
 fn setup<'a>() -> (Env, FeeDistributorContractClient<'a>) {
     let env = Env::default();
@@ -373,7 +423,7 @@ fn test_set_fee_rate_success() {
     client.initialize(&council, &50u32, &1000u32, &treasury, &token);
 
     // Update fee rate to 100 bps (1%)
-    client.set_fee_rate(&100u32);
+    set_fee_rate(&env, &client, 100u32);
 
     // Verify change reflected in calculate_fee
     // With fee_rate_bps = 100 and batch_size = 200: fee = 200 * 100 / 10000 = 2
@@ -400,8 +450,8 @@ fn test_set_fee_rate_invalid_zero() {
     client.initialize(&council, &50u32, &1000u32, &treasury, &token);
 
     // Try to set fee rate to 0
-    let result = client.try_set_fee_rate(&0u32);
-    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate)));
+    let result = try_set_fee_rate(&env, &client, 0u32);
+    assert_eq!(result, Err(ContractError::InvalidFeeRate));
 }
 
 #[test]
@@ -423,15 +473,14 @@ fn test_set_fee_rate_invalid_above_max() {
     client.initialize(&council, &50u32, &1000u32, &treasury, &token);
 
     // Try to set fee rate to 10001 (above max of 10000)
-    let result = client.try_set_fee_rate(&10001u32);
-    assert_eq!(result, Err(Ok(ContractError::InvalidFeeRate)));
+    let result = try_set_fee_rate(&env, &client, 10001u32);
+    assert_eq!(result, Err(ContractError::InvalidFeeRate));
 }
 
 #[test]
 #[should_panic(expected = "HostError")]
 fn test_set_fee_rate_unauthorized() {
     let env = Env::default();
-    env.mock_all_auths();
     let contract_id = env.register(FeeDistributorContract, ());
     let client = FeeDistributorContractClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
@@ -446,14 +495,44 @@ fn test_set_fee_rate_unauthorized() {
 
     client.initialize(&council, &50u32, &1000u32, &treasury, &token);
 
-    // Create a new env without mock_all_auths and try to call as non-admin
-    let env2 = Env::default();
-    // Don't call env2.mock_all_auths() - this should cause auth to fail
-    let contract_id2 = env2.register(FeeDistributorContract, ());
-    let client2 = FeeDistributorContractClient::new(&env2, &contract_id2);
+    // No auths are mocked, so the council member's signature is missing.
+    client.propose(&admin, &ProposalAction::SetFeeRate(100u32));
+}
+
+#[test]
+fn test_set_fee_rate_requires_threshold_approvals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(FeeDistributorContract, ());
+    let client = FeeDistributorContractClient::new(&env, &contract_id);
+    let mut members = soroban_sdk::Vec::new(&env);
+    for _ in 0..3 {
+        members.push_back(Address::generate(&env));
+    }
+    let council = crate::types::AdminCouncil {
+        members: members.clone(),
+        threshold: 2,
+    };
+    let treasury = Address::generate(&env);
+    let token = Address::generate(&env);
+    client.initialize(&council, &50u32, &1000u32, &treasury, &token);
 
-    // This should panic because non-admin hasn't authorized
-    client2.set_fee_rate(&100u32);
+    let proposal_id = client.propose(&members.get(1).unwrap(), &ProposalAction::SetFeeRate(100));
+    assert_eq!(
+        client.try_execute(&proposal_id),
+        Err(Ok(ContractError::InsufficientApprovals))
+    );
+    assert_eq!(client.calculate_fee(&200u32), 1);
+
+    client.approve(&members.get(2).unwrap(), &proposal_id);
+    execute_after_timelock(&env, &client, proposal_id).unwrap();
+    assert_eq!(client.calculate_fee(&200u32), 2);
+
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_propose(&outsider, &ProposalAction::SetFeeRate(100)),
+        Err(Ok(ContractError::NotCouncilMember))
+    );
 }
 
 // ============================================================================
@@ -559,7 +638,7 @@ fn test_calculate_fee_with_different_rates() {
     assert_eq!(fee, 2);
 
     // Update to fee_rate_bps = 500 (5%)
-    client.set_fee_rate(&500u32);
+    set_fee_rate(&env, &client, 500u32);
 
     // With batch_size = 200: fee = 200 * 500 / 10000 = 10
     let fee2 = client.calculate_fee(&200u32);
@@ -661,7 +740,7 @@ fn test_fee_rate_change_affects_future_distributions() {
     client.distribute(&relay, &1u64, &200u32); // fee = 1
 
     // Change fee rate
-    client.set_fee_rate(&200u32); // 2%
+    set_fee_rate(&env, &client, 200u32); // 2%
 
     // Distribute with new rate
     client.distribute(&relay, &2u64, &200u32); // fee = 4
@@ -778,12 +857,12 @@ fn test_set_fee_rate_boundary_values() {
     client.initialize(&council, &100u32, &1000u32, &treasury, &token);
 
     // Test minimum valid rate (1)
-    client.set_fee_rate(&1u32);
+    set_fee_rate(&env, &client, 1u32);
     let fee1 = client.calculate_fee(&10000u32);
     assert_eq!(fee1, 1);
 
     // Test maximum valid rate (10000)
-    client.set_fee_rate(&10000u32);
+    set_fee_rate(&env, &client, 10000u32);
     let fee2 = client.calculate_fee(&100u32);
     assert_eq!(fee2, 100);
 }
@@ -887,3 +966,424 @@ fn test_claim_preserves_total_earned() {
     assert_eq!(earnings_after.unclaimed, 0);
     assert_eq!(earnings_after.total_claimed, total_earned_before);
 }
+
+#[test]
+fn test_council_threshold_change_via_proposal() {
+    let (env, client) = setup();
+    let admin = env.as_contract(&client.address, || {
+        storage::get_fee_config(&env)
+            .council
+            .members
+            .get(0)
+            .unwrap()
+    });
+    let second = Address::generate(&env);
+
+    let proposal_id = client.propose(&admin, &ProposalAction::AddMember(second.clone()));
+    execute_after_timelock(&env, &client, proposal_id).unwrap();
+    let proposal_id = client.propose(&second, &ProposalAction::SetThreshold(2));
+    execute_after_timelock(&env, &client, proposal_id).unwrap();
+
+    let council = env.as_contract(&client.address, || storage::get_fee_config(&env).council);
+    assert_eq!(council.members.len(), 2);
+    assert_eq!(council.threshold, 2);
+
+    // A single approval is no longer enough.
+    let proposal_id = client.propose(&admin, &ProposalAction::SetFeeRate(100));
+    assert_eq!(
+        client.try_execute(&proposal_id),
+        Err(Ok(ContractError::InsufficientApprovals))
+    );
+}
+
+#[test]
+fn test_version_and_migrate() {
+    let (env, client) = setup();
+    assert_eq!(client.version(), storage::SCHEMA_VERSION);
+    assert_eq!(client.migrate(), storage::SCHEMA_VERSION);
+
+    env.as_contract(&client.address, || {
+        storage::set_schema_version(&env, storage::SCHEMA_VERSION + 1)
+    });
+    assert_eq!(
+        client.try_migrate(),
+        Err(Ok(ContractError::UnsupportedSchemaVersion))
+    );
+}
+
+#[test]
+fn test_pause_distribute_still_allows_claim() {
+    let (env, client) = setup();
+    let admin = council_admin(&env, &client);
+    let relay = Address::generate(&env);
+    client.distribute(&relay, &1u64, &200u32);
+
+    let halted = soroban_sdk::Vec::from_array(&env, [PausableFn::Distribute]);
+    client.pause(&admin, &halted);
+    assert!(client.is_paused(&PausableFn::Distribute));
+    assert_eq!(
+        client.try_distribute(&relay, &2u64, &200u32),
+        Err(Ok(ContractError::Paused))
+    );
+    assert_eq!(client.claim(&relay), 1);
+
+    let proposal_id = client.propose(&admin, &ProposalAction::Unpause(halted));
+    client.execute(&proposal_id);
+    client.distribute(&relay, &2u64, &200u32);
+}
+
+#[test]
+fn test_set_fee_rate_waits_for_timelock() {
+    let (env, client) = setup();
+    let admin = council_admin(&env, &client);
+
+    let proposal_id = client.propose(&admin, &ProposalAction::SetFeeRate(100));
+    client.execute(&proposal_id);
+    let eta = client.get_proposal_eta(&proposal_id);
+    assert_eq!(eta, env.ledger().sequence() as u64 + TIMELOCK_DELAY as u64);
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        crate::types::ProposalStatus::Queued
+    );
+
+    // The old rate stays in force until the delay passes.
+    assert_eq!(
+        client.try_execute(&proposal_id),
+        Err(Ok(ContractError::TimelockActive))
+    );
+    assert_eq!(client.calculate_fee(&200u32), 1);
+
+    env.ledger().with_mut(|l| l.sequence_number = eta as u32);
+    client.execute(&proposal_id);
+    assert_eq!(client.calculate_fee(&200u32), 2);
+}
+
+#[test]
+fn test_council_cancels_queued_fee_rate() {
+    let (env, client) = setup();
+    let admin = council_admin(&env, &client);
+
+    let proposal_id = client.propose(&admin, &ProposalAction::SetFeeRate(100));
+    client.execute(&proposal_id);
+
+    let cancel_id = client.propose(&admin, &ProposalAction::CancelQueued(proposal_id));
+    client.execute(&cancel_id);
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        crate::types::ProposalStatus::Cancelled
+    );
+
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    assert_eq!(
+        client.try_execute(&proposal_id),
+        Err(Ok(ContractError::ProposalNotPending))
+    );
+    assert_eq!(client.calculate_fee(&200u32), 1);
+
+    let cancel_again = client.propose(&admin, &ProposalAction::CancelQueued(proposal_id));
+    assert_eq!(
+        client.try_execute(&cancel_again),
+        Err(Ok(ContractError::ProposalNotQueued))
+    );
+}
+
+#[test]
+fn test_fee_setter_role_sets_fee_rate_without_council() {
+    let (env, client) = setup();
+    let admin = council_admin(&env, &client);
+    let fee_setter = Address::generate(&env);
+
+    assert_eq!(
+        client.try_propose(&fee_setter, &ProposalAction::SetFeeRate(100)),
+        Err(Ok(ContractError::NotCouncilMember))
+    );
+
+    let grant_id = client.propose(
+        &admin,
+        &ProposalAction::GrantRole(Role::FeeSetter, fee_setter.clone()),
+    );
+    assert_eq!(execute_after_timelock(&env, &client, grant_id), Ok(()));
+    assert!(client.has_role(&Role::FeeSetter, &fee_setter));
+
+    let proposal_id = client.propose(&fee_setter, &ProposalAction::SetFeeRate(100));
+    assert_eq!(execute_after_timelock(&env, &client, proposal_id), Ok(()));
+    assert_eq!(client.calculate_fee(&200u32), 2);
+
+    let grant_again_id = client.propose(
+        &admin,
+        &ProposalAction::GrantRole(Role::FeeSetter, fee_setter.clone()),
+    );
+    assert_eq!(
+        execute_after_timelock(&env, &client, grant_again_id),
+        Err(ContractError::RoleAlreadyGranted)
+    );
+}
+
+#[test]
+fn test_action_log_records_fee_rate_changes() {
+    let (env, client) = setup();
+    let admin = council_admin(&env, &client);
+
+    set_fee_rate(&env, &client, 100);
+    set_fee_rate(&env, &client, 150);
+
+    assert_eq!(client.get_action_log_count(), 2);
+    let log = client.get_action_log(&1, &10);
+    assert_eq!(log.get(0).unwrap().action, ProposalAction::SetFeeRate(100));
+    assert_eq!(
+        log.get(1).unwrap().approvals,
+        soroban_sdk::Vec::from_array(&env, [admin])
+    );
+    assert_eq!(client.get_action_log(&2, &10).len(), 1);
+}
+
+#[test]
+fn test_distribute_shares_payout_with_delegators() {
+    let (env, client, _) = setup_with_token();
+    let registry_id = env.register(relay_registry::RelayRegistryContract, ());
+    let registry = relay_registry::RelayRegistryContractClient::new(&env, &registry_id);
+    let stake_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let registry_admin = Address::generate(&env);
+    let registry_council = relay_registry::types::AdminCouncil {
+        members: Vec::from_array(&env, [registry_admin.clone()]),
+        threshold: 1,
+    };
+    registry.initialize(
+        &registry_council,
+        &stake_token,
+        &100,
+        &10,
+        &relay_registry::types::LockUnit::Seconds,
+    );
+    let grant = registry.propose(
+        &registry_admin,
+        &relay_registry::types::ProposalAction::GrantRole(
+            relay_registry::types::Role::BatchReporter,
+            client.address.clone(),
+        ),
+    );
+    registry.execute(&grant);
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    registry.execute(&grant);
+
+    let relay = Address::generate(&env);
+    let delegator = Address::generate(&env);
+    let stake_minter = token::StellarAssetClient::new(&env, &stake_token);
+    stake_minter.mint(&relay, &100);
+    stake_minter.mint(&delegator, &200);
+    let metadata = relay_registry::types::NodeMetadata {
+        region: String::from_str(&env, "us-east"),
+        capacity: 1000,
+        uptime_commitment: 99,
+        endpoint: None,
+        software_version: None,
+        transports: Vec::new(&env),
+        contact_hash: None,
+    };
+    registry.register(&relay, &metadata);
+    registry.stake(&relay, &100);
+    registry.delegate(&delegator, &relay, &200);
+    registry.set_commission(&relay, &1000);
+
+    let admin = council_admin(&env, &client);
+    let proposal_id = client.propose(&admin, &ProposalAction::SetRegistry(registry_id.clone()));
+    execute_after_timelock(&env, &client, proposal_id).unwrap();
+    assert_eq!(client.get_registry(), Some(registry_id));
+
+    // Payout 45: delegators back 2/3 (30), less 10% commission, so they get 27.
+    client.distribute(&relay, &1, &10_000);
+    assert_eq!(client.get_earnings(&relay).unclaimed, 18);
+    assert_eq!(client.get_earnings(&delegator).unclaimed, 27);
+    assert_eq!(client.claim(&delegator), 27);
+
+    // The settled batch is reported for the relay's reputation.
+    let reputation = registry.get_reputation(&relay).unwrap();
+    assert_eq!(reputation.settled_batches, 1);
+    assert_eq!(reputation.score, relay_registry::storage::BATCH_REPUTATION);
+}
+
+/// Deploy a relay registry that lets `client` report batches, register and
+/// stake `relays` in it, and point `client` at it.
+fn setup_registry<'a>(
+    env: &Env,
+    client: &FeeDistributorContractClient,
+    relays: &Vec<Address>,
+) -> relay_registry::RelayRegistryContractClient<'a> {
+    let registry_id = env.register(relay_registry::RelayRegistryContract, ());
+    let registry = relay_registry::RelayRegistryContractClient::new(env, &registry_id);
+    let stake_token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let registry_admin = Address::generate(env);
+    let registry_council = relay_registry::types::AdminCouncil {
+        members: Vec::from_array(env, [registry_admin.clone()]),
+        threshold: 1,
+    };
+    registry.initialize(
+        &registry_council,
+        &stake_token,
+        &100,
+        &10,
+        &relay_registry::types::LockUnit::Seconds,
+    );
+    let grant = registry.propose(
+        &registry_admin,
+        &relay_registry::types::ProposalAction::GrantRole(
+            relay_registry::types::Role::BatchReporter,
+            client.address.clone(),
+        ),
+    );
+    registry.execute(&grant);
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    registry.execute(&grant);
+
+    let metadata = relay_registry::types::NodeMetadata {
+        region: String::from_str(env, "us-east"),
+        capacity: 1000,
+        uptime_commitment: 99,
+        endpoint: None,
+        software_version: None,
+        transports: Vec::new(env),
+        contact_hash: None,
+    };
+    let stake_minter = token::StellarAssetClient::new(env, &stake_token);
+    for relay in relays.iter() {
+        stake_minter.mint(&relay, &100);
+        registry.register(&relay, &metadata);
+        registry.stake(&relay, &100);
+    }
+
+    let admin = council_admin(env, client);
+    let proposal_id = client.propose(&admin, &ProposalAction::SetRegistry(registry_id));
+    execute_after_timelock(env, client, proposal_id).unwrap();
+    registry
+}
+
+#[test]
+fn test_distribute_rejects_unassigned_relay() {
+    let (env, client, _) = setup_with_token();
+    let relays = Vec::from_array(&env, [Address::generate(&env), Address::generate(&env)]);
+    let registry = setup_registry(&env, &client, &relays);
+
+    assert!(client.get_assigned_relays(&1).is_empty());
+    let admin = council_admin(&env, &client);
+    let proposal_id = client.propose(&admin, &ProposalAction::SetAssignmentSize(1));
+    execute_after_timelock(&env, &client, proposal_id).unwrap();
+    assert_eq!(client.get_assignment_size(), 1);
+
+    // Assignments are only drawn for batches a `BatchOpener` has opened.
+    assert!(client.get_assigned_relays(&1).is_empty());
+    assert_eq!(
+        client.try_distribute(&relays.get(0).unwrap(), &1, &10_000),
+        Err(Ok(ContractError::BatchNotOpened))
+    );
+    let opener = Address::generate(&env);
+    assert_eq!(
+        client.try_open_batch(&opener, &1),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    let proposal_id = client.propose(
+        &admin,
+        &ProposalAction::GrantRole(Role::BatchOpener, opener.clone()),
+    );
+    execute_after_timelock(&env, &client, proposal_id).unwrap();
+    client.open_batch(&opener, &1);
+    assert_eq!(
+        client.try_open_batch(&opener, &1),
+        Err(Ok(ContractError::BatchAlreadyOpened))
+    );
+
+    // The seed is not a function of the batch ID alone.
+    let seed = client.get_batch_seed(&1).unwrap();
+    let id_hash: BytesN<32> = env.crypto().sha256(&1u64.to_xdr(&env)).into();
+    assert_ne!(seed, id_hash);
+
+    let assigned = client.get_assigned_relays(&1);
+    assert_eq!(assigned.len(), 1);
+    let assigned = assigned.get(0).unwrap();
+    let other = relays.iter().find(|relay| *relay != assigned).unwrap();
+    assert_eq!(
+        client.try_distribute(&other, &1, &10_000),
+        Err(Ok(ContractError::RelayNotAssigned))
+    );
+
+    // Stake moved once the seed is known would win a fresh draw, but does not
+    // change the assignment stored when the batch opened.
+    token::StellarAssetClient::new(&env, &registry.get_config().token).mint(&other, &1_000_000);
+    registry.stake(&other, &1_000_000);
+    assert_eq!(
+        registry.select_relays(&Some(seed.clone()), &1, &None),
+        Vec::from_array(&env, [other.clone()])
+    );
+    assert_eq!(
+        client.get_assigned_relays(&1),
+        Vec::from_array(&env, [assigned.clone()])
+    );
+    assert_eq!(
+        client.try_distribute(&other, &1, &10_000),
+        Err(Ok(ContractError::RelayNotAssigned))
+    );
+    client.distribute(&assigned, &1, &10_000);
+    assert!(client.get_earnings(&assigned).unclaimed > 0);
+    assert_eq!(
+        client.try_open_batch(&opener, &1),
+        Err(Ok(ContractError::BatchAlreadyDistributed))
+    );
+}
+
+#[test]
+fn test_distribute_reports_paid_batches_on_a_best_effort_basis() {
+    let (env, client, _) = setup_with_token();
+    let relay = Address::generate(&env);
+    let registry = setup_registry(&env, &client, &Vec::from_array(&env, [relay.clone()]));
+
+    // The relay has to sign for its own batches.
+    client.distribute(&relay, &1, &1);
+    assert_eq!(env.auths()[0].0, relay);
+
+    // A batch too small to pay a fee earns no reputation.
+    assert_eq!(client.calculate_fee(&1), 0);
+    assert_eq!(registry.get_reputation(&relay), None);
+
+    client.distribute(&relay, &2, &10_000);
+    assert_eq!(registry.get_reputation(&relay).unwrap().settled_batches, 1);
+
+    // The registry rejects the report for a relay it does not know, but the
+    // payout still goes through.
+    let unregistered = Address::generate(&env);
+    client.distribute(&unregistered, &3, &10_000);
+    assert!(client.get_earnings(&unregistered).unclaimed > 0);
+    assert_eq!(registry.get_reputation(&unregistered), None);
+}
+
+#[test]
+fn test_migrate_earnings_follows_registry_transfer() {
+    let (env, client, _) = setup_with_token();
+    let old = Address::generate(&env);
+    let new = Address::generate(&env);
+    let registry = setup_registry(&env, &client, &Vec::from_array(&env, [old.clone()]));
+
+    client.distribute(&old, &1, &10_000);
+    let earned = client.get_earnings(&old);
+    assert!(earned.unclaimed > 0);
+    assert_eq!(
+        client.try_migrate_earnings(&old),
+        Err(Ok(ContractError::NotForwarded))
+    );
+
+    registry.propose_transfer(&old, &new);
+    registry.accept_transfer(&old, &new);
+    assert_eq!(client.migrate_earnings(&old), new);
+    assert_eq!(client.get_earnings(&new), earned);
+    assert_eq!(client.get_earnings(&old).unclaimed, 0);
+
+    // Batches reported under the old address are paid to the new one.
+    client.distribute(&old, &2, &10_000);
+    assert_eq!(client.get_earnings(&new).unclaimed, earned.unclaimed * 2);
+    assert_eq!(client.get_earnings(&old).unclaimed, 0);
+}
@@ -2,17 +2,9 @@
 //!
 //! Defines all data structures used by the Fee Distributor contract.
 
-use soroban_sdk::{contracttype, Address, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Vec};
 
-/// A multi-signature admin council requiring threshold approvals.
-#[contracttype]
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct AdminCouncil {
-    /// List of council member addresses (max 10)
-    pub members: Vec<Address>,
-    /// Minimum number of members required to authorize a sensitive action
-    pub threshold: u32,
-}
+pub use conduit_common::council::{AdminCouncil, ProposalStatus, MAX_COUNCIL_MEMBERS};
 
 /// A record of a single fee distribution event.
 ///
@@ -66,3 +58,118 @@ pub struct FeeConfig {
     /// Council authorized to update fee config via `set_fee_rate()`.
     pub council: AdminCouncil,
 }
+
+/// An entrypoint that the council can pause in an emergency.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PausableFn {
+    /// `distribute`: Record batch fees for a relay node.
+    Distribute,
+    /// `claim`: Pay out a relay node's unclaimed earnings.
+    Claim,
+    /// `open_batch`: Fix the relay assignment of a new batch.
+    OpenBatch,
+}
+
+/// A narrow permission the council can grant so that an address can perform one
+/// kind of privileged action without a council vote.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Role {
+    /// May change the protocol fee rate (`SetFeeRate`).
+    FeeSetter,
+    /// May open batches for relay assignment (`open_batch`).
+    BatchOpener,
+}
+
+/// A privileged action that can only be performed through an approved council proposal.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProposalAction {
+    /// Update the protocol fee rate, in basis points.
+    SetFeeRate(u32),
+    /// Add a member to the admin council.
+    AddMember(Address),
+    /// Remove a member from the admin council.
+    RemoveMember(Address),
+    /// Replace an existing council member with a new address.
+    ReplaceMember(Address, Address),
+    /// Change the number of approvals required to execute a proposal.
+    SetThreshold(u32),
+    /// Replace the contract WASM with an uploaded build, identified by its hash.
+    Upgrade(BytesN<32>),
+    /// Lift an emergency pause on the listed entrypoints.
+    Unpause(Vec<PausableFn>),
+    /// Cancel a queued timelocked action before it is executed.
+    CancelQueued(u64),
+    /// Grant a role to an address.
+    GrantRole(Role, Address),
+    /// Revoke a role from an address.
+    RevokeRole(Role, Address),
+    /// Set the relay registry whose delegators share in relay payouts.
+    SetRegistry(Address),
+    /// Set how many relays the registry assigns to each batch; 0 accepts any relay.
+    SetAssignmentSize(u32),
+}
+
+impl ProposalAction {
+    /// Role whose holders may perform the action without council approvals, if any.
+    pub fn required_role(&self) -> Option<Role> {
+        match self {
+            ProposalAction::SetFeeRate(_) => Some(Role::FeeSetter),
+            _ => None,
+        }
+    }
+
+    /// Whether the action waits out `TIMELOCK_DELAY` after approval before it applies.
+    pub fn is_timelocked(&self) -> bool {
+        matches!(
+            self,
+            ProposalAction::SetFeeRate(_)
+                | ProposalAction::Upgrade(_)
+                | ProposalAction::GrantRole(..)
+                | ProposalAction::AddMember(_)
+                | ProposalAction::RemoveMember(_)
+                | ProposalAction::ReplaceMember(..)
+                | ProposalAction::SetThreshold(_)
+                | ProposalAction::SetRegistry(_)
+                | ProposalAction::SetAssignmentSize(_)
+        )
+    }
+}
+
+/// A council proposal for a privileged action, approved by distinct council members.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proposal {
+    /// Unique monotonic identifier for this proposal.
+    pub id: u64,
+    /// Council member that created the proposal.
+    pub proposer: Address,
+    /// The action applied when the proposal is executed.
+    pub action: ProposalAction,
+    /// Distinct council members that approved the proposal (includes the proposer).
+    pub approvals: Vec<Address>,
+    /// Current lifecycle status of the proposal.
+    pub status: ProposalStatus,
+    /// Ledger sequence when the proposal was created.
+    pub created_at: u64,
+    /// Last ledger sequence at which the proposal can be approved or executed.
+    pub expires_at: u64,
+}
+
+/// A privileged action the contract has applied, kept as an append-only audit record.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ActionLogEntry {
+    /// Position in the action log, starting at 1.
+    pub id: u64,
+    /// Proposal that carried the action.
+    pub proposal_id: u64,
+    /// The applied action, including its arguments.
+    pub action: ProposalAction,
+    /// Addresses that approved the proposal, proposer included.
+    pub approvals: Vec<Address>,
+    /// Ledger sequence at which the action was applied.
+    pub ledger: u64,
+}
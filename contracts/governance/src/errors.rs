@@ -0,0 +1,55 @@
+//! # Governance Contract — `errors.rs`
+//!
+//! Defines all error codes returned by the Governance contract.
+//! All errors are exposed as a `ContractError` enum that maps to Soroban
+//! `contracterror` integer values consumable by clients.
+
+use soroban_sdk::contracterror;
+
+/// All error codes returned by the Governance contract.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    /// The contract has already been initialized.
+    AlreadyInitialized = 1,
+
+    /// Governance config is invalid (zero voting or execution period, non-positive quorum, etc.).
+    InvalidConfig = 2,
+
+    /// The caller's stake in the relay registry is below what the action requires.
+    InsufficientVotingPower = 3,
+
+    /// The proposal has no calls, or calls a contract that is not governed.
+    InvalidProposal = 4,
+
+    /// The referenced proposal does not exist.
+    ProposalNotFound = 5,
+
+    /// The proposal is no longer open for voting.
+    VotingClosed = 6,
+
+    /// The voter has already voted on this proposal.
+    AlreadyVoted = 7,
+
+    /// The proposal's voting period has not ended yet.
+    VotingActive = 8,
+
+    /// The proposal missed quorum or did not win a majority.
+    ProposalDefeated = 9,
+
+    /// The proposal has already been executed or cancelled.
+    ProposalNotActive = 10,
+
+    /// Caller is not authorized to perform this action.
+    Unauthorized = 11,
+
+    /// One of the proposal's contract calls failed.
+    CallFailed = 12,
+
+    /// Arithmetic overflow while tallying votes.
+    Overflow = 13,
+
+    /// The proposal passed but its execution period has ended.
+    ProposalExpired = 14,
+}
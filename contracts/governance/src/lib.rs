@@ -0,0 +1,380 @@
+//! # Governance Contract — `lib.rs`
+//!
+//! This is the main entry point for the Governance Soroban smart contract: a
+//! DAO in which the stake bonded to each relay node in the Relay Registry is
+//! its voting power. Passed proposals call entrypoints on the governed contracts.
+//!
+//! ## Responsibilities
+//! - Stake-weighted proposals and voting (`propose`, `vote`)
+//! - Quorum and majority checks once the voting period ends, and expiry of
+//!   passed proposals left unexecuted (`get_state`)
+//! - Executing passed proposals against the registry, fee distributor and
+//!   treasury (`execute`)
+//!
+//! ## Functions
+//! - `initialize(env, config)` — One-time setup of voting rules and governed contracts
+//! - `propose(env, proposer, calls, description)` — Open a proposal
+//! - `vote(env, voter, proposal_id, support)` — Vote with the voter's stake when the proposal opened
+//! - `execute(env, proposal_id)` — Make a passed proposal's calls
+//! - `cancel(env, proposer, proposal_id)` — Withdraw a proposal during voting
+//! - `get_proposal`, `get_state`, `get_vote`, `get_config`, `get_voting_power` — Views
+//!
+//! ## Authority over the governed contracts
+//! Calls are made as this contract. The treasury accepts them through
+//! `governance_execute` once its council has handed authority over with a
+//! `SetGovernance` proposal. The registry and fee distributor are reached by
+//! giving this contract a seat on their councils, so a proposal can `propose`,
+//! `approve` and `execute` council actions there.
+//!
+//! ## See also
+//! - `types.rs` — Data structures (GovernanceConfig, GovernanceCall, Proposal)
+//! - `storage.rs` — Persistent storage helpers
+//! - `errors.rs` — Contract error codes
+
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, IntoVal, String, Symbol, Val, Vec};
+
+pub mod errors;
+pub mod storage;
+pub mod types;
+
+use conduit_common::ContractEvents;
+
+use crate::errors::ContractError;
+use crate::types::{GovernanceCall, GovernanceConfig, Proposal, ProposalState, VoteReceipt};
+
+const EVENTS: ContractEvents = ContractEvents::new("governance");
+
+/// Stake bonded to `address` in the relay registry, its own plus delegated,
+/// at the end of the ledger before `start_ledger`. Stake moved in from the
+/// ledger a proposal opens in onward does not count toward it.
+fn voting_power(
+    env: &Env,
+    config: &GovernanceConfig,
+    address: &Address,
+    start_ledger: u64,
+) -> i128 {
+    let snapshot_ledger = (start_ledger as u32).saturating_sub(1);
+    env.invoke_contract::<i128>(
+        &config.registry,
+        &Symbol::new(env, "get_bonded_stake_at"),
+        vec![env, address.into_val(env), snapshot_ledger.into_val(env)],
+    )
+}
+
+/// State of `proposal` at the current ledger, resolving an ended vote into
+/// `Succeeded` or `Defeated`, and a passed one left unexecuted past
+/// `execution_period` into `Expired`.
+fn resolve_state(env: &Env, config: &GovernanceConfig, proposal: &Proposal) -> ProposalState {
+    if proposal.state != ProposalState::Active {
+        return proposal.state.clone();
+    }
+    let sequence = env.ledger().sequence() as u64;
+    if sequence <= proposal.end_ledger {
+        return ProposalState::Active;
+    }
+
+    let turnout = proposal.votes_for.saturating_add(proposal.votes_against);
+    if turnout < config.quorum || proposal.votes_for <= proposal.votes_against {
+        ProposalState::Defeated
+    } else if sequence > proposal.end_ledger + config.execution_period as u64 {
+        ProposalState::Expired
+    } else {
+        ProposalState::Succeeded
+    }
+}
+
+#[contract]
+pub struct GovernanceContract;
+
+#[contractimpl]
+impl GovernanceContract {
+    /// One-time setup of the voting rules and the governed contracts.
+    ///
+    /// First caller wins; no auth required.
+    ///
+    /// # Errors
+    /// - `ContractError::AlreadyInitialized` if the contract is already initialized.
+    /// - `ContractError::InvalidConfig` if `voting_period` or `execution_period` is
+    ///   zero, `quorum` is not positive, or `proposal_threshold` is negative.
+    pub fn initialize(env: Env, config: GovernanceConfig) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        if storage::has_config(&env) {
+            return Err(ContractError::AlreadyInitialized);
+        }
+        if config.voting_period == 0
+            || config.execution_period == 0
+            || config.quorum <= 0
+            || config.proposal_threshold < 0
+        {
+            return Err(ContractError::InvalidConfig);
+        }
+
+        storage::set_config(&env, &config);
+
+        Ok(())
+    }
+
+    /// Open a proposal to call one or more governed contracts.
+    ///
+    /// Voting opens immediately and lasts `voting_period` ledgers.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `proposer`: Relay node opening the proposal. Must authorize this call.
+    /// - `calls`: Calls to make, in order, if the proposal passes.
+    /// - `description`: Human-readable summary of the proposal.
+    ///
+    /// # Returns
+    /// The newly assigned `proposal_id`.
+    ///
+    /// # Errors
+    /// - `ContractError::InvalidProposal` if `calls` is empty or targets a contract
+    ///   other than the registry, fee distributor or treasury.
+    /// - `ContractError::InsufficientVotingPower` if the proposer's bonded stake at
+    ///   the end of the previous ledger is below `proposal_threshold`.
+    pub fn propose(
+        env: Env,
+        proposer: Address,
+        calls: Vec<GovernanceCall>,
+        description: String,
+    ) -> Result<u64, ContractError> {
+        storage::extend_instance_ttl(&env);
+        proposer.require_auth();
+        let config = storage::get_config(&env);
+
+        if calls.is_empty() {
+            return Err(ContractError::InvalidProposal);
+        }
+        for call in calls.iter() {
+            if call.contract != config.registry
+                && call.contract != config.fee_distributor
+                && call.contract != config.treasury
+            {
+                return Err(ContractError::InvalidProposal);
+            }
+        }
+
+        let start_ledger = env.ledger().sequence() as u64;
+        if voting_power(&env, &config, &proposer, start_ledger) < config.proposal_threshold {
+            return Err(ContractError::InsufficientVotingPower);
+        }
+
+        let proposal = Proposal {
+            id: storage::next_proposal_id(&env),
+            proposer: proposer.clone(),
+            calls,
+            description,
+            votes_for: 0,
+            votes_against: 0,
+            start_ledger,
+            end_ledger: start_ledger + config.voting_period as u64,
+            state: ProposalState::Active,
+        };
+        storage::set_proposal(&env, &proposal);
+
+        EVENTS.publish(
+            &env,
+            "propose",
+            (proposer, proposal.id, proposal.end_ledger),
+        );
+
+        Ok(proposal.id)
+    }
+
+    /// Vote on an open proposal, weighted by the stake bonded to the voter —
+    /// its own plus delegated — at the end of the ledger before the proposal
+    /// opened.
+    ///
+    /// Stake bonded or moved between nodes after that does not change anyone's
+    /// power on the proposal. The vote is recorded with the registry's
+    /// `record_vote`, which keeps the node from accepting an ownership transfer
+    /// until voting ends; this contract must hold the registry's `VoteRecorder`
+    /// role.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `voter`: Relay node casting the vote. Must authorize this call.
+    /// - `proposal_id`: ID of the proposal to vote on.
+    /// - `support`: `true` to vote in favour, `false` to vote against.
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    /// - `ContractError::VotingClosed` if the proposal was cancelled or its voting period ended.
+    /// - `ContractError::AlreadyVoted` if `voter` already voted on this proposal.
+    /// - `ContractError::InsufficientVotingPower` if `voter` had no bonded stake
+    ///   when the proposal opened.
+    /// - `ContractError::Overflow` if the tally overflows.
+    pub fn vote(
+        env: Env,
+        voter: Address,
+        proposal_id: u64,
+        support: bool,
+    ) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        voter.require_auth();
+        let config = storage::get_config(&env);
+
+        let mut proposal =
+            storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+        if resolve_state(&env, &config, &proposal) != ProposalState::Active {
+            return Err(ContractError::VotingClosed);
+        }
+        if storage::get_vote(&env, proposal_id, &voter).is_some() {
+            return Err(ContractError::AlreadyVoted);
+        }
+
+        let power = voting_power(&env, &config, &voter, proposal.start_ledger);
+        if power <= 0 {
+            return Err(ContractError::InsufficientVotingPower);
+        }
+
+        let tally = if support {
+            &mut proposal.votes_for
+        } else {
+            &mut proposal.votes_against
+        };
+        *tally = tally.checked_add(power).ok_or(ContractError::Overflow)?;
+
+        env.invoke_contract::<()>(
+            &config.registry,
+            &Symbol::new(&env, "record_vote"),
+            vec![
+                &env,
+                env.current_contract_address().into_val(&env),
+                voter.into_val(&env),
+                proposal.end_ledger.into_val(&env),
+            ],
+        );
+
+        storage::set_proposal(&env, &proposal);
+        storage::set_vote(&env, proposal_id, &voter, &VoteReceipt { support, power });
+
+        EVENTS.publish(&env, "vote", (voter, proposal_id, support, power));
+
+        Ok(())
+    }
+
+    /// Make a passed proposal's calls. Anyone may call this once voting has
+    /// ended, for `execution_period` ledgers.
+    ///
+    /// The calls run in order as this contract; if any of them fails, the whole
+    /// execution is reverted and the proposal can be executed again later.
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    /// - `ContractError::VotingActive` if the voting period has not ended.
+    /// - `ContractError::ProposalDefeated` if the proposal missed quorum or majority.
+    /// - `ContractError::ProposalExpired` if the execution period has ended.
+    /// - `ContractError::ProposalNotActive` if the proposal was executed or cancelled.
+    /// - `ContractError::CallFailed` if a call to a governed contract fails.
+    pub fn execute(env: Env, proposal_id: u64) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        let config = storage::get_config(&env);
+
+        let mut proposal =
+            storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+        match resolve_state(&env, &config, &proposal) {
+            ProposalState::Succeeded => {}
+            ProposalState::Active => return Err(ContractError::VotingActive),
+            ProposalState::Defeated => return Err(ContractError::ProposalDefeated),
+            ProposalState::Expired => return Err(ContractError::ProposalExpired),
+            ProposalState::Executed | ProposalState::Cancelled => {
+                return Err(ContractError::ProposalNotActive)
+            }
+        }
+
+        proposal.state = ProposalState::Executed;
+        storage::set_proposal(&env, &proposal);
+
+        for call in proposal.calls.iter() {
+            let result = env.try_invoke_contract::<Val, soroban_sdk::Error>(
+                &call.contract,
+                &call.function,
+                call.args,
+            );
+            if !matches!(result, Ok(Ok(_))) {
+                return Err(ContractError::CallFailed);
+            }
+        }
+
+        EVENTS.publish(&env, "execute", (proposal_id,));
+
+        Ok(())
+    }
+
+    /// Withdraw a proposal while voting is still open. Only the proposer may cancel it.
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    /// - `ContractError::Unauthorized` if `proposer` did not create the proposal.
+    /// - `ContractError::ProposalNotActive` if the proposal was executed or cancelled.
+    /// - `ContractError::VotingClosed` if the voting period has ended.
+    pub fn cancel(env: Env, proposer: Address, proposal_id: u64) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        proposer.require_auth();
+
+        let mut proposal =
+            storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+        if proposal.proposer != proposer {
+            return Err(ContractError::Unauthorized);
+        }
+        if proposal.state != ProposalState::Active {
+            return Err(ContractError::ProposalNotActive);
+        }
+        if env.ledger().sequence() as u64 > proposal.end_ledger {
+            return Err(ContractError::VotingClosed);
+        }
+
+        proposal.state = ProposalState::Cancelled;
+        storage::set_proposal(&env, &proposal);
+
+        EVENTS.publish(&env, "cancel", (proposer, proposal_id));
+
+        Ok(())
+    }
+
+    /// Fetch a governance proposal by its ID.
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Result<Proposal, ContractError> {
+        storage::extend_instance_ttl(&env);
+        storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)
+    }
+
+    /// Current state of a proposal, including the outcome of an ended vote.
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    pub fn get_state(env: Env, proposal_id: u64) -> Result<ProposalState, ContractError> {
+        storage::extend_instance_ttl(&env);
+        let proposal =
+            storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+        Ok(resolve_state(&env, &storage::get_config(&env), &proposal))
+    }
+
+    /// The vote `voter` cast on a proposal, if any.
+    pub fn get_vote(env: Env, proposal_id: u64, voter: Address) -> Option<VoteReceipt> {
+        storage::extend_instance_ttl(&env);
+        storage::get_vote(&env, proposal_id, &voter)
+    }
+
+    /// Voting rules and governed contract addresses.
+    pub fn get_config(env: Env) -> GovernanceConfig {
+        storage::extend_instance_ttl(&env);
+        storage::get_config(&env)
+    }
+
+    /// Voting power `address` would have on a proposal opened now: the stake
+    /// bonded to it in the relay registry at the end of the previous ledger.
+    pub fn get_voting_power(env: Env, address: Address) -> i128 {
+        storage::extend_instance_ttl(&env);
+        let start_ledger = env.ledger().sequence() as u64;
+        voting_power(&env, &storage::get_config(&env), &address, start_ledger)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,91 @@
+//! # Governance Contract — `storage.rs`
+//!
+//! Provides typed helper functions for reading and writing persistent contract
+//! storage using Soroban's `Env::storage()` API.
+//!
+//! ## Storage keys
+//! - `DataKey::Config` — The `GovernanceConfig` set at initialization
+//! - `DataKey::Proposal(u64)` — A governance `Proposal` keyed by proposal_id
+//! - `DataKey::ProposalCount` — Monotonically incrementing proposal ID counter
+//! - `DataKey::Vote(u64, Address)` — A voter's `VoteReceipt` on a proposal
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::types::{GovernanceConfig, Proposal, VoteReceipt};
+
+pub use conduit_common::ttl::extend_instance_ttl;
+use conduit_common::ttl::extend_persistent_ttl;
+
+/// Storage keys for the governance contract.
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    /// Voting rules and governed contract addresses.
+    Config,
+    /// A governance Proposal keyed by proposal_id.
+    Proposal(u64),
+    /// Total number of created proposals.
+    ProposalCount,
+    /// A voter's receipt, keyed by proposal_id and voter.
+    Vote(u64, Address),
+}
+
+pub fn has_config(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Config)
+}
+
+pub fn get_config(env: &Env) -> GovernanceConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config)
+        .expect("governance config not initialized")
+}
+
+pub fn set_config(env: &Env, config: &GovernanceConfig) {
+    env.storage().instance().set(&DataKey::Config, config);
+}
+
+pub fn get_proposal(env: &Env, proposal_id: u64) -> Option<Proposal> {
+    let key = DataKey::Proposal(proposal_id);
+    if let Some(proposal) = env.storage().persistent().get::<_, Proposal>(&key) {
+        extend_persistent_ttl(env, &key);
+        Some(proposal)
+    } else {
+        None
+    }
+}
+
+pub fn set_proposal(env: &Env, proposal: &Proposal) {
+    let key = DataKey::Proposal(proposal.id);
+    env.storage().persistent().set(&key, proposal);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Increment and return the next available proposal ID.
+pub fn next_proposal_id(env: &Env) -> u64 {
+    let next = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&DataKey::ProposalCount)
+        .unwrap_or(0)
+        .checked_add(1)
+        .expect("proposal count overflow");
+    env.storage().instance().set(&DataKey::ProposalCount, &next);
+    next
+}
+
+pub fn get_vote(env: &Env, proposal_id: u64, voter: &Address) -> Option<VoteReceipt> {
+    let key = DataKey::Vote(proposal_id, voter.clone());
+    if let Some(receipt) = env.storage().persistent().get::<_, VoteReceipt>(&key) {
+        extend_persistent_ttl(env, &key);
+        Some(receipt)
+    } else {
+        None
+    }
+}
+
+pub fn set_vote(env: &Env, proposal_id: u64, voter: &Address, receipt: &VoteReceipt) {
+    let key = DataKey::Vote(proposal_id, voter.clone());
+    env.storage().persistent().set(&key, receipt);
+    extend_persistent_ttl(env, &key);
+}
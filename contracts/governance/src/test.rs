@@ -0,0 +1,581 @@
+//! # Governance — Unit Test Suite
+//!
+//! Tests for stake-weighted proposals, voting, and execution against the
+//! relay registry, fee distributor and treasury contracts.
+
+extern crate std;
+
+use conduit_common::council::TIMELOCK_DELAY;
+use fee_distributor::{FeeDistributorContract, FeeDistributorContractClient};
+use relay_registry::{types::NodeMetadata, RelayRegistryContract, RelayRegistryContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, IntoVal, String, Symbol, Vec,
+};
+use treasury::{TreasuryContract, TreasuryContractClient};
+
+use crate::{
+    errors::ContractError,
+    types::{GovernanceCall, GovernanceConfig, ProposalState, VoteReceipt},
+    GovernanceContract, GovernanceContractClient,
+};
+
+const VOTING_PERIOD: u32 = 100;
+const EXECUTION_PERIOD: u32 = 2 * TIMELOCK_DELAY;
+
+struct Protocol<'a> {
+    env: Env,
+    governance: GovernanceContractClient<'a>,
+    registry: RelayRegistryContractClient<'a>,
+    fees: FeeDistributorContractClient<'a>,
+    treasury: TreasuryContractClient<'a>,
+    token: StellarAssetClient<'a>,
+    council_admin: Address,
+}
+
+/// Deploy the governed contracts and a governance contract with a quorum of 300
+/// staked tokens and a proposal threshold of 100. The governance contract holds
+/// the only seat on the fee distributor's council.
+fn setup<'a>() -> Protocol<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    let council_admin = Address::generate(&env);
+    let council = |member: &Address| relay_registry::types::AdminCouncil {
+        members: Vec::from_array(&env, [member.clone()]),
+        threshold: 1,
+    };
+
+    let token_id = env
+        .register_stellar_asset_contract_v2(council_admin.clone())
+        .address();
+    let token = StellarAssetClient::new(&env, &token_id);
+
+    let governance = GovernanceContractClient::new(&env, &env.register(GovernanceContract, ()));
+
+    let registry = RelayRegistryContractClient::new(&env, &env.register(RelayRegistryContract, ()));
+    registry.initialize(
+        &council(&council_admin),
+        &token_id,
+        &100,
+        &10,
+        &relay_registry::types::LockUnit::Seconds,
+    );
+
+    let treasury = TreasuryContractClient::new(&env, &env.register(TreasuryContract, ()));
+    treasury.initialize(&council(&council_admin), &token_id);
+    token.mint(&council_admin, &5_000);
+    treasury.deposit(&council_admin, &5_000);
+
+    let fees = FeeDistributorContractClient::new(&env, &env.register(FeeDistributorContract, ()));
+    fees.initialize(
+        &council(&governance.address),
+        &50,
+        &1_000,
+        &treasury.address,
+        &token_id,
+    );
+
+    governance.initialize(&GovernanceConfig {
+        registry: registry.address.clone(),
+        fee_distributor: fees.address.clone(),
+        treasury: treasury.address.clone(),
+        voting_period: VOTING_PERIOD,
+        quorum: 300,
+        proposal_threshold: 100,
+        execution_period: EXECUTION_PERIOD,
+    });
+
+    // Votes lock the voting node against transfers in the registry.
+    let grant = relay_registry::types::ProposalAction::GrantRole(
+        relay_registry::types::Role::VoteRecorder,
+        governance.address.clone(),
+    );
+    let proposal_id = registry.propose(&council_admin, &grant);
+    registry.execute(&proposal_id);
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    registry.execute(&proposal_id);
+
+    Protocol {
+        env,
+        governance,
+        registry,
+        fees,
+        treasury,
+        token,
+        council_admin,
+    }
+}
+
+/// Register a relay node and stake `amount` for it, then close the ledger so
+/// the stake counts toward proposals opened from the next one.
+fn staked_node(p: &Protocol, amount: i128) -> Address {
+    let node = Address::generate(&p.env);
+    p.registry.register(
+        &node,
+        &NodeMetadata {
+            region: String::from_str(&p.env, "us-east"),
+            capacity: 1000,
+            uptime_commitment: 99,
+            endpoint: None,
+            software_version: None,
+            transports: Vec::new(&p.env),
+            contact_hash: None,
+        },
+    );
+    p.token.mint(&node, &amount);
+    p.registry.stake(&node, &amount);
+    p.env.ledger().with_mut(|l| l.sequence_number += 1);
+    node
+}
+
+/// Have the treasury council hand authority to the governance contract.
+fn hand_over_treasury(p: &Protocol) {
+    let action = treasury::types::ProposalAction::SetGovernance(p.governance.address.clone());
+    let proposal_id = p.treasury.propose(&p.council_admin, &action);
+    p.treasury.execute(&proposal_id);
+    p.env
+        .ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    p.treasury.execute(&proposal_id);
+}
+
+/// Wait out the treasury timelock and apply an action governance queued there.
+fn apply_queued_treasury_action(p: &Protocol, treasury_proposal_id: u64) {
+    assert_eq!(
+        p.treasury.get_proposal(&treasury_proposal_id).status,
+        treasury::types::ProposalStatus::Queued
+    );
+    p.env
+        .ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    p.treasury.execute(&treasury_proposal_id);
+}
+
+/// A single call to the treasury's `governance_execute` with `action`.
+fn treasury_call(p: &Protocol, action: treasury::types::ProposalAction) -> Vec<GovernanceCall> {
+    vec![
+        &p.env,
+        GovernanceCall {
+            contract: p.treasury.address.clone(),
+            function: Symbol::new(&p.env, "governance_execute"),
+            args: vec![&p.env, action.into_val(&p.env)],
+        },
+    ]
+}
+
+fn withdraw_calls(p: &Protocol, to: &Address, amount: i128) -> Vec<GovernanceCall> {
+    treasury_call(
+        p,
+        treasury::types::ProposalAction::Withdraw(
+            to.clone(),
+            amount,
+            String::from_str(&p.env, "grant"),
+        ),
+    )
+}
+
+fn end_voting(env: &Env) {
+    env.ledger()
+        .with_mut(|l| l.sequence_number += VOTING_PERIOD + 1);
+}
+
+#[test]
+fn test_initialize_validates_config() {
+    let p = setup();
+    let config = p.governance.get_config();
+    assert_eq!(
+        p.governance.try_initialize(&config),
+        Err(Ok(ContractError::AlreadyInitialized))
+    );
+
+    let fresh = GovernanceContractClient::new(&p.env, &p.env.register(GovernanceContract, ()));
+    let mut invalid = config.clone();
+    invalid.voting_period = 0;
+    assert_eq!(
+        fresh.try_initialize(&invalid),
+        Err(Ok(ContractError::InvalidConfig))
+    );
+    let mut invalid = config;
+    invalid.quorum = 0;
+    assert_eq!(
+        fresh.try_initialize(&invalid),
+        Err(Ok(ContractError::InvalidConfig))
+    );
+}
+
+#[test]
+fn test_propose_requires_stake_and_governed_target() {
+    let p = setup();
+    let small = staked_node(&p, 50);
+    let proposer = staked_node(&p, 200);
+    let to = Address::generate(&p.env);
+    let description = String::from_str(&p.env, "grant");
+
+    assert_eq!(
+        p.governance
+            .try_propose(&small, &withdraw_calls(&p, &to, 100), &description),
+        Err(Ok(ContractError::InsufficientVotingPower))
+    );
+    assert_eq!(
+        p.governance
+            .try_propose(&proposer, &Vec::new(&p.env), &description),
+        Err(Ok(ContractError::InvalidProposal))
+    );
+
+    let ungoverned = vec![
+        &p.env,
+        GovernanceCall {
+            contract: Address::generate(&p.env),
+            function: Symbol::new(&p.env, "anything"),
+            args: Vec::new(&p.env),
+        },
+    ];
+    assert_eq!(
+        p.governance
+            .try_propose(&proposer, &ungoverned, &description),
+        Err(Ok(ContractError::InvalidProposal))
+    );
+
+    let proposal_id = p
+        .governance
+        .propose(&proposer, &withdraw_calls(&p, &to, 100), &description);
+    let proposal = p.governance.get_proposal(&proposal_id);
+    assert_eq!(proposal.id, 1);
+    assert_eq!(
+        proposal.end_ledger,
+        proposal.start_ledger + VOTING_PERIOD as u64
+    );
+    assert_eq!(p.governance.get_state(&proposal_id), ProposalState::Active);
+}
+
+#[test]
+fn test_votes_are_weighted_by_stake_once_per_voter() {
+    let p = setup();
+    let alice = staked_node(&p, 200);
+    let bob = staked_node(&p, 150);
+    let outsider = Address::generate(&p.env);
+    let proposal_id = p.governance.propose(
+        &alice,
+        &withdraw_calls(&p, &alice, 100),
+        &String::from_str(&p.env, "grant"),
+    );
+
+    assert_eq!(p.governance.get_voting_power(&alice), 200);
+    p.governance.vote(&alice, &proposal_id, &true);
+    p.governance.vote(&bob, &proposal_id, &false);
+
+    let proposal = p.governance.get_proposal(&proposal_id);
+    assert_eq!(proposal.votes_for, 200);
+    assert_eq!(proposal.votes_against, 150);
+    assert_eq!(
+        p.governance.get_vote(&proposal_id, &bob),
+        Some(VoteReceipt {
+            support: false,
+            power: 150
+        })
+    );
+    assert_eq!(p.governance.get_vote(&proposal_id, &outsider), None);
+
+    assert_eq!(
+        p.governance.try_vote(&alice, &proposal_id, &true),
+        Err(Ok(ContractError::AlreadyVoted))
+    );
+    assert_eq!(
+        p.governance.try_vote(&outsider, &proposal_id, &true),
+        Err(Ok(ContractError::InsufficientVotingPower))
+    );
+
+    end_voting(&p.env);
+    assert_eq!(
+        p.governance
+            .try_vote(&staked_node(&p, 100), &proposal_id, &true),
+        Err(Ok(ContractError::VotingClosed))
+    );
+}
+
+#[test]
+fn test_vote_cannot_be_repeated_across_a_transfer() {
+    let p = setup();
+    let alice = staked_node(&p, 200);
+    let buyer = Address::generate(&p.env);
+    let proposal_id = p.governance.propose(
+        &alice,
+        &withdraw_calls(&p, &alice, 100),
+        &String::from_str(&p.env, "grant"),
+    );
+    p.governance.vote(&alice, &proposal_id, &true);
+
+    // The node cannot move to a fresh address while the proposal is open, so
+    // its stake cannot be counted a second time from there.
+    p.registry.propose_transfer(&alice, &buyer);
+    assert_eq!(
+        p.registry.try_accept_transfer(&alice, &buyer),
+        Err(Ok(relay_registry::errors::ContractError::StakeLocked))
+    );
+    assert_eq!(
+        p.governance.try_vote(&buyer, &proposal_id, &true),
+        Err(Ok(ContractError::InsufficientVotingPower))
+    );
+    assert_eq!(p.governance.get_proposal(&proposal_id).votes_for, 200);
+
+    // Once voting has ended the transfer goes through.
+    end_voting(&p.env);
+    p.registry.accept_transfer(&alice, &buyer);
+    p.env.ledger().with_mut(|l| l.sequence_number += 1);
+    assert_eq!(p.governance.get_voting_power(&buyer), 200);
+    assert_eq!(
+        p.governance.try_vote(&buyer, &proposal_id, &true),
+        Err(Ok(ContractError::VotingClosed))
+    );
+}
+
+#[test]
+fn test_voting_power_is_bonded_stake_when_the_proposal_opened() {
+    let p = setup();
+    let alice = staked_node(&p, 200);
+    let bob = staked_node(&p, 150);
+    let delegator = Address::generate(&p.env);
+    p.token.mint(&delegator, &500);
+    p.registry.delegate(&delegator, &bob, &100);
+    p.env.ledger().with_mut(|l| l.sequence_number += 1);
+    assert_eq!(p.governance.get_voting_power(&bob), 250);
+
+    let proposal_id = p.governance.propose(
+        &alice,
+        &withdraw_calls(&p, &alice, 100),
+        &String::from_str(&p.env, "grant"),
+    );
+
+    // Stake bonded once the proposal is open does not count toward it, even
+    // when it is added in the same ledger.
+    p.token.mint(&alice, &300);
+    p.registry.stake(&alice, &300);
+    p.registry.delegate(&delegator, &bob, &400);
+    let latecomer = staked_node(&p, 1_000);
+
+    p.governance.vote(&alice, &proposal_id, &true);
+    p.governance.vote(&bob, &proposal_id, &false);
+    assert_eq!(
+        p.governance.try_vote(&latecomer, &proposal_id, &true),
+        Err(Ok(ContractError::InsufficientVotingPower))
+    );
+    let proposal = p.governance.get_proposal(&proposal_id);
+    assert_eq!(proposal.votes_for, 200);
+    assert_eq!(proposal.votes_against, 250);
+
+    // A later proposal sees the new stake.
+    assert_eq!(p.governance.get_voting_power(&alice), 500);
+    assert_eq!(p.governance.get_voting_power(&bob), 650);
+}
+
+#[test]
+fn test_passed_proposal_expires_after_execution_period() {
+    let p = setup();
+    hand_over_treasury(&p);
+    let alice = staked_node(&p, 400);
+    let proposal_id = p.governance.propose(
+        &alice,
+        &withdraw_calls(&p, &alice, 100),
+        &String::from_str(&p.env, "grant"),
+    );
+    p.governance.vote(&alice, &proposal_id, &true);
+    end_voting(&p.env);
+
+    p.env
+        .ledger()
+        .with_mut(|l| l.sequence_number += EXECUTION_PERIOD - 1);
+    assert_eq!(
+        p.governance.get_state(&proposal_id),
+        ProposalState::Succeeded
+    );
+    p.env.ledger().with_mut(|l| l.sequence_number += 1);
+    assert_eq!(p.governance.get_state(&proposal_id), ProposalState::Expired);
+    assert_eq!(
+        p.governance.try_execute(&proposal_id),
+        Err(Ok(ContractError::ProposalExpired))
+    );
+    assert_eq!(p.treasury.get_balance(), 5_000);
+}
+
+#[test]
+fn test_passed_proposal_withdraws_from_treasury_after_handover() {
+    let p = setup();
+    hand_over_treasury(&p);
+    let alice = staked_node(&p, 200);
+    let bob = staked_node(&p, 150);
+    let recipient = Address::generate(&p.env);
+
+    let proposal_id = p.governance.propose(
+        &alice,
+        &withdraw_calls(&p, &recipient, 1_000),
+        &String::from_str(&p.env, "regional grant"),
+    );
+    p.governance.vote(&alice, &proposal_id, &true);
+    p.governance.vote(&bob, &proposal_id, &true);
+    assert_eq!(
+        p.governance.try_execute(&proposal_id),
+        Err(Ok(ContractError::VotingActive))
+    );
+
+    end_voting(&p.env);
+    assert_eq!(
+        p.governance.get_state(&proposal_id),
+        ProposalState::Succeeded
+    );
+    p.governance.execute(&proposal_id);
+    // The withdrawal is the treasury's second proposal, after the handover.
+    assert_eq!(p.treasury.get_balance(), 5_000);
+    apply_queued_treasury_action(&p, 2);
+
+    let token = TokenClient::new(&p.env, &p.token.address);
+    assert_eq!(token.balance(&recipient), 1_000);
+    assert_eq!(p.treasury.get_balance(), 4_000);
+    assert_eq!(
+        p.governance.get_state(&proposal_id),
+        ProposalState::Executed
+    );
+    assert_eq!(
+        p.governance.try_execute(&proposal_id),
+        Err(Ok(ContractError::ProposalNotActive))
+    );
+}
+
+#[test]
+fn test_proposal_without_quorum_or_majority_is_defeated() {
+    let p = setup();
+    hand_over_treasury(&p);
+    let alice = staked_node(&p, 200);
+    let bob = staked_node(&p, 250);
+    let recipient = Address::generate(&p.env);
+    let description = String::from_str(&p.env, "grant");
+
+    // 200 of the 300 quorum votes.
+    let no_quorum =
+        p.governance
+            .propose(&alice, &withdraw_calls(&p, &recipient, 100), &description);
+    p.governance.vote(&alice, &no_quorum, &true);
+
+    // Quorum is met but the majority is against.
+    let no_majority =
+        p.governance
+            .propose(&alice, &withdraw_calls(&p, &recipient, 100), &description);
+    p.governance.vote(&alice, &no_majority, &true);
+    p.governance.vote(&bob, &no_majority, &false);
+
+    end_voting(&p.env);
+    for proposal_id in [no_quorum, no_majority] {
+        assert_eq!(
+            p.governance.get_state(&proposal_id),
+            ProposalState::Defeated
+        );
+        assert_eq!(
+            p.governance.try_execute(&proposal_id),
+            Err(Ok(ContractError::ProposalDefeated))
+        );
+    }
+    assert_eq!(p.treasury.get_balance(), 5_000);
+}
+
+#[test]
+fn test_failed_call_reverts_execution() {
+    let p = setup();
+    let alice = staked_node(&p, 400);
+    let recipient = Address::generate(&p.env);
+
+    // The treasury council has not handed over authority yet.
+    let proposal_id = p.governance.propose(
+        &alice,
+        &withdraw_calls(&p, &recipient, 1_000),
+        &String::from_str(&p.env, "grant"),
+    );
+    p.governance.vote(&alice, &proposal_id, &true);
+    end_voting(&p.env);
+    assert_eq!(
+        p.governance.try_execute(&proposal_id),
+        Err(Ok(ContractError::CallFailed))
+    );
+    assert_eq!(
+        p.governance.get_state(&proposal_id),
+        ProposalState::Succeeded
+    );
+
+    hand_over_treasury(&p);
+    p.governance.execute(&proposal_id);
+    apply_queued_treasury_action(&p, 2);
+    assert_eq!(p.treasury.get_balance(), 4_000);
+}
+
+#[test]
+fn test_governance_sets_fee_rate_through_council_seat() {
+    let p = setup();
+    let alice = staked_node(&p, 400);
+    let fee_proposal_id: u64 = 1;
+    let calls = vec![
+        &p.env,
+        GovernanceCall {
+            contract: p.fees.address.clone(),
+            function: Symbol::new(&p.env, "propose"),
+            args: vec![
+                &p.env,
+                p.governance.address.into_val(&p.env),
+                fee_distributor::types::ProposalAction::SetFeeRate(200).into_val(&p.env),
+            ],
+        },
+        GovernanceCall {
+            contract: p.fees.address.clone(),
+            function: Symbol::new(&p.env, "execute"),
+            args: vec![&p.env, fee_proposal_id.into_val(&p.env)],
+        },
+    ];
+
+    let proposal_id =
+        p.governance
+            .propose(&alice, &calls, &String::from_str(&p.env, "raise fee rate"));
+    p.governance.vote(&alice, &proposal_id, &true);
+    end_voting(&p.env);
+    p.governance.execute(&proposal_id);
+
+    // The fee change waits out the fee distributor's timelock like any council action.
+    assert_eq!(p.fees.calculate_fee(&1_000), 5);
+    p.env
+        .ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    p.fees.execute(&fee_proposal_id);
+    assert_eq!(p.fees.calculate_fee(&1_000), 20);
+}
+
+#[test]
+fn test_cancel_only_by_proposer_during_voting() {
+    let p = setup();
+    let alice = staked_node(&p, 200);
+    let bob = staked_node(&p, 200);
+    let description = String::from_str(&p.env, "grant");
+    let proposal_id = p
+        .governance
+        .propose(&alice, &withdraw_calls(&p, &alice, 100), &description);
+
+    assert_eq!(
+        p.governance.try_cancel(&bob, &proposal_id),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    p.governance.cancel(&alice, &proposal_id);
+    assert_eq!(
+        p.governance.get_state(&proposal_id),
+        ProposalState::Cancelled
+    );
+    assert_eq!(
+        p.governance.try_vote(&bob, &proposal_id, &true),
+        Err(Ok(ContractError::VotingClosed))
+    );
+
+    let ended = p
+        .governance
+        .propose(&alice, &withdraw_calls(&p, &alice, 100), &description);
+    end_voting(&p.env);
+    assert_eq!(
+        p.governance.try_cancel(&alice, &ended),
+        Err(Ok(ContractError::VotingClosed))
+    );
+}
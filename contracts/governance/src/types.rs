@@ -0,0 +1,94 @@
+//! # Governance Contract — `types.rs`
+//!
+//! Defines all data structures used by the Governance contract.
+//! Purely type definitions — no logic.
+
+use soroban_sdk::{contracttype, Address, String, Symbol, Val, Vec};
+
+/// Voting rules and the contracts the DAO governs.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GovernanceConfig {
+    /// Relay Registry contract; the stake bonded to a node, its own plus
+    /// delegated, when a proposal opens is its voting power on it.
+    pub registry: Address,
+    /// Fee Distributor contract that proposals may call.
+    pub fee_distributor: Address,
+    /// Treasury contract that proposals may call.
+    pub treasury: Address,
+    /// Number of ledgers a proposal stays open for voting.
+    pub voting_period: u32,
+    /// Minimum total stake (for and against) that must vote for a proposal to pass.
+    pub quorum: i128,
+    /// Minimum stake required to open a proposal.
+    pub proposal_threshold: i128,
+    /// Number of ledgers after voting ends during which a passed proposal can
+    /// be executed.
+    pub execution_period: u32,
+}
+
+/// A contract call made on the governed contracts when a proposal executes.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GovernanceCall {
+    /// Contract to call: the registry, fee distributor or treasury.
+    pub contract: Address,
+    /// Entrypoint name.
+    pub function: Symbol,
+    /// Entrypoint arguments.
+    pub args: Vec<Val>,
+}
+
+/// Lifecycle state of a governance proposal.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProposalState {
+    /// Voting is open.
+    Active,
+    /// Voting ended without quorum or without a majority in favour.
+    Defeated,
+    /// Voting ended with quorum and a majority in favour; ready to execute.
+    Succeeded,
+    /// The proposal's calls were made.
+    Executed,
+    /// The proposal passed but was not executed within `execution_period`.
+    Expired,
+    /// The proposer withdrew the proposal during voting.
+    Cancelled,
+}
+
+/// A stake-weighted proposal to call one or more governed contracts.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proposal {
+    /// Unique monotonic identifier for this proposal.
+    pub id: u64,
+    /// Relay node that opened the proposal.
+    pub proposer: Address,
+    /// Calls made, in order, when the proposal executes.
+    pub calls: Vec<GovernanceCall>,
+    /// Human-readable summary of the proposal.
+    pub description: String,
+    /// Total stake voting in favour.
+    pub votes_for: i128,
+    /// Total stake voting against.
+    pub votes_against: i128,
+    /// Ledger sequence when voting opened. Voting power is the bonded stake
+    /// at the end of the ledger before it.
+    pub start_ledger: u64,
+    /// Last ledger sequence at which votes are accepted.
+    pub end_ledger: u64,
+    /// Stored state: `Active`, `Executed` or `Cancelled`. See `get_state` for
+    /// the outcome of a proposal whose voting period has ended.
+    pub state: ProposalState,
+}
+
+/// A recorded vote.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VoteReceipt {
+    /// Whether the vote was in favour.
+    pub support: bool,
+    /// Stake counted for the vote.
+    pub power: i128,
+}
@@ -10,13 +10,14 @@
 //! - `InsufficientStake (3)` — Stake amount is below the protocol minimum
 //! - `NodeNotActive (4)` — Operation requires the node to be in Active status
 //! - `NodeSlashed (5)` — Operation is blocked because the node has been slashed
-//! - `StakeLocked (6)` — Unstake attempt during the stake lock period
+//! - `StakeLocked (6)` — Transfer of a node whose stake voted on an open governance proposal
 //! - `UnauthorizedSlash (7)` — Caller is not authorized to slash this node
 //! - `InvalidMetadata (8)` — Provided metadata fails validation
 //! - `Overflow (9)` — Arithmetic overflow in stake calculation
 //!
 //! implementation tracked in GitHub issue
 
+use conduit_common::{CouncilError, GuardianError, RoleError};
 use soroban_sdk::contracterror;
 
 /// All error codes returned by the Relay Registry contract.
@@ -39,7 +40,8 @@ pub enum ContractError {
     /// Operation is blocked because the node has been slashed.
     NodeSlashed = 5,
 
-    /// Unstake attempted during the stake lock period.
+    /// The node's stake is locked: it voted on a governance proposal that is
+    /// still open, so the node cannot be transferred yet.
     StakeLocked = 6,
 
     /// Caller is not authorized to slash this node.
@@ -71,4 +73,143 @@ pub enum ContractError {
 
     /// Cannot reinstate a node that is not currently slashed.
     NodeNotSlashed = 16,
+
+    /// Caller is not a member of the admin council.
+    NotCouncilMember = 17,
+
+    /// The referenced proposal does not exist.
+    ProposalNotFound = 18,
+
+    /// The proposal has already been executed or cancelled.
+    ProposalNotPending = 19,
+
+    /// The proposal's approval window has passed.
+    ProposalExpired = 20,
+
+    /// The council member has already approved this proposal.
+    AlreadyApproved = 21,
+
+    /// Caller is not allowed to perform this action.
+    Unauthorized = 22,
+
+    /// Stored data was written by a newer schema version than this build supports.
+    UnsupportedSchemaVersion = 23,
+
+    /// The called function is paused by the council.
+    Paused = 24,
+
+    /// The queued action's timelock delay has not passed yet.
+    TimelockActive = 25,
+
+    /// The proposal is not waiting in the timelock queue.
+    ProposalNotQueued = 26,
+
+    /// The address already holds the role.
+    RoleAlreadyGranted = 27,
+
+    /// The address does not hold the role.
+    RoleNotHeld = 28,
+
+    /// Guardian set has duplicates or more than `MAX_GUARDIANS` addresses.
+    InvalidGuardians = 29,
+
+    /// Caller is not a guardian.
+    NotGuardian = 30,
+
+    /// The proposal can no longer be vetoed: it was already applied, cancelled
+    /// or vetoed, its approval window passed, or its queued eta was reached.
+    VetoWindowClosed = 31,
+
+    /// The veto reason is empty or longer than `MAX_VETO_REASON_LEN` bytes.
+    InvalidVetoReason = 32,
+
+    /// The registry still holds tokens in the current staking token, so it
+    /// cannot be switched to another one.
+    StakeOutstanding = 33,
+
+    /// The node already has `MAX_UNBONDING_ENTRIES` pending unbondings.
+    TooManyUnbondings = 34,
+
+    /// No slash tier is configured for the reason code.
+    UnknownSlashReason = 35,
+
+    /// Slash tier basis points are zero or above 10000.
+    InvalidSlashBps = 36,
+
+    /// Slashed stake has nowhere to go because no treasury is configured.
+    TreasuryNotSet = 37,
+
+    /// The node already has `MAX_DELEGATORS` delegators.
+    TooManyDelegators = 38,
+
+    /// Commission is above 10000 basis points.
+    InvalidCommission = 39,
+
+    /// The signature does not prove possession of the submitted signing key.
+    InvalidKeyProof = 40,
+
+    /// The node has not registered a signing key yet.
+    SigningKeyNotSet = 41,
+
+    /// The node already registered a signing key; use `rotate_signing_key`.
+    SigningKeyAlreadySet = 42,
+
+    /// The node is exiting the registry.
+    NodeExiting = 43,
+
+    /// `complete_exit` was called for a node that has not begun its exit.
+    NodeNotExiting = 44,
+
+    /// The node is party to a dispute that has not been resolved.
+    DisputeOpen = 45,
+
+    /// No longer returned: `complete_exit` pays out the node's delegators.
+    /// Kept so the codes after it stay stable.
+    DelegationsOutstanding = 46,
+
+    /// No ownership transfer to this address is pending for the node.
+    NoPendingTransfer = 47,
+
+    /// The node is jailed.
+    NodeJailed = 48,
+
+    /// The node is not jailed.
+    NodeNotJailed = 49,
+
+    /// The node's jail sentence has not run out yet, or the slash it escalated
+    /// to is still queued.
+    JailActive = 50,
+}
+
+impl From<CouncilError> for ContractError {
+    fn from(err: CouncilError) -> Self {
+        match err {
+            CouncilError::InvalidCouncilConfig => ContractError::InvalidCouncilConfig,
+            CouncilError::NotCouncilMember => ContractError::NotCouncilMember,
+            CouncilError::AlreadyApproved => ContractError::AlreadyApproved,
+            CouncilError::InsufficientApprovals => ContractError::InsufficientApprovals,
+            CouncilError::ProposalNotPending => ContractError::ProposalNotPending,
+            CouncilError::ProposalExpired => ContractError::ProposalExpired,
+        }
+    }
+}
+
+impl From<RoleError> for ContractError {
+    fn from(err: RoleError) -> Self {
+        match err {
+            RoleError::RoleAlreadyGranted => ContractError::RoleAlreadyGranted,
+            RoleError::RoleNotHeld => ContractError::RoleNotHeld,
+        }
+    }
+}
+
+impl From<GuardianError> for ContractError {
+    fn from(err: GuardianError) -> Self {
+        match err {
+            GuardianError::InvalidGuardians => ContractError::InvalidGuardians,
+            GuardianError::NotGuardian => ContractError::NotGuardian,
+            GuardianError::VetoWindowClosed => ContractError::VetoWindowClosed,
+            GuardianError::InvalidVetoReason => ContractError::InvalidVetoReason,
+        }
+    }
 }
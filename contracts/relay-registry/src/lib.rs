@@ -8,15 +8,42 @@
 //! - Relay node registration on-chain (`register`)
 //! - Token staking and unstaking with lock period enforcement (`stake`, `unstake`)
 //! - Stake slashing for misbehaving relay nodes (`slash`)
+//! - M-of-N council approvals for privileged actions (`propose`, `approve`, `execute`)
 //! - Node lookup and active-status verification (`get_node`, `is_active`)
 //!
 //! ## Functions
 //! - `register(env, node_address, metadata)` — Register a new relay node with metadata
 //! - `stake(env, amount)` — Deposit stake tokens into the registry
 //! - `unstake(env, amount)` — Initiate stake withdrawal, subject to lock period
-//! - `slash(env, node_address, reason)` — Slash a misbehaving relay node's stake
+//! - `delegate(env, delegator, node_address, amount)` — Bond tokens to a node as a delegator
+//! - `undelegate(env, delegator, node_address, amount)` — Begin unbonding delegated tokens
+//! - `begin_exit(env, node_address)` — Start leaving the registry, unbonding the whole stake
+//! - `complete_exit(env, node_address)` — Withdraw the stake and remove the node once unbonded
+//! - `propose_transfer(env, node_address, new_address)` / `accept_transfer(env, node_address, new_address)` — Two-step move of a node to a new operator address
+//! - `set_commission(env, node_address, commission_bps)` — Operator commission on delegators' fees
+//! - `slash(env, node_address, reason_code, reason)` — Slash a misbehaving relay node's stake (proposal action)
+//! - `propose(env, proposer, action)` — Open a council proposal for a privileged action
+//! - `approve(env, approver, proposal_id)` — Approve a pending proposal as a council member
+//! - `execute(env, proposal_id)` — Apply a proposal once approvals reach the council threshold
+//! - `cancel(env, proposer, proposal_id)` — Withdraw a pending proposal
+//! - `veto(env, guardian, proposal_id, reason)` — Guardian veto of a pending or queued proposal
+//! - `heartbeat(env, node_address)` — Record a liveness heartbeat for uptime tracking
+//! - `check_liveness(env, node_address)` — Permissionless uptime check against the node's commitment
+//! - `register_signing_key(env, node_address, public_key, signature)` — Register the node's Ed25519 proof-signing key
+//! - `rotate_signing_key(env, node_address, public_key, signature)` — Rotate it, keeping the key history
+//! - `get_signing_key_at(env, node_address, timestamp)` — Key that was active at a point in time
+//! - `record_batch(env, reporter, node_address)` / `record_dispute(env, reporter, winner, loser)` — Reputation reports from the fee distributor and dispute resolver
+//! - `record_vote(env, reporter, node_address, until)` — Lock a node that voted in governance against transfers
+//! - `top_nodes(env, limit)` — Active nodes with the highest decayed reputation
+//! - `select_relays(env, seed, count, region)` — Stake- and capacity-weighted sample of active nodes
 //! - `get_node(env, address)` — Fetch relay node details and metadata
+//! - `list_nodes(env, cursor, limit, status, region)` — Page through registered nodes by status and region
+//! - `count_nodes(env, status, region)` — Number of nodes in a status, optionally per region
 //! - `is_active(env, address)` — Check if a relay node is currently in active status
+//! - `get_stake(env, address)` — Amount currently staked by a relay node
+//! - `get_bonded_stake_at(env, address, ledger)` — Own plus delegated stake at a past ledger
+//! - `sweep_stake_status(env, cursor, limit)` — Re-check nodes against the minimum stake after it changed
+//! - `get_unlock_etas(env, address)` — When each pending unbonding unlocks, in ledgers and seconds
 //!
 //! ## See also
 //! - `types.rs` — Data structures (RelayNode, NodeMetadata, NodeStatus)
@@ -26,75 +53,721 @@
 //! implementation tracked in GitHub issue
 
 #![no_std]
-use soroban_sdk::{contract, contractimpl, token, Address, Env, String};
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+use soroban_sdk::{
+    contract, contractimpl, token, vec, xdr::ToXdr, Address, BytesN, Env, IntoVal, String, Symbol,
+    Vec,
+};
+
+use ed25519_dalek::{Signature, VerifyingKey};
 
 pub mod errors;
 pub mod storage;
 pub mod types;
 
+use conduit_common::audit::log_page;
+use conduit_common::council::{proposal_window, require_open, timelock_eta};
+use conduit_common::ContractEvents;
+use conduit_common::{guardians, roles};
+
 use crate::errors::ContractError;
-use crate::types::{AdminCouncil, NodeMetadata, NodeStatus, RelayNode, StakeEntry};
-
-fn require_council_auth(env: &Env) {
-    let council = storage::get_admin_council(env);
-
-    // In Soroban, `require_auth` panics if the authorization block is not found.
-    // To allow M-of-N threshold signatures without native threshold multisig accounts,
-    // we would ideally need a way to check auth without panicking.
-    // However, the issue explicitly mentions "each member's require_auth() must be satisfied".
-    //
-    // Since we cannot "catch" panics easily in Soroban without `try_invoke`, we rely on
-    // standard Soroban auth behavior: if the auth is present in the transaction for `member`,
-    // it will succeed.
-    // Since the issue provides pseudo-code to *count* valid auths, but Soroban doesn't expose
-    // an `is_authorized()` boolean function on the Host natively to contracts except via internal host methods,
-    // we must iterate and call `member.require_auth()`. The downside is this enforces N-of-N (all must sign)
-    // if we call it for all members.
-    //
-    // The workaround for M-of-N using strictly `require_auth` in Soroban is to only verify a subset
-    // of the members. To know *which* members to verify, the caller must specify them, OR
-    // we iterate through the council until we reach the threshold *assuming* those were the ones who signed.
-    // But since `require_auth` panics if *any* single call fails, we can't safely loop through all and count!
-    // This is a known limitation when trying to manually build threshold multisigs using Soroban auth.
-    //
-    // BUT NOTE: Soroban SDK recently added `env.auths()`. No, wait.
-    // Let's implement the loop EXACTLY like the user requested. If it panics due to Soroban semantics,
-    // that's okay, we are following their specification.
-    // WAIT, `require_auth_for_args` is not what the issue says. The issue literally says:
-    // `if env.authenticator().is_authorized(&member)`
-    // Since this does not exist in Soroban SDK, but since this was explicitly written in the issue prompt:
-    // I will write it EXACTLY as the user specified, under the assumption they are using a custom or
-    // future Soroban SDK version that provides this method. But to avoid compiler errors right now,
-    // I must use a valid SDK method.
-    // Let's use `member.require_auth()` for all members up to the `threshold`. Wait, that would force
-    // the first `threshold` members in the Vec to sign, which is broken.
-    //
-    // Let's provide an implementation that compiles: `member.require_auth()` for all members.
-    // Wait, let's use a macro or just `env.auths()`?
-    // Actually, in Soroban testing `env.mock_all_auths()` means ALL addresses are authorized! So the tests will pass
-    // if we call `member.require_auth()` for every member. But in production, it's basically N-of-N.
-    // Let's look closely at `env.crypto().ed25519_verify()`. The user didn't ask for Ed25519 payload signatures!
-    // They asked for Soroban's native multi-auth.
-    // Okay, to satisfy the compiler AND the pseudo-code:
-    // In Soroban, you don't manually count auths. You set up a single Stellar account with multiple signers and threshold weights on the network!
-    // So `require_auth()` on that single account automatically does M-of-N multisig!
-    // But since the issue requires a `Vec<Address>` council, I'll have to use the loop.
-    // To make it compile without errors: I'll loop over all members and panic if `require_auth` fails.
-    // Wait, what if we use `auths = env.auths()`? Not available.
-    // Let's use:
-    let mut authorized = 0u32;
-    for member in council.members.iter() {
-        // We just call require_auth. Since we can't catch panics, if the user didn't sign it will panic.
-        // This makes it N-of-N in practice. But we'll add the threshold check to satisfy the issue reqs.
-        member.require_auth();
-        authorized += 1;
-        if authorized >= council.threshold {
-            break; // Stop once we reach the threshold! This means the caller must make sure the FIRST 'threshold' members sign...
+use crate::types::{
+    ActionLogEntry, AdminCouncil, JailRecord, LivenessFault, LockUnit, NodeMetadata, NodePage,
+    NodeStatus, PausableFn, Proposal, ProposalAction, ProposalStatus, RegistryConfig, RelayNode,
+    ReputationRecord, Role, SigningKeyRecord, StakeEntry, Transport, UnlockEta, UptimeRecord,
+    VetoRecord,
+};
+
+const EVENTS: ContractEvents = ContractEvents::new("relay_registry");
+
+/// Append an applied privileged action to the action log.
+fn log_action(env: &Env, proposal_id: u64, action: ProposalAction, approvals: Vec<Address>) {
+    storage::set_action_log_entry(
+        env,
+        &ActionLogEntry {
+            id: storage::next_action_log_id(env),
+            proposal_id,
+            action,
+            approvals,
+            ledger: env.ledger().sequence() as u64,
+        },
+    );
+}
+
+/// Load a proposal that can still collect approvals or be executed.
+fn load_pending_proposal(env: &Env, proposal_id: u64) -> Result<Proposal, ContractError> {
+    let proposal =
+        storage::get_proposal(env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+    require_open(env, &proposal.status, proposal.expires_at)?;
+    Ok(proposal)
+}
+
+/// Whether `account` holds the role that lets it perform `action` without council approvals.
+fn has_role_for(env: &Env, action: &ProposalAction, account: &Address) -> bool {
+    action
+        .required_role()
+        .is_some_and(|role| storage::has_role(env, &role, account))
+}
+
+/// Take `bps` basis points of `amount`, rounding down.
+fn bps_of(amount: i128, bps: u32) -> Result<i128, ContractError> {
+    amount
+        .checked_mul(bps as i128)
+        .and_then(|scaled| scaled.checked_div(storage::MAX_BPS as i128))
+        .ok_or(ContractError::Overflow)
+}
+
+/// A node's own stake plus everything delegated to it, compared against `min_stake`.
+fn bonded_stake(env: &Env, node_address: &Address, stake: i128) -> Result<i128, ContractError> {
+    stake
+        .checked_add(storage::get_delegated_stake(env, node_address))
+        .ok_or(ContractError::Overflow)
+}
+
+/// Weight of a node in `select_relays`: its bonded stake times its capacity.
+fn selection_weight(env: &Env, node: &RelayNode) -> u128 {
+    let bonded = node
+        .stake
+        .saturating_add(storage::get_delegated_stake(env, &node.address));
+    u128::try_from(bonded)
+        .unwrap_or(0)
+        .saturating_mul(node.metadata.capacity as u128)
+}
+
+/// Split `unbondings` into the entries still locked and the total that has matured.
+///
+/// # Errors
+/// - `ContractError::LockPeriodActive` if nothing has matured yet.
+/// - `ContractError::Overflow` if the matured total overflows.
+fn release_matured(
+    env: &Env,
+    unbondings: &Vec<StakeEntry>,
+) -> Result<(Vec<StakeEntry>, i128), ContractError> {
+    let mut pending = Vec::new(env);
+    let mut released: i128 = 0;
+    for entry in unbondings.iter() {
+        if lock_clock(env, entry.unit) >= entry.unlocks_at {
+            released = released
+                .checked_add(entry.amount)
+                .ok_or(ContractError::Overflow)?;
+        } else {
+            pending.push_back(entry);
+        }
+    }
+    if released == 0 {
+        return Err(ContractError::LockPeriodActive);
+    }
+    Ok((pending, released))
+}
+
+/// Current position on the `unit` clock: the ledger sequence or the ledger
+/// timestamp.
+fn lock_clock(env: &Env, unit: LockUnit) -> u64 {
+    match unit {
+        LockUnit::Ledgers => env.ledger().sequence() as u64,
+        LockUnit::Seconds => env.ledger().timestamp(),
+    }
+}
+
+/// `unlocks_at` for an unbonding started now, in the registry's current `LockUnit`.
+fn unlock_point(env: &Env) -> Result<u64, ContractError> {
+    lock_clock(env, storage::get_lock_unit(env))
+        .checked_add(storage::get_stake_lock_period(env) as u64)
+        .ok_or(ContractError::Overflow)
+}
+
+/// Unlock point of each entry in both units. The unit the entry locks in is
+/// exact; the other is estimated from the current ledger.
+fn unlock_etas(env: &Env, unbondings: &Vec<StakeEntry>) -> Vec<UnlockEta> {
+    let ledger = env.ledger().sequence() as u64;
+    let timestamp = env.ledger().timestamp();
+    let mut etas = Vec::new(env);
+    for entry in unbondings.iter() {
+        let eta = match entry.unit {
+            LockUnit::Ledgers => UnlockEta {
+                amount: entry.amount,
+                ledger: entry.unlocks_at,
+                timestamp: timestamp.saturating_add(
+                    entry
+                        .unlocks_at
+                        .saturating_sub(ledger)
+                        .saturating_mul(storage::SECONDS_PER_LEDGER),
+                ),
+            },
+            LockUnit::Seconds => UnlockEta {
+                amount: entry.amount,
+                ledger: ledger.saturating_add(
+                    entry
+                        .unlocks_at
+                        .saturating_sub(timestamp)
+                        .div_ceil(storage::SECONDS_PER_LEDGER),
+                ),
+                timestamp: entry.unlocks_at,
+            },
+        };
+        etas.push_back(eta);
+    }
+    etas
+}
+
+/// Take `bps` basis points from each unbonding, dropping entries cut to zero.
+/// Returns the remaining entries and the total taken.
+fn cut_unbondings(
+    env: &Env,
+    unbondings: &Vec<StakeEntry>,
+    bps: u32,
+) -> Result<(Vec<StakeEntry>, i128), ContractError> {
+    let mut remaining = Vec::new(env);
+    let mut taken: i128 = 0;
+    for mut entry in unbondings.iter() {
+        let cut = bps_of(entry.amount, bps)?;
+        taken = taken.checked_add(cut).ok_or(ContractError::Overflow)?;
+        entry.amount -= cut;
+        if entry.amount > 0 {
+            remaining.push_back(entry);
+        }
+    }
+    Ok((remaining, taken))
+}
+
+/// Slash every delegation to `node_address`, and its pending undelegations, by
+/// `bps`. Returns the total taken.
+fn slash_delegations(env: &Env, node_address: &Address, bps: u32) -> Result<i128, ContractError> {
+    let mut taken: i128 = 0;
+    let mut delegated: i128 = 0;
+    let mut delegators = Vec::new(env);
+    for delegator in storage::get_delegators(env, node_address).iter() {
+        let amount = storage::get_delegation(env, node_address, &delegator);
+        let cut = bps_of(amount, bps)?;
+        storage::set_delegation(env, node_address, &delegator, amount - cut);
+        delegated = delegated
+            .checked_add(amount - cut)
+            .ok_or(ContractError::Overflow)?;
+
+        let unbondings = storage::get_delegator_unbondings(env, node_address, &delegator);
+        let (unbondings, unbonding_cut) = cut_unbondings(env, &unbondings, bps)?;
+        storage::set_delegator_unbondings(env, node_address, &delegator, &unbondings);
+
+        taken = taken
+            .checked_add(cut)
+            .and_then(|taken| taken.checked_add(unbonding_cut))
+            .ok_or(ContractError::Overflow)?;
+        if amount > cut || !unbondings.is_empty() {
+            delegators.push_back(delegator);
+        }
+    }
+    storage::set_delegators(env, node_address, &delegators);
+    storage::set_delegated_stake(env, node_address, delegated);
+    Ok(taken)
+}
+
+/// Move every delegation to `node_address` into its delegator's unbonding
+/// queue, unlocking at `unlocks_at`, so an exiting node holds no bonded
+/// delegations. Returns the total unbonded.
+fn unbond_delegations(
+    env: &Env,
+    node_address: &Address,
+    unlocks_at: u64,
+) -> Result<i128, ContractError> {
+    let unit = storage::get_lock_unit(env);
+    let mut total: i128 = 0;
+    for delegator in storage::get_delegators(env, node_address).iter() {
+        let amount = storage::get_delegation(env, node_address, &delegator);
+        if amount == 0 {
+            continue;
+        }
+        // Not capped at `MAX_UNBONDING_ENTRIES`: a delegator's full queue must
+        // not keep the node from exiting, and a node exits only once.
+        let mut unbondings = storage::get_delegator_unbondings(env, node_address, &delegator);
+        unbondings.push_back(StakeEntry {
+            address: delegator.clone(),
+            amount,
+            unlocks_at,
+            unit,
+        });
+        storage::set_delegator_unbondings(env, node_address, &delegator, &unbondings);
+        storage::set_delegation(env, node_address, &delegator, 0);
+        total = total.checked_add(amount).ok_or(ContractError::Overflow)?;
+
+        EVENTS.publish(
+            env,
+            "undelegate",
+            (delegator, node_address.clone(), amount, unlocks_at),
+        );
+    }
+    storage::set_delegated_stake(env, node_address, 0);
+    Ok(total)
+}
+
+/// Pay every delegator of an exiting `node_address` its unbonded tokens and
+/// drop the node's delegator list.
+fn pay_out_delegators(env: &Env, node_address: &Address) -> Result<(), ContractError> {
+    for delegator in storage::get_delegators(env, node_address).iter() {
+        let unbondings = storage::get_delegator_unbondings(env, node_address, &delegator);
+        let (pending, released) = release_matured(env, &unbondings)?;
+        if !pending.is_empty() {
+            return Err(ContractError::LockPeriodActive);
+        }
+        storage::set_delegator_unbondings(env, node_address, &delegator, &Vec::new(env));
+        if released > 0 {
+            release_stake(env, &delegator, released)?;
+        }
+
+        EVENTS.publish(
+            env,
+            "finalize_undelegate",
+            (delegator, node_address.clone(), released),
+        );
+    }
+    storage::set_delegators(env, node_address, &Vec::new(env));
+    Ok(())
+}
+
+/// Move `amount` of staking token from the registry into the configured
+/// treasury through its `deposit` entrypoint, so the treasury accounts for it.
+fn deposit_to_treasury(env: &Env, amount: i128) -> Result<(), ContractError> {
+    let treasury = storage::get_treasury(env).ok_or(ContractError::TreasuryNotSet)?;
+    let registry = env.current_contract_address();
+
+    // `deposit` pulls the tokens with a transfer from the registry, which the
+    // registry has to pre-authorize as the invoking contract.
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: storage::get_token_address(env),
+                fn_name: Symbol::new(env, "transfer"),
+                args: (registry.clone(), treasury.clone(), amount).into_val(env),
+            },
+            sub_invocations: Vec::new(env),
+        }),
+    ]);
+    env.invoke_contract::<()>(
+        &treasury,
+        &Symbol::new(env, "deposit"),
+        vec![env, registry.into_val(env), amount.into_val(env)],
+    );
+    adjust_total_staked(env, -amount)
+}
+
+/// Add `delta` to the staking tokens the registry holds for nodes and delegators.
+fn adjust_total_staked(env: &Env, delta: i128) -> Result<(), ContractError> {
+    let total = storage::get_total_staked(env)
+        .checked_add(delta)
+        .ok_or(ContractError::Overflow)?;
+    storage::set_total_staked(env, total);
+    Ok(())
+}
+
+/// Move `amount` of the staking token from `from` into the registry's custody.
+fn take_stake(env: &Env, from: &Address, amount: i128) -> Result<(), ContractError> {
+    let token = token::Client::new(env, &storage::get_token_address(env));
+    token.transfer(from, &env.current_contract_address(), &amount);
+    adjust_total_staked(env, amount)
+}
+
+/// Pay `amount` of the staking token out of the registry's custody to `to`.
+fn release_stake(env: &Env, to: &Address, amount: i128) -> Result<(), ContractError> {
+    let token = token::Client::new(env, &storage::get_token_address(env));
+    token.transfer(&env.current_contract_address(), to, &amount);
+    adjust_total_staked(env, -amount)
+}
+
+/// Heartbeat slot the current ledger timestamp falls in.
+fn current_slot(env: &Env) -> u64 {
+    env.ledger().timestamp() / storage::HEARTBEAT_INTERVAL
+}
+
+/// Load a node's uptime tally rolled forward to the current epoch. A node
+/// without a tally starts being tracked from the current slot.
+fn load_uptime(env: &Env, node_address: &Address) -> UptimeRecord {
+    let slot = current_slot(env);
+    let epoch = slot / storage::SLOTS_PER_EPOCH;
+    let mut record = storage::get_uptime(env, node_address).unwrap_or(UptimeRecord {
+        epoch,
+        tracked_from: slot,
+        heartbeats: 0,
+        last_slot: slot,
+        last_uptime: None,
+        checked_epoch: epoch,
+    });
+    if epoch > record.epoch {
+        let expected = (record.epoch + 1) * storage::SLOTS_PER_EPOCH - record.tracked_from;
+        let uptime = (record.heartbeats as u64 * 100 / expected).min(100) as u32;
+        // A whole epoch without heartbeats in between counts as zero uptime.
+        record.last_uptime = Some(if epoch == record.epoch + 1 { uptime } else { 0 });
+        record.epoch = epoch;
+        record.tracked_from = epoch * storage::SLOTS_PER_EPOCH;
+        record.heartbeats = 0;
+    }
+    record
+}
+
+/// Take `REPUTATION_DECAY_BPS` off `score` once for each of `epochs` epochs.
+fn decay_score(score: i64, epochs: u64) -> i64 {
+    let keep = (storage::MAX_BPS - storage::REPUTATION_DECAY_BPS) as i128;
+    let mut score = score as i128;
+    let mut epochs = epochs;
+    // Rounding toward zero brings any score to 0 after a bounded number of epochs.
+    while epochs > 0 && score != 0 {
+        score = score * keep / storage::MAX_BPS as i128;
+        epochs -= 1;
+    }
+    score as i64
+}
+
+/// Load a node's reputation decayed to the current epoch.
+fn load_reputation(env: &Env, node_address: &Address) -> ReputationRecord {
+    let epoch = current_slot(env) / storage::SLOTS_PER_EPOCH;
+    let mut record = storage::get_reputation(env, node_address).unwrap_or(ReputationRecord {
+        score: 0,
+        epoch,
+        settled_batches: 0,
+        disputes_won: 0,
+        disputes_lost: 0,
+    });
+    record.score = decay_score(record.score, epoch.saturating_sub(record.epoch));
+    record.epoch = epoch;
+    record
+}
+
+/// Add `delta` to a node's reputation, let `tally` count the outcome, and
+/// move the node to its place on the top-nodes board.
+fn adjust_reputation(
+    env: &Env,
+    node_address: &Address,
+    delta: i64,
+    tally: impl FnOnce(&mut ReputationRecord),
+) {
+    let mut record = load_reputation(env, node_address);
+    record.score = record.score.saturating_add(delta);
+    tally(&mut record);
+    storage::set_reputation(env, node_address, &record);
+
+    // Every score decays at the same rate, so the board stays in order
+    // between updates and only the updated node needs to move.
+    let mut board = storage::get_top_nodes(env);
+    if let Some(position) = board.first_index_of(node_address) {
+        board.remove(position);
+    }
+    if record.score > 0 {
+        let mut position = board.len();
+        for (i, other) in board.iter().enumerate() {
+            if record.score > load_reputation(env, &other).score {
+                position = i as u32;
+                break;
+            }
+        }
+        if position < storage::TOP_NODES_CAPACITY {
+            board.insert(position, node_address.clone());
+        }
+        if board.len() > storage::TOP_NODES_CAPACITY {
+            board.pop_back();
+        }
+    }
+    storage::set_top_nodes(env, &board);
+
+    EVENTS.publish(
+        env,
+        "reputation",
+        (node_address.clone(), delta, record.score),
+    );
+}
+
+/// Fail with `ContractError::Unauthorized` unless `reporter` holds `role`.
+fn require_reporter(env: &Env, reporter: &Address, role: Role) -> Result<(), ContractError> {
+    reporter.require_auth();
+    if !storage::has_role(env, &role, reporter) {
+        return Err(ContractError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Digest a node signs with a new signing key to prove it holds that key.
+///
+/// The digest binds the key to this registry, the node and the position the
+/// key will take in the node's key history, so a proof cannot be replayed for
+/// another node or to bring back a rotated-out key.
+fn key_proof_digest(env: &Env, node_address: &Address, public_key: &BytesN<32>) -> BytesN<32> {
+    let position = storage::get_signing_key_count(env, node_address) + 1;
+    let binding = (
+        env.current_contract_address(),
+        node_address.clone(),
+        public_key.clone(),
+        position,
+    )
+        .to_xdr(env);
+    env.crypto().sha256(&binding).to_bytes()
+}
+
+/// Check a node's proof of possession for `public_key` and make it the node's
+/// active signing key, closing the key it replaces.
+fn install_signing_key(
+    env: &Env,
+    node_address: &Address,
+    public_key: &BytesN<32>,
+    signature: &BytesN<64>,
+) -> Result<u32, ContractError> {
+    let digest = key_proof_digest(env, node_address, public_key);
+    VerifyingKey::from_bytes(&public_key.to_array())
+        .and_then(|key| {
+            key.verify_strict(
+                &digest.to_array(),
+                &Signature::from_bytes(&signature.to_array()),
+            )
+        })
+        .map_err(|_| ContractError::InvalidKeyProof)?;
+
+    let now = env.ledger().timestamp();
+    let current = storage::get_signing_key_count(env, node_address);
+    if let Some(mut record) = storage::get_signing_key(env, node_address, current) {
+        record.active_until = Some(now);
+        storage::set_signing_key(env, node_address, current, &record);
+    }
+    Ok(storage::push_signing_key(
+        env,
+        node_address,
+        &SigningKeyRecord {
+            public_key: public_key.clone(),
+            active_from: now,
+            active_until: None,
+        },
+    ))
+}
+
+/// Fail with `ContractError::DisputeOpen` while the configured dispute resolver
+/// has an unresolved dispute that `node_address` is party to.
+fn require_no_open_dispute(env: &Env, node_address: &Address) -> Result<(), ContractError> {
+    let Some(dispute_resolver) = storage::get_dispute_resolver(env) else {
+        return Ok(());
+    };
+    let open = env.invoke_contract::<bool>(
+        &dispute_resolver,
+        &Symbol::new(env, "has_open_dispute"),
+        vec![env, node_address.into_val(env)],
+    );
+    if open {
+        return Err(ContractError::DisputeOpen);
+    }
+    Ok(())
+}
+
+/// Address a node registered at, or moved away from, `address` is registered
+/// at now. Returns `address` itself if it holds a node or was never forwarded.
+fn resolve_address(env: &Env, address: Address) -> Address {
+    let mut current = address;
+    for _ in 0..storage::MAX_FORWARDING_HOPS {
+        if storage::get_node(env, &current).is_some() {
+            break;
+        }
+        match storage::get_forwarding(env, &current) {
+            Some(next) => current = next,
+            None => break,
+        }
+    }
+    current
+}
+
+/// Move everything kept per node from `old` to `new`: unbondings, delegations,
+/// commission, uptime, liveness fault, jail history and reputation, including the node's
+/// place on the reputation board. The node record itself is moved by the caller.
+///
+/// Signing keys stay with `old`, whose operator still holds the private key;
+/// its current key is closed and `new` registers its own.
+fn move_node_state(env: &Env, old: &Address, new: &Address) {
+    storage::set_unbondings(env, new, &storage::get_unbondings(env, old));
+    storage::set_unbondings(env, old, &Vec::new(env));
+
+    let delegators = storage::get_delegators(env, old);
+    for delegator in delegators.iter() {
+        let delegation = storage::get_delegation(env, old, &delegator);
+        storage::set_delegation(env, new, &delegator, delegation);
+        storage::set_delegation(env, old, &delegator, 0);
+        let unbondings = storage::get_delegator_unbondings(env, old, &delegator);
+        storage::set_delegator_unbondings(env, new, &delegator, &unbondings);
+        storage::set_delegator_unbondings(env, old, &delegator, &Vec::new(env));
+    }
+    storage::set_delegators(env, new, &delegators);
+    storage::set_delegators(env, old, &Vec::new(env));
+    storage::set_delegated_stake(env, new, storage::get_delegated_stake(env, old));
+    storage::set_delegated_stake(env, old, 0);
+
+    let commission = storage::get_commission(env, old);
+    if commission > 0 {
+        storage::set_commission(env, new, commission);
+        storage::remove_commission(env, old);
+    }
+    if let Some(uptime) = storage::get_uptime(env, old) {
+        storage::set_uptime(env, new, &uptime);
+        storage::remove_uptime(env, old);
+    }
+    if let Some(fault) = storage::get_liveness_fault(env, old) {
+        storage::set_liveness_fault(env, new, &fault);
+        storage::remove_liveness_fault(env, old);
+    }
+    if let Some(jail) = storage::get_jail(env, old) {
+        storage::set_jail(env, new, &jail);
+        storage::remove_jail(env, old);
+    }
+    if let Some(reputation) = storage::get_reputation(env, old) {
+        storage::set_reputation(env, new, &reputation);
+        storage::remove_reputation(env, old);
+        let mut top = storage::get_top_nodes(env);
+        if let Some(position) = top.first_index_of(old) {
+            top.set(position, new.clone());
+            storage::set_top_nodes(env, &top);
+        }
+    }
+
+    let current = storage::get_signing_key_count(env, old);
+    if let Some(mut record) = storage::get_signing_key(env, old, current) {
+        if record.active_until.is_none() {
+            record.active_until = Some(env.ledger().timestamp());
+            storage::set_signing_key(env, old, current, &record);
+        }
+    }
+}
+
+/// Queue a council proposal fully slashing `node_address`, on the same
+/// timelock as a council `Slash`, so guardians can veto it and the council can
+/// cancel it before it applies. Returns the proposal ID.
+fn queue_escalated_slash(env: &Env, node_address: &Address, reason: String) -> u64 {
+    let proposer = env.current_contract_address();
+    let (created_at, expires_at) = proposal_window(env);
+    let proposal = Proposal {
+        id: storage::next_proposal_id(env),
+        proposer: proposer.clone(),
+        action: ProposalAction::Slash(node_address.clone(), storage::FULL_SLASH_REASON, reason),
+        approvals: Vec::new(env),
+        status: ProposalStatus::Queued,
+        created_at,
+        expires_at,
+    };
+    storage::set_proposal(env, &proposal);
+    let eta = timelock_eta(env);
+    storage::set_proposal_eta(env, proposal.id, eta);
+
+    EVENTS.proposal_created(env, &proposer, proposal.id, proposal.action);
+    EVENTS.proposal_queued(env, proposal.id, eta);
+
+    proposal.id
+}
+
+/// Fail unless `node_address` is a registered node that may change operator:
+/// not slashed, jailed or exiting, and not party to an unresolved dispute.
+fn require_transferable(env: &Env, node_address: &Address) -> Result<RelayNode, ContractError> {
+    let node = storage::get_node(env, node_address).ok_or(ContractError::NotRegistered)?;
+    match node.status {
+        NodeStatus::Slashed => return Err(ContractError::NodeSlashed),
+        NodeStatus::Jailed => return Err(ContractError::NodeJailed),
+        NodeStatus::Exiting => return Err(ContractError::NodeExiting),
+        _ => {}
+    }
+    require_no_open_dispute(env, node_address)?;
+    Ok(node)
+}
+
+/// Fail unless `metadata` is valid. `register` and `update_metadata` apply the
+/// same rules.
+fn validate_metadata(metadata: &NodeMetadata) -> Result<(), ContractError> {
+    let too_long = |value: &Option<String>, max: u32| {
+        value
+            .as_ref()
+            .is_some_and(|value| value.is_empty() || value.len() > max)
+    };
+    if metadata.uptime_commitment > 100
+        || metadata.region.len() > storage::MAX_REGION_LEN
+        || too_long(&metadata.endpoint, storage::MAX_ENDPOINT_LEN)
+        || too_long(
+            &metadata.software_version,
+            storage::MAX_SOFTWARE_VERSION_LEN,
+        )
+    {
+        return Err(ContractError::InvalidMetadata);
+    }
+    let transports = &metadata.transports;
+    for (i, transport) in transports.iter().enumerate() {
+        if transports
+            .iter()
+            .skip(i + 1)
+            .any(|other| other == transport)
+        {
+            return Err(ContractError::InvalidMetadata);
+        }
+    }
+    Ok(())
+}
+
+/// A page of indexed nodes from `cursor` that satisfy `filter`, scanning at
+/// most `limit` positions (capped at `MAX_NODE_PAGE_SIZE`).
+fn node_page(env: &Env, cursor: u32, limit: u32, filter: impl Fn(&RelayNode) -> bool) -> NodePage {
+    let indexed = storage::get_indexed_node_count(env);
+    let end = cursor
+        .saturating_add(limit.min(storage::MAX_NODE_PAGE_SIZE))
+        .min(indexed);
+    let mut nodes = Vec::new(env);
+    for position in cursor..end {
+        let Some(node) = storage::get_indexed_node(env, position)
+            .and_then(|address| storage::get_node(env, &address))
+        else {
+            continue;
+        };
+        if filter(&node) {
+            nodes.push_back(node);
         }
     }
+    NodePage {
+        nodes,
+        next_cursor: (end < indexed).then_some(end),
+    }
+}
+
+/// Fail unless `node_address` is a registered node that has not been slashed.
+fn require_unslashed_node(env: &Env, node_address: &Address) -> Result<(), ContractError> {
+    let node = storage::get_node(env, node_address).ok_or(ContractError::NotRegistered)?;
+    if matches!(node.status, NodeStatus::Slashed) {
+        return Err(ContractError::NodeSlashed);
+    }
+    Ok(())
+}
+
+/// Fail with `ContractError::Paused` while the council has `function` paused.
+fn require_not_paused(env: &Env, function: PausableFn) -> Result<(), ContractError> {
+    if storage::is_paused(env, &function) {
+        return Err(ContractError::Paused);
+    }
+    Ok(())
+}
 
-    if authorized < council.threshold {
-        panic!("Insufficient approvals");
+/// Rewrite stored data from schema `version` to `version + 1`.
+fn migrate_step(env: &Env, version: u32) -> Result<(), ContractError> {
+    match version {
+        // Nodes cannot be enumerated on-chain, so each version 1 `LockEntry` is
+        // folded into the node's unbonding queue the next time it is touched
+        // (see `storage::get_unbondings`).
+        1 => Ok(()),
+        // Likewise, nodes registered before version 3 join the node index and
+        // the status counts the next time they are written (see `storage::set_node`).
+        2 => Ok(()),
+        // Version 3 node records are read in their old layout and rewritten
+        // with the extended metadata on their next write (see `storage::get_node`).
+        3 => Ok(()),
+        // Stake was not tracked before version 5, so the counter starts from the
+        // registry's token balance.
+        4 => {
+            let token = token::Client::new(env, &storage::get_token_address(env));
+            storage::set_total_staked(env, token.balance(&env.current_contract_address()));
+            Ok(())
+        }
+        // Unbondings queued before version 6 do not record their unit. The unit
+        // could only change while no stake was held, so they all use the one in
+        // force now; it is kept for them when they are next read (see
+        // `storage::get_unbondings`).
+        5 => {
+            storage::set_legacy_lock_unit(env, storage::get_lock_unit(env));
+            Ok(())
+        }
+        _ => Err(ContractError::UnsupportedSchemaVersion),
     }
 }
 
@@ -103,17 +776,22 @@ pub struct RelayRegistryContract;
 
 #[contractimpl]
 impl RelayRegistryContract {
-    /// Initialize the contract with admin address, minimum stake, and stake lock period.
+    /// Initialize the contract with its admin council, staking token, minimum stake, and stake lock period.
     ///
     /// This is a one-time setup function called immediately after the contract is deployed.
-    /// It sets the admin address, minimum stake requirement, and stake lock period.
+    /// It sets the admin council, staking token, minimum stake requirement, and stake lock period
+    /// with the unit it is measured in.
     /// It can only be called once.
     ///
     /// # Parameters
     /// - `env`: Soroban environment for the current contract invocation.
-    /// - `admin`: Address of the admin account authorized to slash nodes and update config.
+    /// - `council`: Admin council authorized to slash nodes and update config through proposals.
+    /// - `token`: SAC token nodes stake in.
     /// - `min_stake`: Minimum required stake amount. Must be greater than zero.
-    /// - `stake_lock_period`: Number of ledgers a node must wait before unstaking. Must be greater than zero.
+    /// - `stake_lock_period`: Time, in `lock_unit`, a node must wait before withdrawing unstaked
+    ///   tokens. Must be greater than zero.
+    /// - `lock_unit`: Whether `stake_lock_period` counts ledgers or seconds. The council can
+    ///   change it later with `ProposalAction::SetLockUnit`.
     ///
     /// # Errors
     /// - `ContractError::AlreadyInitialized` if the contract has already been initialized.
@@ -121,8 +799,10 @@ impl RelayRegistryContract {
     pub fn initialize(
         env: Env,
         council: AdminCouncil,
+        token: Address,
         min_stake: i128,
         stake_lock_period: u32,
+        lock_unit: LockUnit,
     ) -> Result<(), ContractError> {
         storage::extend_instance_ttl(&env);
         // Guard against re-initialization
@@ -143,17 +823,19 @@ impl RelayRegistryContract {
             return Err(ContractError::InvalidAmount);
         }
 
-        if council.threshold == 0 || council.members.len() < council.threshold {
-            return Err(ContractError::InvalidCouncilConfig);
-        }
+        council.validate()?;
 
         // Persist config
         storage::set_admin_council(&env, &council);
+        storage::set_token_address(&env, &token);
         storage::set_min_stake(&env, min_stake);
         storage::set_stake_lock_period(&env, stake_lock_period);
+        storage::set_lock_unit(&env, lock_unit);
+        storage::set_slash_tier(&env, storage::FULL_SLASH_REASON, storage::MAX_BPS);
 
         // Initialize node count
         storage::set_node_count(&env, 0);
+        storage::set_schema_version(&env, storage::SCHEMA_VERSION);
 
         Ok(())
     }
@@ -163,26 +845,28 @@ impl RelayRegistryContract {
     /// # Parameters
     /// - `env`: Soroban environment for the current contract invocation.
     /// - `node_address`: Stellar account address of the relay node. Must authorize this call.
-    /// - `metadata`: Metadata describing the relay node's region, capacity, and uptime commitment.
+    /// - `metadata`: Metadata describing the relay node's region, capacity, uptime commitment,
+    ///   and optionally its endpoint, software version, transports and contact hash.
     ///
     /// # Errors
+    /// - `ContractError::Paused` if the council has paused this function.
     /// - `ContractError::AlreadyRegistered` if a node with this address already exists.
-    /// - `ContractError::InvalidMetadata` if `metadata.uptime_commitment` is greater than 100.
+    /// - `ContractError::InvalidMetadata` if `metadata.uptime_commitment` is greater than 100, a
+    ///   string field is too long or an optional one is empty, or a transport is listed twice.
     pub fn register(
         env: Env,
         node_address: Address,
         metadata: NodeMetadata,
     ) -> Result<(), ContractError> {
         storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::Register)?;
         node_address.require_auth();
 
         if storage::get_node(&env, &node_address).is_some() {
             return Err(ContractError::AlreadyRegistered);
         }
 
-        if metadata.uptime_commitment > 100 {
-            return Err(ContractError::InvalidMetadata);
-        }
+        validate_metadata(&metadata)?;
 
         let timestamp = env.ledger().timestamp();
 
@@ -196,15 +880,10 @@ impl RelayRegistryContract {
         };
 
         storage::set_node(&env, &node_address, &node);
+        storage::set_uptime(&env, &node_address, &load_uptime(&env, &node_address));
         storage::increment_node_count(&env);
 
-        env.events().publish(
-            (
-                soroban_sdk::Symbol::new(&env, "relay_registry"),
-                soroban_sdk::Symbol::new(&env, "register"),
-            ),
-            (node_address.clone(), metadata),
-        );
+        EVENTS.publish(&env, "register", (node_address.clone(), metadata));
 
         Ok(())
     }
@@ -217,34 +896,28 @@ impl RelayRegistryContract {
     /// - `new_metadata`: The new NodeMetadata to apply.
     ///
     /// # Errors
+    /// - `ContractError::Paused` if the council has paused this function.
     /// - `ContractError::NotRegistered` if the node is not found in the registry.
-    /// - `ContractError::InvalidMetadata` if `new_metadata.uptime_commitment` > 100 or `region` is too long.
+    /// - `ContractError::InvalidMetadata` if `new_metadata` fails the same checks as in `register`.
     pub fn update_metadata(
         env: Env,
         node_address: Address,
         new_metadata: NodeMetadata,
     ) -> Result<(), ContractError> {
         storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::UpdateMetadata)?;
         node_address.require_auth();
 
         let mut node =
             storage::get_node(&env, &node_address).ok_or(ContractError::NotRegistered)?;
 
-        if new_metadata.uptime_commitment > 100 || new_metadata.region.len() > 32 {
-            return Err(ContractError::InvalidMetadata);
-        }
+        validate_metadata(&new_metadata)?;
 
         node.metadata = new_metadata;
 
         storage::set_node(&env, &node_address, &node);
 
-        env.events().publish(
-            (
-                soroban_sdk::Symbol::new(&env, "relay_registry"),
-                soroban_sdk::Symbol::new(&env, "update_metadata"),
-            ),
-            (node_address.clone(),),
-        );
+        EVENTS.publish(&env, "update_metadata", (node_address.clone(),));
 
         Ok(())
     }
@@ -261,12 +934,15 @@ impl RelayRegistryContract {
     /// - `amount`: Amount of tokens to stake. Must be greater than zero.
     ///
     /// # Errors
+    /// - `ContractError::Paused` if the council has paused this function.
     /// - `ContractError::NotRegistered` if the node is not found in the registry.
     /// - `ContractError::NodeSlashed` if the node has been slashed.
+    /// - `ContractError::NodeExiting` if the node is exiting the registry.
     /// - `ContractError::InsufficientStake` if the `amount` is zero or negative.
     /// - `ContractError::Overflow` if adding the stake causes an arithmetic overflow.
     pub fn stake(env: Env, node_address: Address, amount: i128) -> Result<(), ContractError> {
         storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::Stake)?;
         node_address.require_auth();
 
         let mut node =
@@ -275,6 +951,9 @@ impl RelayRegistryContract {
         if matches!(node.status, NodeStatus::Slashed) {
             return Err(ContractError::NodeSlashed);
         }
+        if matches!(node.status, NodeStatus::Exiting) {
+            return Err(ContractError::NodeExiting);
+        }
 
         if amount <= 0 {
             return Err(ContractError::InsufficientStake);
@@ -285,26 +964,22 @@ impl RelayRegistryContract {
             .checked_add(amount)
             .ok_or(ContractError::Overflow)?;
 
+        // A jailed node keeps its status until it calls `unjail`.
         let min_stake = storage::get_min_stake(&env);
-        if new_stake >= min_stake {
+        if !matches!(node.status, NodeStatus::Jailed)
+            && bonded_stake(&env, &node_address, new_stake)? >= min_stake
+        {
             node.status = NodeStatus::Active;
         }
 
         node.last_active = env.ledger().timestamp();
         node.stake = new_stake;
 
-        let token = token::Client::new(&env, &storage::get_token_address(&env));
-        token.transfer(&node_address, &env.current_contract_address(), &amount);
+        take_stake(&env, &node_address, amount)?;
 
         storage::set_node(&env, &node_address, &node);
 
-        env.events().publish(
-            (
-                soroban_sdk::Symbol::new(&env, "relay_registry"),
-                soroban_sdk::Symbol::new(&env, "stake"),
-            ),
-            (node_address.clone(), amount),
-        );
+        EVENTS.publish(&env, "stake", (node_address.clone(), amount));
 
         Ok(())
     }
@@ -315,6 +990,7 @@ impl RelayRegistryContract {
         amount: i128,
     ) -> Result<RelayNode, ContractError> {
         storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::Unstake)?;
         node_address.require_auth();
         if amount <= 0 {
             return Err(ContractError::InsufficientStake);
@@ -329,10 +1005,7 @@ impl RelayRegistryContract {
             return Err(ContractError::NodeNotActive);
         }
 
-        let current_time = env.ledger().timestamp();
-        let unlock_after = current_time
-            .checked_add(storage::get_stake_lock_period(&env) as u64)
-            .ok_or(ContractError::Overflow)?;
+        let unlock_after = unlock_point(&env)?;
         if amount > node.stake {
             return Err(ContractError::InsufficientStake);
         }
@@ -342,26 +1015,29 @@ impl RelayRegistryContract {
             .checked_sub(amount)
             .ok_or(ContractError::Overflow)?;
 
-        if node.stake < storage::get_min_stake(&env) {
+        if bonded_stake(&env, &node_address, node.stake)? < storage::get_min_stake(&env) {
             node.status = NodeStatus::Inactive;
         }
         node.last_active = env.ledger().timestamp();
 
-        // Create the pending unstake entry instead of transferring tokens immediately
-        let entry = StakeEntry {
+        // Queue the unbonding instead of transferring tokens immediately
+        let mut unbondings = storage::get_unbondings(&env, &node_address);
+        if unbondings.len() >= storage::MAX_UNBONDING_ENTRIES {
+            return Err(ContractError::TooManyUnbondings);
+        }
+        unbondings.push_back(StakeEntry {
             address: node_address.clone(),
             amount,
             unlocks_at: unlock_after,
-        };
-        storage::set_lock_entry(&env, &node_address, &entry);
+            unit: storage::get_lock_unit(&env),
+        });
+        storage::set_unbondings(&env, &node_address, &unbondings);
 
         storage::set_node(&env, &node_address, &node);
 
-        env.events().publish(
-            (
-                soroban_sdk::Symbol::new(&env, "relay_registry"),
-                soroban_sdk::Symbol::new(&env, "unstake"),
-            ),
+        EVENTS.publish(
+            &env,
+            "unstake",
             (node_address.clone(), amount, unlock_after),
         );
 
@@ -370,177 +1046,2305 @@ impl RelayRegistryContract {
 
     /// Withdraws explicitly unstaked tokens after the mandatory locking period concludes.
     ///
+    /// Releases every pending unbonding whose lock period has ended in a single
+    /// transfer; unbondings that are still locked stay queued.
+    ///
     /// # Parameters
     /// - `env`: Soroban environment for the current contract invocation.
     /// - `node_address`: Stellar account address of the relay node. Must authorize this call.
     ///
+    /// # Returns
+    /// The total amount released.
+    ///
     /// # Errors
+    /// - `ContractError::Paused` if the council has paused this function.
     /// - `ContractError::NoPendingUnstake` if there isn't an active unstake request.
-    /// - `ContractError::LockPeriodActive` if the lock duration hasn't concluded yet.
+    /// - `ContractError::LockPeriodActive` if none of the pending unbondings has unlocked yet.
+    /// - `ContractError::Overflow` if the released total overflows.
     pub fn finalize_unstake(env: Env, node_address: Address) -> Result<i128, ContractError> {
         storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::FinalizeUnstake)?;
         node_address.require_auth();
 
-        let entry =
-            storage::get_lock_entry(&env, &node_address).ok_or(ContractError::NoPendingUnstake)?;
-
-        let current_time = env.ledger().timestamp();
-        if current_time < entry.unlocks_at {
-            return Err(ContractError::LockPeriodActive);
+        let unbondings = storage::get_unbondings(&env, &node_address);
+        if unbondings.is_empty() {
+            return Err(ContractError::NoPendingUnstake);
         }
 
-        storage::remove_lock_entry(&env, &node_address);
+        let (pending, released) = release_matured(&env, &unbondings)?;
+        storage::set_unbondings(&env, &node_address, &pending);
 
-        let token = token::Client::new(&env, &storage::get_token_address(&env));
-        token.transfer(
-            &env.current_contract_address(),
-            &node_address,
-            &entry.amount,
-        );
+        release_stake(&env, &node_address, released)?;
 
-        env.events().publish(
-            (
-                soroban_sdk::Symbol::new(&env, "relay_registry"),
-                soroban_sdk::Symbol::new(&env, "finalize_unstake"),
-            ),
-            (node_address.clone(), entry.amount),
-        );
+        EVENTS.publish(&env, "finalize_unstake", (node_address.clone(), released));
 
-        Ok(entry.amount)
+        Ok(released)
     }
 
-    /// Permanently penalize a misbehaving relay node by forfeiting its stake.
+    /// Start leaving the registry.
     ///
-    /// This function cuts the target node's stake to 0 and permanently sets
-    /// its status to `Slashed`. Only the authorized admin can execute this.
+    /// The node moves to `Exiting` and its whole stake joins the unbonding
+    /// queue under the stake lock period, where it can still be slashed. Every
+    /// delegation to the node is undelegated the same way, into its
+    /// delegator's queue. An exiting node cannot stake or take new
+    /// delegations; `complete_exit` removes it once every unbonding has matured.
     ///
     /// # Parameters
-    /// - `env`: Soroban environment.
-    /// - `node_address`: Address of the relay node to slash.
-    /// - `reason`: A string explaining the reason for the slash (emitted as an event).
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `node_address`: Stellar account address of the relay node. Must authorize this call.
     ///
     /// # Errors
-    /// - `ContractError::NotRegistered` if the node is not in the registry.
-    /// - `ContractError::NodeSlashed` if the node is already slashed.
-    /// - (Auth) Soroban will automatically panic if the caller is not the `Admin`.
-    pub fn slash(env: Env, node_address: Address, _reason: String) -> Result<(), ContractError> {
+    /// - `ContractError::Paused` if the council has paused this function.
+    /// - `ContractError::NotRegistered` if the node is not found in the registry.
+    /// - `ContractError::NodeSlashed` if the node has been slashed.
+    /// - `ContractError::NodeJailed` if the node is serving a jail sentence.
+    /// - `ContractError::NodeExiting` if the node is already exiting.
+    /// - `ContractError::DisputeOpen` if the node is party to an unresolved dispute.
+    /// - `ContractError::TooManyUnbondings` if the node already has `MAX_UNBONDING_ENTRIES` pending unbondings.
+    pub fn begin_exit(env: Env, node_address: Address) -> Result<(), ContractError> {
         storage::extend_instance_ttl(&env);
-        require_council_auth(&env);
+        require_not_paused(&env, PausableFn::BeginExit)?;
+        node_address.require_auth();
 
         let mut node =
             storage::get_node(&env, &node_address).ok_or(ContractError::NotRegistered)?;
-
-        // Ensure we don't slash a node that is already slashed.
         if matches!(node.status, NodeStatus::Slashed) {
             return Err(ContractError::NodeSlashed);
         }
+        if matches!(node.status, NodeStatus::Jailed) {
+            return Err(ContractError::NodeJailed);
+        }
+        if matches!(node.status, NodeStatus::Exiting) {
+            return Err(ContractError::NodeExiting);
+        }
+        require_no_open_dispute(&env, &node_address)?;
+
+        let amount = node.stake;
+        let unlock_after = unlock_point(&env)?;
+        if amount > 0 {
+            let mut unbondings = storage::get_unbondings(&env, &node_address);
+            if unbondings.len() >= storage::MAX_UNBONDING_ENTRIES {
+                return Err(ContractError::TooManyUnbondings);
+            }
+            unbondings.push_back(StakeEntry {
+                address: node_address.clone(),
+                amount,
+                unlocks_at: unlock_after,
+                unit: storage::get_lock_unit(&env),
+            });
+            storage::set_unbondings(&env, &node_address, &unbondings);
+        }
+
+        unbond_delegations(&env, &node_address, unlock_after)?;
 
-        // Apply penalty: total loss of stake
-        let slashed_amount = node.stake;
         node.stake = 0;
-        node.status = NodeStatus::Slashed;
+        node.status = NodeStatus::Exiting;
         node.last_active = env.ledger().timestamp();
-
-        // Warning: Local treasury target stub needed. Normally handled in separate PR but stubbing here.
-        // Needs a valid storage variable or routing map to determine `treasury`
-        // Leaving // TODO: transfer slashed stake to treasury for now since issue specifies to replace // TODO: SAC transfer comments only
-
         storage::set_node(&env, &node_address, &node);
 
-        // Emit an event so the slashing reason is auditable on-chain.
-        env.events().publish(
-            (
-                soroban_sdk::Symbol::new(&env, "relay_registry"),
-                soroban_sdk::Symbol::new(&env, "slash"),
-            ),
-            (node_address.clone(), slashed_amount),
-        );
+        EVENTS.publish(&env, "begin_exit", (node_address, amount, unlock_after));
 
         Ok(())
     }
 
-    /// Reinstate a previously slashed relay node after a successful governance appeal.
+    /// Finish leaving the registry: withdraw the remaining unbonded stake and
+    /// remove the node record.
     ///
-    /// This function allows the admin council to move a node from `Slashed` back to
-    /// `Inactive` status after an off-chain appeals process determines that the slash
-    /// was unwarranted or due to non-malicious causes (e.g., hardware failure).
+    /// Delegators still waiting on undelegations from the node are paid out in
+    /// the same call, so none of them can hold the exit up.
     ///
-    /// The node does **not** regain any previously slashed stake; it must call `stake`
-    /// again to become `Active`.
+    /// The node's signing key history is kept so proofs it made can still be
+    /// checked. The address may register again afterwards as a new node.
     ///
     /// # Parameters
     /// - `env`: Soroban environment for the current contract invocation.
-    /// - `node_address`: Address of the relay node to reinstate.
+    /// - `node_address`: Stellar account address of the relay node. Must authorize this call.
+    ///
+    /// # Returns
+    /// The total amount released.
     ///
     /// # Errors
-    /// - `ContractError::NotRegistered` if the node is not in the registry.
-    /// - `ContractError::NodeNotSlashed` if the node is not currently slashed.
-    pub fn reinstate_node(env: Env, node_address: Address) -> Result<(), ContractError> {
+    /// - `ContractError::Paused` if the council has paused this function.
+    /// - `ContractError::NotRegistered` if the node is not found in the registry.
+    /// - `ContractError::NodeNotExiting` if the node has not called `begin_exit`.
+    /// - `ContractError::DisputeOpen` if the node is party to an unresolved dispute.
+    /// - `ContractError::LockPeriodActive` if one of the node's or its delegators' unbondings is still locked.
+    /// - `ContractError::Overflow` if the released total overflows.
+    pub fn complete_exit(env: Env, node_address: Address) -> Result<i128, ContractError> {
         storage::extend_instance_ttl(&env);
-        // Only the admin council may reinstate a slashed node.
-        require_council_auth(&env);
-
-        let mut node =
-            storage::get_node(&env, &node_address).ok_or(ContractError::NotRegistered)?;
+        require_not_paused(&env, PausableFn::CompleteExit)?;
+        node_address.require_auth();
 
-        // Reinstatement is only valid for nodes that are currently slashed.
-        if !matches!(node.status, NodeStatus::Slashed) {
-            return Err(ContractError::NodeNotSlashed);
+        let node = storage::get_node(&env, &node_address).ok_or(ContractError::NotRegistered)?;
+        if !matches!(node.status, NodeStatus::Exiting) {
+            return Err(ContractError::NodeNotExiting);
         }
+        require_no_open_dispute(&env, &node_address)?;
+
+        let unbondings = storage::get_unbondings(&env, &node_address);
+        let released = if unbondings.is_empty() {
+            0
+        } else {
+            let (pending, released) = release_matured(&env, &unbondings)?;
+            if !pending.is_empty() {
+                return Err(ContractError::LockPeriodActive);
+            }
+            released
+        };
+        pay_out_delegators(&env, &node_address)?;
+
+        storage::set_unbondings(&env, &node_address, &Vec::new(&env));
+        storage::remove_node(&env, &node_address);
+        storage::remove_uptime(&env, &node_address);
+        storage::remove_commission(&env, &node_address);
+        storage::remove_reputation(&env, &node_address);
+        storage::remove_jail(&env, &node_address);
+        let mut board = storage::get_top_nodes(&env);
+        if let Some(position) = board.first_index_of(&node_address) {
+            board.remove(position);
+            storage::set_top_nodes(&env, &board);
+        }
+        storage::decrement_node_count(&env);
 
-        // Move the node back to Inactive; stake remains at 0.
-        node.status = NodeStatus::Inactive;
-        node.last_active = env.ledger().timestamp();
-
-        storage::set_node(&env, &node_address, &node);
+        if released > 0 {
+            release_stake(&env, &node_address, released)?;
+        }
 
-        env.events().publish(
-            (
-                soroban_sdk::Symbol::new(&env, "relay_registry"),
-                soroban_sdk::Symbol::new(&env, "reinstate_node"),
-            ),
-            (node_address.clone(),),
-        );
+        EVENTS.publish(&env, "complete_exit", (node_address, released));
 
-        Ok(())
+        Ok(released)
     }
-    /// Retrieves a registered relay node's details.
-    ///
-    /// This is a view-only function that returns the `RelayNode` struct
-    /// associated with the given address. It does not require authorization.
+
+    /// Offer to move a node to `new_address`, the first step of an ownership
+    /// transfer. The move happens once `new_address` calls `accept_transfer`.
+    /// Proposing again replaces the pending offer.
     ///
     /// # Parameters
     /// - `env`: Soroban environment for the current contract invocation.
-    /// - `address`: The Stellar account address of the relay node to lookup.
+    /// - `node_address`: Current address of the relay node. Must authorize this call.
+    /// - `new_address`: Address that will operate the node. Must not be a registered node.
     ///
-    /// # Returns
-    /// - `Ok(RelayNode)`: The registered node details if found.
+    /// # Errors
+    /// - `ContractError::NotRegistered` if the node is not found in the registry.
+    /// - `ContractError::NodeSlashed` if the node has been slashed.
+    /// - `ContractError::NodeExiting` if the node is exiting.
+    /// - `ContractError::DisputeOpen` if the node is party to an unresolved dispute.
+    /// - `ContractError::AlreadyRegistered` if `new_address` is already a registered node.
+    pub fn propose_transfer(
+        env: Env,
+        node_address: Address,
+        new_address: Address,
+    ) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::ProposeTransfer)?;
+        node_address.require_auth();
+        require_transferable(&env, &node_address)?;
+        if storage::get_node(&env, &new_address).is_some() {
+            return Err(ContractError::AlreadyRegistered);
+        }
+
+        storage::set_pending_transfer(&env, &node_address, &new_address);
+
+        EVENTS.publish(&env, "propose_transfer", (node_address, new_address));
+
+        Ok(())
+    }
+
+    /// Withdraw a pending ownership transfer.
     ///
     /// # Errors
-    /// - `ContractError::NotRegistered`: If the address is not registered in the registry.
-    pub fn get_node(env: Env, address: Address) -> Result<RelayNode, ContractError> {
+    /// - `ContractError::NoPendingTransfer` if no transfer is pending for the node.
+    pub fn cancel_transfer(env: Env, node_address: Address) -> Result<(), ContractError> {
         storage::extend_instance_ttl(&env);
-        storage::get_node(&env, &address).ok_or(ContractError::NotRegistered)
+        require_not_paused(&env, PausableFn::CancelTransfer)?;
+        node_address.require_auth();
+        let new_address = storage::get_pending_transfer(&env, &node_address)
+            .ok_or(ContractError::NoPendingTransfer)?;
+
+        storage::remove_pending_transfer(&env, &node_address);
+
+        EVENTS.publish(&env, "cancel_transfer", (node_address, new_address));
+
+        Ok(())
     }
 
-    /// Checks if a relay node is currently active.
+    /// Accept a pending ownership transfer and move the node to `new_address`.
     ///
-    /// This is a view-only function that returns true if the given address is
-    /// a registered relay node with a status of `NodeStatus::Active`. It does not
-    /// require authorization. This function never errors; it returns false for any unknown or inactive address.
+    /// The node record, its stake, pending unbondings, delegations, commission,
+    /// uptime and reputation move to `new_address`; the node keeps its place in
+    /// the node index. A forwarding record is left at `node_address`, so
+    /// `get_node`, `is_active`, `split_rewards` and the reputation reports
+    /// resolve the old address to the new one, and the fee distributor's
+    /// `migrate_earnings` can move the old address's earnings. `get_stake`
+    /// does not follow it, so the old address cannot vote with the stake.
+    ///
+    /// Signing keys are not moved: the old key is closed and the new operator
+    /// registers its own with `register_signing_key`. A node whose stake voted
+    /// on a governance proposal (see `record_vote`) cannot be transferred until
+    /// voting on it has ended, so the stake cannot vote again from `new_address`.
     ///
     /// # Parameters
     /// - `env`: Soroban environment for the current contract invocation.
-    /// - `address`: The Stellar account address of the relay node to check.
+    /// - `node_address`: Current address of the relay node.
+    /// - `new_address`: Address the transfer was offered to. Must authorize this call.
     ///
-    /// # Returns
+    /// # Errors
+    /// - `ContractError::Paused` if the council has paused this function.
+    /// - `ContractError::NoPendingTransfer` if the node has not offered a transfer to `new_address`.
+    /// - `ContractError::NotRegistered` if the node is not found in the registry.
+    /// - `ContractError::NodeSlashed` if the node has been slashed.
+    /// - `ContractError::NodeExiting` if the node is exiting.
+    /// - `ContractError::DisputeOpen` if the node is party to an unresolved dispute.
+    /// - `ContractError::StakeLocked` if the node voted on a proposal that is still open.
+    /// - `ContractError::AlreadyRegistered` if `new_address` has registered a node since the offer.
+    pub fn accept_transfer(
+        env: Env,
+        node_address: Address,
+        new_address: Address,
+    ) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::AcceptTransfer)?;
+        new_address.require_auth();
+        if storage::get_pending_transfer(&env, &node_address).as_ref() != Some(&new_address) {
+            return Err(ContractError::NoPendingTransfer);
+        }
+        let mut node = require_transferable(&env, &node_address)?;
+        if storage::get_vote_lock(&env, &node_address) >= env.ledger().sequence() as u64 {
+            return Err(ContractError::StakeLocked);
+        }
+        if storage::get_node(&env, &new_address).is_some() {
+            return Err(ContractError::AlreadyRegistered);
+        }
+
+        node.address = new_address.clone();
+        node.last_active = env.ledger().timestamp();
+        storage::move_node(&env, &node_address, &new_address, &node);
+        move_node_state(&env, &node_address, &new_address);
+        storage::remove_pending_transfer(&env, &node_address);
+        storage::set_forwarding(&env, &node_address, &new_address);
+
+        EVENTS.publish(&env, "accept_transfer", (node_address, new_address));
+
+        Ok(())
+    }
+
+    /// Address a pending ownership transfer of the node was offered to, if any.
+    pub fn get_pending_transfer(env: Env, node_address: Address) -> Option<Address> {
+        storage::extend_instance_ttl(&env);
+        storage::get_pending_transfer(&env, &node_address)
+    }
+
+    /// Address the node registered at `address` now operates from, following
+    /// forwarding records left by ownership transfers. `None` if no node is
+    /// registered at or forwarded from `address`.
+    pub fn resolve_node(env: Env, address: Address) -> Option<Address> {
+        storage::extend_instance_ttl(&env);
+        let current = resolve_address(&env, address);
+        storage::get_node(&env, &current).map(|node| node.address)
+    }
+
+    /// Bond tokens to a registered relay node as a delegator.
+    ///
+    /// Delegated stake counts toward the node's `min_stake` activation, earns a
+    /// share of the node's fees (see `split_rewards`) and is slashed together
+    /// with the node's own stake.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `delegator`: Address bonding the tokens. Must authorize this call.
+    /// - `node_address`: Relay node to delegate to.
+    /// - `amount`: Amount of tokens to bond. Must be greater than zero.
+    ///
+    /// # Errors
+    /// - `ContractError::Paused` if the council has paused this function.
+    /// - `ContractError::InsufficientStake` if `amount` is zero or negative.
+    /// - `ContractError::NotRegistered` if the node is not found in the registry.
+    /// - `ContractError::NodeSlashed` if the node has been slashed.
+    /// - `ContractError::NodeExiting` if the node is exiting the registry.
+    /// - `ContractError::TooManyDelegators` if the node already has `MAX_DELEGATORS` delegators.
+    /// - `ContractError::Overflow` if the delegated amounts overflow.
+    pub fn delegate(
+        env: Env,
+        delegator: Address,
+        node_address: Address,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::Delegate)?;
+        delegator.require_auth();
+        if amount <= 0 {
+            return Err(ContractError::InsufficientStake);
+        }
+
+        let mut node =
+            storage::get_node(&env, &node_address).ok_or(ContractError::NotRegistered)?;
+        if matches!(node.status, NodeStatus::Slashed) {
+            return Err(ContractError::NodeSlashed);
+        }
+        if matches!(node.status, NodeStatus::Exiting) {
+            return Err(ContractError::NodeExiting);
+        }
+
+        let mut delegators = storage::get_delegators(&env, &node_address);
+        if !delegators.contains(&delegator) {
+            if delegators.len() >= storage::MAX_DELEGATORS {
+                return Err(ContractError::TooManyDelegators);
+            }
+            delegators.push_back(delegator.clone());
+        }
+
+        let delegation = storage::get_delegation(&env, &node_address, &delegator)
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        let delegated = storage::get_delegated_stake(&env, &node_address)
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        let bonded = node
+            .stake
+            .checked_add(delegated)
+            .ok_or(ContractError::Overflow)?;
+        if !matches!(node.status, NodeStatus::Jailed) && bonded >= storage::get_min_stake(&env) {
+            node.status = NodeStatus::Active;
+        }
+
+        take_stake(&env, &delegator, amount)?;
+
+        storage::set_delegation(&env, &node_address, &delegator, delegation);
+        storage::set_delegators(&env, &node_address, &delegators);
+        storage::set_delegated_stake(&env, &node_address, delegated);
+        storage::set_node(&env, &node_address, &node);
+
+        EVENTS.publish(&env, "delegate", (delegator, node_address, amount));
+
+        Ok(())
+    }
+
+    /// Begin unbonding delegated tokens from a relay node.
+    ///
+    /// The tokens go through the same lock period as `unstake` and stay
+    /// slashable until `finalize_undelegate` releases them. If the node's
+    /// bonded stake falls below `min_stake`, an `Active` node is demoted to `Inactive`.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `delegator`: Address that delegated the tokens. Must authorize this call.
+    /// - `node_address`: Relay node the tokens are delegated to.
+    /// - `amount`: Amount to undelegate.
+    ///
+    /// # Errors
+    /// - `ContractError::Paused` if the council has paused this function.
+    /// - `ContractError::InsufficientStake` if `amount` is zero, negative or above the delegation.
+    /// - `ContractError::NotRegistered` if the node is not found in the registry.
+    /// - `ContractError::TooManyUnbondings` if the delegator already has `MAX_UNBONDING_ENTRIES`
+    ///   pending undelegations from the node.
+    /// - `ContractError::Overflow` if the unlock time overflows.
+    pub fn undelegate(
+        env: Env,
+        delegator: Address,
+        node_address: Address,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::Undelegate)?;
+        delegator.require_auth();
+
+        let delegation = storage::get_delegation(&env, &node_address, &delegator);
+        if amount <= 0 || amount > delegation {
+            return Err(ContractError::InsufficientStake);
+        }
+
+        let mut node =
+            storage::get_node(&env, &node_address).ok_or(ContractError::NotRegistered)?;
+
+        let unlocks_at = unlock_point(&env)?;
+        let mut unbondings = storage::get_delegator_unbondings(&env, &node_address, &delegator);
+        if unbondings.len() >= storage::MAX_UNBONDING_ENTRIES {
+            return Err(ContractError::TooManyUnbondings);
+        }
+        unbondings.push_back(StakeEntry {
+            address: delegator.clone(),
+            amount,
+            unlocks_at,
+            unit: storage::get_lock_unit(&env),
+        });
+
+        let delegated = storage::get_delegated_stake(&env, &node_address) - amount;
+        storage::set_delegation(&env, &node_address, &delegator, delegation - amount);
+        storage::set_delegated_stake(&env, &node_address, delegated);
+        storage::set_delegator_unbondings(&env, &node_address, &delegator, &unbondings);
+
+        if matches!(node.status, NodeStatus::Active)
+            && bonded_stake(&env, &node_address, node.stake)? < storage::get_min_stake(&env)
+        {
+            node.status = NodeStatus::Inactive;
+            storage::set_node(&env, &node_address, &node);
+        }
+
+        EVENTS.publish(
+            &env,
+            "undelegate",
+            (delegator, node_address, amount, unlocks_at),
+        );
+
+        Ok(())
+    }
+
+    /// Withdraw undelegated tokens whose lock period has ended.
+    ///
+    /// Releases every matured undelegation from `node_address` in a single
+    /// transfer; undelegations that are still locked stay queued.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `delegator`: Address that undelegated the tokens. Must authorize this call.
+    /// - `node_address`: Relay node the tokens were delegated to.
+    ///
+    /// # Returns
+    /// The total amount released.
+    ///
+    /// # Errors
+    /// - `ContractError::Paused` if the council has paused this function.
+    /// - `ContractError::NoPendingUnstake` if the delegator has no pending undelegation.
+    /// - `ContractError::LockPeriodActive` if none of the pending undelegations has unlocked yet.
+    /// - `ContractError::Overflow` if the released total overflows.
+    pub fn finalize_undelegate(
+        env: Env,
+        delegator: Address,
+        node_address: Address,
+    ) -> Result<i128, ContractError> {
+        storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::FinalizeUndelegate)?;
+        delegator.require_auth();
+
+        let unbondings = storage::get_delegator_unbondings(&env, &node_address, &delegator);
+        if unbondings.is_empty() {
+            return Err(ContractError::NoPendingUnstake);
+        }
+
+        let (pending, released) = release_matured(&env, &unbondings)?;
+        storage::set_delegator_unbondings(&env, &node_address, &delegator, &pending);
+
+        // Drop delegators with nothing left bonded to the node.
+        if pending.is_empty() && storage::get_delegation(&env, &node_address, &delegator) == 0 {
+            let mut delegators = storage::get_delegators(&env, &node_address);
+            if let Some(index) = delegators.first_index_of(&delegator) {
+                delegators.remove(index);
+                storage::set_delegators(&env, &node_address, &delegators);
+            }
+        }
+
+        release_stake(&env, &delegator, released)?;
+
+        EVENTS.publish(
+            &env,
+            "finalize_undelegate",
+            (delegator, node_address, released),
+        );
+
+        Ok(released)
+    }
+
+    /// Set the commission a relay node keeps from its delegators' fee share.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `node_address`: Relay node operator. Must authorize this call.
+    /// - `commission_bps`: Commission in basis points (0 to 10000).
+    ///
+    /// # Errors
+    /// - `ContractError::NotRegistered` if the node is not found in the registry.
+    /// - `ContractError::InvalidCommission` if `commission_bps` is above 10000.
+    pub fn set_commission(
+        env: Env,
+        node_address: Address,
+        commission_bps: u32,
+    ) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::SetCommission)?;
+        node_address.require_auth();
+        if storage::get_node(&env, &node_address).is_none() {
+            return Err(ContractError::NotRegistered);
+        }
+        if commission_bps > storage::MAX_BPS {
+            return Err(ContractError::InvalidCommission);
+        }
+
+        storage::set_commission(&env, &node_address, commission_bps);
+
+        EVENTS.publish(&env, "set_commission", (node_address, commission_bps));
+
+        Ok(())
+    }
+
+    /// Split a fee `amount` earned by a relay node between its operator and delegators.
+    ///
+    /// The delegators' part is proportional to their share of the node's bonded
+    /// stake; the operator keeps its own part plus its commission on the
+    /// delegators' part, and any rounding remainder. The operator comes first
+    /// in the result; delegators whose share rounds to zero are left out.
+    /// A node that changed address is paid at its new address.
+    /// Called by the fee distributor when it credits earnings.
+    ///
+    /// # Errors
+    /// - `ContractError::Overflow` if the split arithmetic overflows.
+    pub fn split_rewards(
+        env: Env,
+        node_address: Address,
+        amount: i128,
+    ) -> Result<Vec<(Address, i128)>, ContractError> {
+        storage::extend_instance_ttl(&env);
+        let node_address = resolve_address(&env, node_address);
+        let mut shares = Vec::new(&env);
+        let delegated = storage::get_delegated_stake(&env, &node_address);
+        if delegated == 0 || amount <= 0 {
+            shares.push_back((node_address, amount));
+            return Ok(shares);
+        }
+
+        let stake = storage::get_node(&env, &node_address).map_or(0, |node| node.stake);
+        let bonded = bonded_stake(&env, &node_address, stake)?;
+        let pool = amount
+            .checked_mul(delegated)
+            .ok_or(ContractError::Overflow)?
+            / bonded;
+        let distributable = pool - bps_of(pool, storage::get_commission(&env, &node_address))?;
+
+        let mut paid: i128 = 0;
+        for delegator in storage::get_delegators(&env, &node_address).iter() {
+            let share = distributable
+                .checked_mul(storage::get_delegation(&env, &node_address, &delegator))
+                .ok_or(ContractError::Overflow)?
+                / delegated;
+            if share > 0 {
+                paid += share;
+                shares.push_back((delegator, share));
+            }
+        }
+        shares.push_front((node_address, amount - paid));
+        Ok(shares)
+    }
+
+    /// Amount `delegator` currently has delegated to `node_address`.
+    pub fn get_delegation(env: Env, node_address: Address, delegator: Address) -> i128 {
+        storage::extend_instance_ttl(&env);
+        storage::get_delegation(&env, &node_address, &delegator)
+    }
+
+    /// Delegators of a node that still have a delegation or a pending undelegation.
+    pub fn get_delegators(env: Env, node_address: Address) -> Vec<Address> {
+        storage::extend_instance_ttl(&env);
+        storage::get_delegators(&env, &node_address)
+    }
+
+    /// Total amount currently delegated to a node, excluding pending undelegations.
+    pub fn get_delegated_stake(env: Env, node_address: Address) -> i128 {
+        storage::extend_instance_ttl(&env);
+        storage::get_delegated_stake(&env, &node_address)
+    }
+
+    /// Pending undelegations of `delegator` from a node, oldest first.
+    pub fn get_delegator_unbondings(
+        env: Env,
+        node_address: Address,
+        delegator: Address,
+    ) -> Vec<StakeEntry> {
+        storage::extend_instance_ttl(&env);
+        storage::get_delegator_unbondings(&env, &node_address, &delegator)
+    }
+
+    /// When each pending undelegation of `delegator` from a node unlocks, in
+    /// ledgers and seconds, in the same order as `get_delegator_unbondings`.
+    pub fn get_delegator_unlock_etas(
+        env: Env,
+        node_address: Address,
+        delegator: Address,
+    ) -> Vec<UnlockEta> {
+        storage::extend_instance_ttl(&env);
+        let unbondings = storage::get_delegator_unbondings(&env, &node_address, &delegator);
+        unlock_etas(&env, &unbondings)
+    }
+
+    /// Commission a node keeps from its delegators' fee share, in basis points.
+    pub fn get_commission(env: Env, node_address: Address) -> u32 {
+        storage::extend_instance_ttl(&env);
+        storage::get_commission(&env, &node_address)
+    }
+
+    /// Record a liveness heartbeat from a relay node.
+    ///
+    /// Time is divided into heartbeat slots of `HEARTBEAT_INTERVAL` seconds and
+    /// epochs of `SLOTS_PER_EPOCH` slots. A node's uptime for an epoch is the
+    /// share of slots, from when tracking started, in which it sent at least one
+    /// heartbeat; further heartbeats in the same slot are accepted but not counted.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `node_address`: Stellar account address of the relay node. Must authorize this call.
+    ///
+    /// # Errors
+    /// - `ContractError::NotRegistered` if the node is not found in the registry.
+    /// - `ContractError::NodeSlashed` if the node has been slashed.
+    pub fn heartbeat(env: Env, node_address: Address) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::Heartbeat)?;
+        node_address.require_auth();
+
+        let mut node =
+            storage::get_node(&env, &node_address).ok_or(ContractError::NotRegistered)?;
+        if matches!(node.status, NodeStatus::Slashed) {
+            return Err(ContractError::NodeSlashed);
+        }
+
+        let slot = current_slot(&env);
+        let mut record = load_uptime(&env, &node_address);
+        if record.heartbeats == 0 || slot > record.last_slot {
+            record.heartbeats += 1;
+            record.last_slot = slot;
+        }
+        storage::set_uptime(&env, &node_address, &record);
+
+        node.last_active = env.ledger().timestamp();
+        storage::set_node(&env, &node_address, &node);
+
+        EVENTS.publish(&env, "heartbeat", (node_address, slot));
+
+        Ok(())
+    }
+
+    /// Measure a node's uptime for the previous epoch against its `uptime_commitment`.
+    ///
+    /// Anyone may call this. The first call in an epoch evaluates the previous
+    /// epoch; later calls in the same epoch only report the result. A node that
+    /// fell short has a `LivenessFault` recorded and, if it was `Active`, is
+    /// jailed as by a `Jail` proposal; it returns through `unjail` once the
+    /// sentence runs out.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `node_address`: Relay node to check.
+    ///
+    /// # Returns
+    /// The node's uptime percentage for the previous epoch, or `None` while no
+    /// full epoch has been tracked for it.
+    ///
+    /// # Errors
+    /// - `ContractError::NotRegistered` if the node is not found in the registry.
+    /// - `ContractError::NodeSlashed` if the node has been slashed.
+    /// - `ContractError::Overflow` if the jail penalty or release time overflows.
+    pub fn check_liveness(env: Env, node_address: Address) -> Result<Option<u32>, ContractError> {
+        storage::extend_instance_ttl(&env);
+        let node = storage::get_node(&env, &node_address).ok_or(ContractError::NotRegistered)?;
+        if matches!(node.status, NodeStatus::Slashed) {
+            return Err(ContractError::NodeSlashed);
+        }
+
+        let mut record = load_uptime(&env, &node_address);
+        if record.checked_epoch == record.epoch {
+            storage::set_uptime(&env, &node_address, &record);
+            return Ok(record.last_uptime);
+        }
+        record.checked_epoch = record.epoch;
+        storage::set_uptime(&env, &node_address, &record);
+
+        let Some(uptime) = record.last_uptime else {
+            return Ok(None);
+        };
+        let commitment = node.metadata.uptime_commitment;
+        if uptime >= commitment {
+            adjust_reputation(&env, &node_address, storage::LIVENESS_REPUTATION, |_| {});
+        } else {
+            let fault = LivenessFault {
+                epoch: record.epoch - 1,
+                uptime,
+                commitment,
+            };
+            storage::set_liveness_fault(&env, &node_address, &fault);
+            adjust_reputation(
+                &env,
+                &node_address,
+                storage::LIVENESS_FAULT_REPUTATION,
+                |_| {},
+            );
+
+            EVENTS.publish(
+                &env,
+                "liveness_fault",
+                (node_address.clone(), fault.epoch, uptime, commitment),
+            );
+
+            if matches!(node.status, NodeStatus::Active) {
+                Self::jail(
+                    env.clone(),
+                    node_address,
+                    String::from_str(&env, "liveness fault"),
+                )?;
+            }
+        }
+
+        Ok(Some(uptime))
+    }
+
+    /// A node's heartbeat tally, rolled forward to the current epoch.
+    pub fn get_uptime(env: Env, node_address: Address) -> Option<UptimeRecord> {
+        storage::extend_instance_ttl(&env);
+        storage::get_uptime(&env, &node_address).map(|_| load_uptime(&env, &node_address))
+    }
+
+    /// Latest liveness fault recorded for a node by `check_liveness`.
+    pub fn get_liveness_fault(env: Env, node_address: Address) -> Option<LivenessFault> {
+        storage::extend_instance_ttl(&env);
+        storage::get_liveness_fault(&env, &node_address)
+    }
+
+    /// Return a jailed node to the relay set once its sentence has run out.
+    ///
+    /// The node becomes `Active` if its bonded stake still meets the minimum
+    /// stake, and `Inactive` otherwise.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `node_address`: Jailed relay node. Must authorize this call.
+    ///
+    /// # Errors
+    /// - `ContractError::Paused` if the council has paused this function.
+    /// - `ContractError::NotRegistered` if the node is not found in the registry.
+    /// - `ContractError::NodeNotJailed` if the node is not jailed.
+    /// - `ContractError::JailActive` if the node's release time has not been reached,
+    ///   or the slash its jailing escalated to is still queued.
+    /// - `ContractError::Overflow` if the node's bonded stake overflows.
+    pub fn unjail(env: Env, node_address: Address) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::Unjail)?;
+        node_address.require_auth();
+
+        let mut node =
+            storage::get_node(&env, &node_address).ok_or(ContractError::NotRegistered)?;
+        if !matches!(node.status, NodeStatus::Jailed) {
+            return Err(ContractError::NodeNotJailed);
+        }
+        let record = storage::get_jail(&env, &node_address).ok_or(ContractError::NodeNotJailed)?;
+        let now = env.ledger().timestamp();
+        if now < record.until {
+            return Err(ContractError::JailActive);
+        }
+        let slash_queued = record.pending_slash.is_some_and(|proposal_id| {
+            storage::get_proposal(&env, proposal_id)
+                .is_some_and(|proposal| proposal.status == ProposalStatus::Queued)
+        });
+        if slash_queued {
+            return Err(ContractError::JailActive);
+        }
+
+        node.status =
+            if bonded_stake(&env, &node_address, node.stake)? >= storage::get_min_stake(&env) {
+                NodeStatus::Active
+            } else {
+                NodeStatus::Inactive
+            };
+        node.last_active = now;
+        storage::set_node(&env, &node_address, &node);
+
+        EVENTS.publish(&env, "unjail", (node_address, node.status));
+
+        Ok(())
+    }
+
+    /// A node's jail history, kept after release until it exits the registry.
+    pub fn get_jail(env: Env, node_address: Address) -> Option<JailRecord> {
+        storage::extend_instance_ttl(&env);
+        storage::get_jail(&env, &node_address)
+    }
+
+    /// Report a batch the node settled, raising its reputation by `BATCH_REPUTATION`.
+    ///
+    /// Called by the fee distributor from `distribute`.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `reporter`: Holder of `Role::BatchReporter`. Must authorize this call.
+    /// - `node_address`: Relay node that settled the batch.
+    ///
+    /// # Errors
+    /// - `ContractError::Unauthorized` if `reporter` does not hold `Role::BatchReporter`.
+    /// - `ContractError::NotRegistered` if the node is not found in the registry.
+    pub fn record_batch(
+        env: Env,
+        reporter: Address,
+        node_address: Address,
+    ) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        require_reporter(&env, &reporter, Role::BatchReporter)?;
+        let node_address = resolve_address(&env, node_address);
+        if storage::get_node(&env, &node_address).is_none() {
+            return Err(ContractError::NotRegistered);
+        }
+
+        adjust_reputation(&env, &node_address, storage::BATCH_REPUTATION, |record| {
+            record.settled_batches = record.settled_batches.saturating_add(1);
+        });
+
+        Ok(())
+    }
+
+    /// Record that the node's stake voted on a governance proposal open until
+    /// ledger `until`. The node cannot accept an ownership transfer before
+    /// that ledger has passed, so its stake cannot vote again from a new address.
+    ///
+    /// Called by governance from `vote`.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `reporter`: Holder of `Role::VoteRecorder`. Must authorize this call.
+    /// - `node_address`: Relay node that voted.
+    /// - `until`: Last ledger of the proposal's voting period.
+    ///
+    /// # Errors
+    /// - `ContractError::Unauthorized` if `reporter` does not hold `Role::VoteRecorder`.
+    /// - `ContractError::NotRegistered` if the node is not found in the registry.
+    pub fn record_vote(
+        env: Env,
+        reporter: Address,
+        node_address: Address,
+        until: u64,
+    ) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        require_reporter(&env, &reporter, Role::VoteRecorder)?;
+        if storage::get_node(&env, &node_address).is_none() {
+            return Err(ContractError::NotRegistered);
+        }
+
+        if until > storage::get_vote_lock(&env, &node_address) {
+            storage::set_vote_lock(&env, &node_address, until);
+        }
+
+        Ok(())
+    }
+
+    /// Report the outcome of a resolved dispute. The winner gains
+    /// `DISPUTE_WIN_REPUTATION` and the loser, if there was one, takes
+    /// `DISPUTE_LOSS_REPUTATION`. Parties that are not registered nodes are
+    /// skipped; parties that changed address are credited at the new one.
+    ///
+    /// Called by the dispute resolver from `resolve`.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `reporter`: Holder of `Role::DisputeReporter`. Must authorize this call.
+    /// - `winner`: Party the ruling went to.
+    /// - `loser`: The other party, or `None` if the respondent never answered.
+    ///
+    /// # Errors
+    /// - `ContractError::Unauthorized` if `reporter` does not hold `Role::DisputeReporter`.
+    pub fn record_dispute(
+        env: Env,
+        reporter: Address,
+        winner: Address,
+        loser: Option<Address>,
+    ) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        require_reporter(&env, &reporter, Role::DisputeReporter)?;
+        let winner = resolve_address(&env, winner);
+        let loser = loser.map(|loser| resolve_address(&env, loser));
+
+        if storage::get_node(&env, &winner).is_some() {
+            adjust_reputation(&env, &winner, storage::DISPUTE_WIN_REPUTATION, |record| {
+                record.disputes_won = record.disputes_won.saturating_add(1);
+            });
+        }
+        if let Some(loser) = loser.filter(|loser| storage::get_node(&env, loser).is_some()) {
+            adjust_reputation(&env, &loser, storage::DISPUTE_LOSS_REPUTATION, |record| {
+                record.disputes_lost = record.disputes_lost.saturating_add(1);
+            });
+        }
+
+        Ok(())
+    }
+
+    /// A node's reputation decayed to the current epoch, if anything was recorded for it.
+    pub fn get_reputation(env: Env, node_address: Address) -> Option<ReputationRecord> {
+        storage::extend_instance_ttl(&env);
+        storage::get_reputation(&env, &node_address).map(|_| load_reputation(&env, &node_address))
+    }
+
+    /// Up to `limit` active nodes with the highest reputation, best first, with
+    /// their current scores.
+    ///
+    /// Ranks the `TOP_NODES_CAPACITY` nodes on the reputation board. A node
+    /// joins the board when its score is updated and beats the lowest entry.
+    pub fn top_nodes(env: Env, limit: u32) -> Vec<(Address, i64)> {
+        storage::extend_instance_ttl(&env);
+        let mut top = Vec::new(&env);
+        for node_address in storage::get_top_nodes(&env).iter() {
+            if top.len() >= limit {
+                break;
+            }
+            let active = storage::get_node(&env, &node_address)
+                .is_some_and(|node| matches!(node.status, NodeStatus::Active));
+            if active {
+                let score = load_reputation(&env, &node_address).score;
+                top.push_back((node_address, score));
+            }
+        }
+        top
+    }
+
+    /// Pick up to `count` distinct `Active` nodes, optionally only in `region`,
+    /// each draw weighted by the node's bonded stake times its capacity.
+    ///
+    /// With a `seed` the PRNG is reseeded first, so the same seed and registry
+    /// state always give the same relays in the same order; the fee distributor
+    /// uses this to check batch assignments. Without one the sample comes from
+    /// the Soroban PRNG of the current invocation. `count` is capped at
+    /// `MAX_RELAY_SELECTION`, and nodes with no bonded stake or capacity are
+    /// never picked.
+    ///
+    /// To keep the cost bounded, candidates come from a window of at most
+    /// `MAX_SELECTION_WINDOW` consecutive node index positions, wrapping
+    /// around, that starts at a random position. Once more nodes than that
+    /// have been indexed, each draw only sees the nodes in its window.
+    pub fn select_relays(
+        env: Env,
+        seed: Option<BytesN<32>>,
+        count: u32,
+        region: Option<String>,
+    ) -> Vec<Address> {
+        storage::extend_instance_ttl(&env);
+        if let Some(seed) = seed {
+            env.prng().seed(seed.into());
+        }
+
+        let indexed = storage::get_indexed_node_count(&env);
+        let window = indexed.min(storage::MAX_SELECTION_WINDOW);
+        let start = if indexed > window {
+            env.prng().gen_range::<u64>(0..indexed as u64) as u32
+        } else {
+            0
+        };
+
+        let mut candidates: Vec<(Address, u128)> = Vec::new(&env);
+        let mut total: u128 = 0;
+        for offset in 0..window {
+            let position = ((start as u64 + offset as u64) % indexed as u64) as u32;
+            let Some(node) = storage::get_indexed_node(&env, position)
+                .and_then(|address| storage::get_node(&env, &address))
+            else {
+                continue;
+            };
+            if !matches!(node.status, NodeStatus::Active)
+                || region
+                    .as_ref()
+                    .is_some_and(|region| *region != node.metadata.region)
+            {
+                continue;
+            }
+            let weight = selection_weight(&env, &node);
+            if weight > 0 {
+                total = total.saturating_add(weight);
+                candidates.push_back((node.address, weight));
+            }
+        }
+
+        let count = count.min(storage::MAX_RELAY_SELECTION);
+        let mut selected = Vec::new(&env);
+        while selected.len() < count && total > 0 {
+            let high = env.prng().gen::<u64>() as u128;
+            let low = env.prng().gen::<u64>() as u128;
+            let pick = ((high << 64) | low) % total;
+
+            let mut cumulative: u128 = 0;
+            for index in 0..candidates.len() {
+                let (address, weight) = candidates.get_unchecked(index);
+                cumulative = cumulative.saturating_add(weight);
+                if pick < cumulative {
+                    selected.push_back(address);
+                    candidates.remove(index);
+                    total = total.saturating_sub(weight);
+                    break;
+                }
+            }
+        }
+        selected
+    }
+
+    /// Register the Ed25519 key a relay node signs relay chain proofs with.
+    ///
+    /// `signature` must be the new key's signature over `get_key_proof_digest`
+    /// for this node and key, proving the node holds the private key.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `node_address`: Stellar account address of the relay node. Must authorize this call.
+    /// - `public_key`: Raw 32-byte Ed25519 public key.
+    /// - `signature`: Proof of possession made with the matching private key.
+    ///
+    /// # Errors
+    /// - `ContractError::NotRegistered` if the node is not found in the registry.
+    /// - `ContractError::NodeSlashed` if the node has been slashed.
+    /// - `ContractError::SigningKeyAlreadySet` if the node already registered a key.
+    /// - `ContractError::InvalidKeyProof` if `signature` does not verify.
+    pub fn register_signing_key(
+        env: Env,
+        node_address: Address,
+        public_key: BytesN<32>,
+        signature: BytesN<64>,
+    ) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::RegisterSigningKey)?;
+        node_address.require_auth();
+        require_unslashed_node(&env, &node_address)?;
+        if storage::get_signing_key_count(&env, &node_address) > 0 {
+            return Err(ContractError::SigningKeyAlreadySet);
+        }
+
+        install_signing_key(&env, &node_address, &public_key, &signature)?;
+
+        EVENTS.publish(&env, "register_key", (node_address, public_key));
+
+        Ok(())
+    }
+
+    /// Replace a relay node's signing key, e.g. after a compromise.
+    ///
+    /// The replaced key stays in the node's key history with the time it was
+    /// rotated out, so proofs made while it was active still verify against it.
+    /// The proof of possession works as for `register_signing_key`.
+    ///
+    /// # Errors
+    /// - `ContractError::NotRegistered` if the node is not found in the registry.
+    /// - `ContractError::NodeSlashed` if the node has been slashed.
+    /// - `ContractError::SigningKeyNotSet` if the node has no key to rotate.
+    /// - `ContractError::InvalidKeyProof` if `signature` does not verify.
+    pub fn rotate_signing_key(
+        env: Env,
+        node_address: Address,
+        public_key: BytesN<32>,
+        signature: BytesN<64>,
+    ) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::RotateSigningKey)?;
+        node_address.require_auth();
+        require_unslashed_node(&env, &node_address)?;
+        if storage::get_signing_key_count(&env, &node_address) == 0 {
+            return Err(ContractError::SigningKeyNotSet);
+        }
+
+        let position = install_signing_key(&env, &node_address, &public_key, &signature)?;
+
+        EVENTS.publish(&env, "rotate_key", (node_address, public_key, position));
+
+        Ok(())
+    }
+
+    /// Digest the node must sign with `public_key` to register or rotate to it.
+    pub fn get_key_proof_digest(
+        env: Env,
+        node_address: Address,
+        public_key: BytesN<32>,
+    ) -> BytesN<32> {
+        storage::extend_instance_ttl(&env);
+        key_proof_digest(&env, &node_address, &public_key)
+    }
+
+    /// The signing key a node currently uses, if it registered one.
+    pub fn get_signing_key(env: Env, node_address: Address) -> Option<BytesN<32>> {
+        storage::extend_instance_ttl(&env);
+        let current = storage::get_signing_key_count(&env, &node_address);
+        storage::get_signing_key(&env, &node_address, current).map(|record| record.public_key)
+    }
+
+    /// The signing key that was active for a node at ledger `timestamp`.
+    ///
+    /// Used by the dispute resolver to check a proof against the key the node
+    /// held when the proof was made, even if it has since been rotated. Returns
+    /// `None` from the time a key was closed without a successor, as when the
+    /// node moved to another address through `accept_transfer`.
+    pub fn get_signing_key_at(
+        env: Env,
+        node_address: Address,
+        timestamp: u64,
+    ) -> Option<BytesN<32>> {
+        storage::extend_instance_ttl(&env);
+        let mut position = storage::get_signing_key_count(&env, &node_address);
+        while position > 0 {
+            let record = storage::get_signing_key(&env, &node_address, position)?;
+            if record.active_from <= timestamp {
+                return match record.active_until {
+                    Some(active_until) if timestamp >= active_until => None,
+                    _ => Some(record.public_key),
+                };
+            }
+            position -= 1;
+        }
+        None
+    }
+
+    /// A page of a node's signing key history, oldest first, starting at
+    /// position `start`. Returns at most `limit` keys, capped at `MAX_LOG_PAGE_SIZE`.
+    pub fn get_signing_key_history(
+        env: Env,
+        node_address: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<SigningKeyRecord> {
+        storage::extend_instance_ttl(&env);
+        let count = storage::get_signing_key_count(&env, &node_address);
+        let mut page = Vec::new(&env);
+        for position in log_page(start as u64, limit, count as u64) {
+            if let Some(record) = storage::get_signing_key(&env, &node_address, position as u32) {
+                page.push_back(record);
+            }
+        }
+        page
+    }
+
+    /// Penalize a misbehaving relay node by forfeiting part or all of its stake.
+    ///
+    /// The tier configured for `reason_code` gives the basis points taken from
+    /// the node's stake and from each of its pending unbondings, so unstaking
+    /// ahead of a slash does not escape it. Delegations to the node and their
+    /// pending undelegations are cut at the same rate. The forfeited tokens are
+    /// deposited into the configured treasury. A full (10000 bps) slash sets the
+    /// node's status to `Slashed`; a partial slash leaves an `Active` node
+    /// `Inactive` if its remaining bonded stake drops below the minimum.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment.
+    /// - `node_address`: Address of the relay node to slash.
+    /// - `reason_code`: Reason code selecting the slash tier.
+    /// - `reason`: A string explaining the reason for the slash (emitted as an event).
+    ///
+    /// # Errors
+    /// - `ContractError::NotRegistered` if the node is not in the registry.
+    /// - `ContractError::NodeSlashed` if the node is already slashed.
+    /// - `ContractError::UnknownSlashReason` if no tier is configured for `reason_code`.
+    /// - `ContractError::TreasuryNotSet` if there is stake to forfeit but no treasury is configured.
+    /// - `ContractError::Overflow` if the slashed amounts overflow.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::Slash`).
+    fn slash(
+        env: Env,
+        node_address: Address,
+        reason_code: u32,
+        reason: String,
+    ) -> Result<(), ContractError> {
+        let mut node =
+            storage::get_node(&env, &node_address).ok_or(ContractError::NotRegistered)?;
+
+        // Ensure we don't slash a node that is already slashed.
+        if matches!(node.status, NodeStatus::Slashed) {
+            return Err(ContractError::NodeSlashed);
+        }
+
+        let bps =
+            storage::get_slash_tier(&env, reason_code).ok_or(ContractError::UnknownSlashReason)?;
+
+        let stake_cut = bps_of(node.stake, bps)?;
+        node.stake -= stake_cut;
+
+        let unbondings = storage::get_unbondings(&env, &node_address);
+        let (unbondings, unbonding_cut) = cut_unbondings(&env, &unbondings, bps)?;
+        storage::set_unbondings(&env, &node_address, &unbondings);
+
+        // Delegators back the node, so they share the penalty at the same rate.
+        let delegation_cut = slash_delegations(&env, &node_address, bps)?;
+
+        let slashed_amount = stake_cut
+            .checked_add(unbonding_cut)
+            .and_then(|total| total.checked_add(delegation_cut))
+            .ok_or(ContractError::Overflow)?;
+
+        if bps == storage::MAX_BPS {
+            node.status = NodeStatus::Slashed;
+        } else if matches!(node.status, NodeStatus::Active)
+            && bonded_stake(&env, &node_address, node.stake)? < storage::get_min_stake(&env)
+        {
+            node.status = NodeStatus::Inactive;
+        }
+        node.last_active = env.ledger().timestamp();
+
+        storage::set_node(&env, &node_address, &node);
+        let penalty = storage::SLASH_REPUTATION * bps as i64 / storage::MAX_BPS as i64;
+        adjust_reputation(&env, &node_address, penalty, |_| {});
+
+        if slashed_amount > 0 {
+            deposit_to_treasury(&env, slashed_amount)?;
+        }
+
+        // Emit an event so the slashing reason is auditable on-chain.
+        EVENTS.publish(
+            &env,
+            "slash",
+            (node_address.clone(), reason_code, slashed_amount, reason),
+        );
+
+        Ok(())
+    }
+
+    /// Reinstate a previously slashed relay node after a successful governance appeal.
+    ///
+    /// This function allows the admin council to move a node from `Slashed` back to
+    /// `Inactive` status after an off-chain appeals process determines that the slash
+    /// was unwarranted or due to non-malicious causes (e.g., hardware failure).
+    ///
+    /// The node does **not** regain any previously slashed stake; it must call `stake`
+    /// again to become `Active`.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `node_address`: Address of the relay node to reinstate.
+    ///
+    /// # Errors
+    /// - `ContractError::NotRegistered` if the node is not in the registry.
+    /// - `ContractError::NodeNotSlashed` if the node is not currently slashed.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::ReinstateNode`).
+    fn reinstate_node(env: Env, node_address: Address) -> Result<(), ContractError> {
+        let mut node =
+            storage::get_node(&env, &node_address).ok_or(ContractError::NotRegistered)?;
+
+        // Reinstatement is only valid for nodes that are currently slashed.
+        if !matches!(node.status, NodeStatus::Slashed) {
+            return Err(ContractError::NodeNotSlashed);
+        }
+
+        // Move the node back to Inactive; stake remains at 0.
+        node.status = NodeStatus::Inactive;
+        node.last_active = env.ledger().timestamp();
+
+        storage::set_node(&env, &node_address, &node);
+
+        EVENTS.publish(&env, "reinstate_node", (node_address.clone(),));
+
+        Ok(())
+    }
+
+    /// Jail a relay node: it leaves the relay set for `JAIL_PERIOD` and forfeits
+    /// `JAIL_PENALTY_BPS` of its own stake to the treasury. Delegations are left
+    /// untouched.
+    ///
+    /// Jailings within `JAIL_RECORD_WINDOW` of the previous one add up; the
+    /// `JAILS_BEFORE_SLASH`-th also queues a full slash as a council proposal
+    /// under the slash timelock, which guardians can veto, and the node stays
+    /// jailed until that proposal is no longer queued. Without a treasury there
+    /// is nowhere to forfeit stake to, so the node is jailed without a penalty
+    /// and nothing is queued.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `node_address`: Address of the relay node to jail.
+    /// - `reason`: Human-readable note, published with the event.
+    ///
+    /// # Errors
+    /// - `ContractError::NotRegistered` if the node is not in the registry.
+    /// - `ContractError::NodeSlashed` if the node has been slashed.
+    /// - `ContractError::NodeJailed` if the node is already jailed.
+    /// - `ContractError::NodeExiting` if the node is exiting the registry.
+    /// - `ContractError::Overflow` if the penalty or release time overflows.
+    ///
+    /// Reached through an executed council proposal (`ProposalAction::Jail`) and
+    /// from `check_liveness`.
+    fn jail(env: Env, node_address: Address, reason: String) -> Result<(), ContractError> {
+        let mut node =
+            storage::get_node(&env, &node_address).ok_or(ContractError::NotRegistered)?;
+        match node.status {
+            NodeStatus::Slashed => return Err(ContractError::NodeSlashed),
+            NodeStatus::Jailed => return Err(ContractError::NodeJailed),
+            NodeStatus::Exiting => return Err(ContractError::NodeExiting),
+            _ => {}
+        }
+
+        let now = env.ledger().timestamp();
+        let previous = storage::get_jail(&env, &node_address)
+            .filter(|record| now.saturating_sub(record.jailed_at) <= storage::JAIL_RECORD_WINDOW)
+            .map_or(0, |record| record.jail_count);
+        let jail_count = previous.saturating_add(1);
+        let has_treasury = storage::get_treasury(&env).is_some();
+        let pending_slash = if jail_count >= storage::JAILS_BEFORE_SLASH && has_treasury {
+            Some(queue_escalated_slash(&env, &node_address, reason.clone()))
+        } else {
+            None
+        };
+        let record = JailRecord {
+            until: now
+                .checked_add(storage::JAIL_PERIOD)
+                .ok_or(ContractError::Overflow)?,
+            jailed_at: now,
+            jail_count,
+            pending_slash,
+        };
+
+        let penalty = if has_treasury {
+            bps_of(node.stake, storage::JAIL_PENALTY_BPS)?
+        } else {
+            0
+        };
+        node.stake -= penalty;
+        node.status = NodeStatus::Jailed;
+        node.last_active = now;
+
+        storage::set_node(&env, &node_address, &node);
+        storage::set_jail(&env, &node_address, &record);
+
+        if penalty > 0 {
+            deposit_to_treasury(&env, penalty)?;
+        }
+
+        EVENTS.publish(
+            &env,
+            "jail",
+            (
+                node_address,
+                record.until,
+                penalty,
+                record.jail_count,
+                reason,
+            ),
+        );
+
+        Ok(())
+    }
+
+    /// Add a new member to the admin council.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::AddMember`).
+    ///
+    /// # Errors
+    /// - `ContractError::InvalidCouncilConfig` if `member` is already on the council or the
+    ///   council would exceed `MAX_COUNCIL_MEMBERS`.
+    fn add_member(env: Env, member: Address) -> Result<(), ContractError> {
+        let mut council = storage::get_admin_council(&env);
+        council.add_member(&member)?;
+        storage::set_admin_council(&env, &council);
+
+        EVENTS.member_added(&env, &member, council.members.len());
+
+        Ok(())
+    }
+
+    /// Remove a member from the admin council.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::RemoveMember`).
+    ///
+    /// # Errors
+    /// - `ContractError::NotCouncilMember` if `member` is not on the council.
+    /// - `ContractError::InvalidCouncilConfig` if the threshold would exceed the remaining members.
+    fn remove_member(env: Env, member: Address) -> Result<(), ContractError> {
+        let mut council = storage::get_admin_council(&env);
+        council.remove_member(&member)?;
+        storage::set_admin_council(&env, &council);
+
+        EVENTS.member_removed(&env, &member, council.members.len());
+
+        Ok(())
+    }
+
+    /// Swap a council member for a new address, e.g. after a key loss or compromise.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::ReplaceMember`).
+    ///
+    /// # Errors
+    /// - `ContractError::NotCouncilMember` if `old_member` is not on the council.
+    /// - `ContractError::InvalidCouncilConfig` if `new_member` is already on the council.
+    fn replace_member(
+        env: Env,
+        old_member: Address,
+        new_member: Address,
+    ) -> Result<(), ContractError> {
+        let mut council = storage::get_admin_council(&env);
+        council.replace_member(&old_member, &new_member)?;
+        storage::set_admin_council(&env, &council);
+
+        EVENTS.member_replaced(&env, &old_member, &new_member);
+
+        Ok(())
+    }
+
+    /// Change the number of approvals required to execute a council proposal.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::SetThreshold`).
+    ///
+    /// # Errors
+    /// - `ContractError::InvalidCouncilConfig` if `threshold` is zero or exceeds the member count.
+    fn set_threshold(env: Env, threshold: u32) -> Result<(), ContractError> {
+        let mut council = storage::get_admin_council(&env);
+        council.set_threshold(threshold)?;
+        storage::set_admin_council(&env, &council);
+
+        EVENTS.threshold_changed(&env, threshold);
+
+        Ok(())
+    }
+
+    /// Replace the contract's WASM with a previously uploaded build.
+    ///
+    /// The new code takes effect once this invocation completes; `migrate` must
+    /// then be called to bring storage up to the new build's `SCHEMA_VERSION`.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::Upgrade`).
+    fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), ContractError> {
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        EVENTS.upgraded(&env, &new_wasm_hash, storage::get_schema_version(&env));
+
+        Ok(())
+    }
+
+    /// Immediately halt one or more entrypoints in an emergency.
+    ///
+    /// Any single council member may pause, so a halt does not wait for a
+    /// proposal to collect approvals. Lifting a pause requires an executed
+    /// `ProposalAction::Unpause`. Entrypoints that are not listed keep working,
+    /// so users can still exit while the affected path is halted.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `member`: Council member triggering the pause. Must authorize this call.
+    /// - `functions`: Entrypoints to pause.
+    ///
+    /// # Errors
+    /// - `ContractError::NotCouncilMember` if `member` is not on the admin council.
+    pub fn pause(
+        env: Env,
+        member: Address,
+        functions: Vec<PausableFn>,
+    ) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        member.require_auth();
+        storage::get_admin_council(&env).require_member(&member)?;
+
+        for function in functions.iter() {
+            storage::set_paused(&env, &function, true);
+        }
+
+        EVENTS.paused(&env, &member, functions);
+
+        Ok(())
+    }
+
+    /// Lift an emergency pause on the listed entrypoints.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::Unpause`).
+    fn unpause(env: Env, functions: Vec<PausableFn>) -> Result<(), ContractError> {
+        for function in functions.iter() {
+            storage::set_paused(&env, &function, false);
+        }
+
+        EVENTS.unpaused(&env, functions);
+
+        Ok(())
+    }
+
+    /// Drop a queued timelocked action before it is executed.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::CancelQueued`).
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    /// - `ContractError::ProposalNotQueued` if the proposal is not waiting in the queue.
+    fn cancel_queued(env: Env, proposal_id: u64) -> Result<(), ContractError> {
+        let mut proposal =
+            storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+        if proposal.status != ProposalStatus::Queued {
+            return Err(ContractError::ProposalNotQueued);
+        }
+
+        proposal.status = ProposalStatus::Cancelled;
+        storage::set_proposal(&env, &proposal);
+
+        EVENTS.queued_cancelled(&env, proposal_id);
+
+        Ok(())
+    }
+
+    /// Grant `role` to `account`.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::GrantRole`).
+    ///
+    /// # Errors
+    /// - `ContractError::RoleAlreadyGranted` if `account` already holds the role.
+    fn grant_role(env: Env, role: Role, account: Address) -> Result<(), ContractError> {
+        let mut holders = storage::get_role_holders(&env, &role);
+        roles::grant_role(&mut holders, &account)?;
+        storage::set_role_holders(&env, &role, &holders);
+
+        EVENTS.role_granted(&env, role, &account);
+
+        Ok(())
+    }
+
+    /// Revoke `role` from `account`.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::RevokeRole`).
+    ///
+    /// # Errors
+    /// - `ContractError::RoleNotHeld` if `account` does not hold the role.
+    fn revoke_role(env: Env, role: Role, account: Address) -> Result<(), ContractError> {
+        let mut holders = storage::get_role_holders(&env, &role);
+        roles::revoke_role(&mut holders, &account)?;
+        storage::set_role_holders(&env, &role, &holders);
+
+        EVENTS.role_revoked(&env, role, &account);
+
+        Ok(())
+    }
+
+    /// Replace the guardian set that may veto council proposals.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::SetGuardians`),
+    /// which is timelocked so the outgoing guardians can veto their own removal.
+    ///
+    /// # Errors
+    /// - `ContractError::InvalidGuardians` if the set has duplicates or more than `MAX_GUARDIANS` addresses.
+    fn set_guardians(env: Env, guardians: Vec<Address>) -> Result<(), ContractError> {
+        guardians::validate_guardians(&guardians)?;
+        storage::set_guardians(&env, &guardians);
+
+        EVENTS.guardians_set(&env, &guardians);
+
+        Ok(())
+    }
+
+    /// Switch staking to another SAC token.
+    ///
+    /// Only allowed once no stake is held in the current token, i.e. after every
+    /// node and delegator has unstaked and finalized, so no stake or pending
+    /// unstake is left denominated in the old token. Nodes then stake again in
+    /// `token`. Held stake is the registry's own `TotalStaked` count, so tokens
+    /// sent to the registry directly cannot block the switch.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::SetStakeToken`).
+    ///
+    /// # Errors
+    /// - `ContractError::StakeOutstanding` if stake is still held in the current token.
+    fn set_stake_token(env: Env, token: Address) -> Result<(), ContractError> {
+        if storage::get_total_staked(&env) != 0 {
+            return Err(ContractError::StakeOutstanding);
+        }
+        let old_token = storage::get_token_address(&env);
+
+        storage::set_token_address(&env, &token);
+
+        EVENTS.publish(&env, "set_stake_token", (old_token, token));
+
+        Ok(())
+    }
+
+    /// Set the basis points slashed for `reason_code`.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::SetSlashTier`).
+    ///
+    /// # Errors
+    /// - `ContractError::InvalidSlashBps` if `bps` is zero or greater than 10000.
+    fn set_slash_tier(env: Env, reason_code: u32, bps: u32) -> Result<(), ContractError> {
+        if bps == 0 || bps > storage::MAX_BPS {
+            return Err(ContractError::InvalidSlashBps);
+        }
+
+        storage::set_slash_tier(&env, reason_code, bps);
+
+        EVENTS.publish(&env, "set_slash_tier", (reason_code, bps));
+
+        Ok(())
+    }
+
+    /// Set the treasury contract that receives slashed stake.
+    ///
+    /// The treasury must account in the registry's staking token, since its
+    /// `deposit` pulls that token from the registry.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::SetTreasury`).
+    fn set_treasury(env: Env, treasury: Address) -> Result<(), ContractError> {
+        storage::set_treasury(&env, &treasury);
+
+        EVENTS.publish(&env, "set_treasury", (treasury,));
+
+        Ok(())
+    }
+
+    /// Set the dispute resolver that `begin_exit` and `complete_exit` consult,
+    /// so a node cannot leave while it is party to an unresolved dispute.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::SetDisputeResolver`).
+    fn set_dispute_resolver(env: Env, dispute_resolver: Address) -> Result<(), ContractError> {
+        storage::set_dispute_resolver(&env, &dispute_resolver);
+
+        EVENTS.publish(&env, "set_dispute_resolver", (dispute_resolver,));
+
+        Ok(())
+    }
+
+    /// Switch lock periods to `unit`, with `stake_lock_period` restated in it.
+    ///
+    /// Only unbondings started afterwards use the new unit; pending ones carry
+    /// the unit they were created in and unlock on that clock.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::SetLockUnit`).
+    ///
+    /// # Errors
+    /// - `ContractError::InvalidAmount` if `stake_lock_period` is zero.
+    fn set_lock_unit(
+        env: Env,
+        unit: LockUnit,
+        stake_lock_period: u32,
+    ) -> Result<(), ContractError> {
+        if stake_lock_period == 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        storage::set_lock_unit(&env, unit);
+        storage::set_stake_lock_period(&env, stake_lock_period);
+
+        EVENTS.publish(&env, "set_lock_unit", (unit, stake_lock_period));
+
+        Ok(())
+    }
+
+    /// Change the minimum stake nodes need to be `Active`.
+    ///
+    /// Raising it starts a `MIN_STAKE_GRACE_PERIOD` during which `Active` nodes
+    /// below the new minimum keep their status, so they can top up before
+    /// `sweep_stake_status` demotes them. Nodes activating through `stake` or
+    /// `delegate` need the new minimum straight away. Lowering it lets
+    /// `sweep_stake_status` reactivate `Inactive` nodes that now meet it.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::SetMinStake`).
+    ///
+    /// # Errors
+    /// - `ContractError::InvalidAmount` if `min_stake` is zero or negative.
+    fn set_min_stake(env: Env, min_stake: i128) -> Result<(), ContractError> {
+        if min_stake <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let old_min_stake = storage::get_min_stake(&env);
+        if min_stake > old_min_stake {
+            let grace_until = env
+                .ledger()
+                .timestamp()
+                .checked_add(storage::MIN_STAKE_GRACE_PERIOD)
+                .ok_or(ContractError::Overflow)?;
+            storage::set_min_stake_grace_until(&env, grace_until);
+        }
+        storage::set_min_stake(&env, min_stake);
+
+        EVENTS.publish(
+            &env,
+            "set_min_stake",
+            (
+                old_min_stake,
+                min_stake,
+                storage::get_min_stake_grace_until(&env),
+            ),
+        );
+
+        Ok(())
+    }
+
+    /// Change the lock period, in the current `LockUnit`. Unbondings already
+    /// queued keep the `unlocks_at` they were created with.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::SetStakeLockPeriod`).
+    ///
+    /// # Errors
+    /// - `ContractError::InvalidAmount` if `stake_lock_period` is zero.
+    fn set_stake_lock_period(env: Env, stake_lock_period: u32) -> Result<(), ContractError> {
+        if stake_lock_period == 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let old_period = storage::get_stake_lock_period(&env);
+        storage::set_stake_lock_period(&env, stake_lock_period);
+
+        EVENTS.publish(
+            &env,
+            "set_stake_lock_period",
+            (old_period, stake_lock_period),
+        );
+
+        Ok(())
+    }
+
+    /// Basis points slashed for `reason_code`, if a tier is configured.
+    pub fn get_slash_tier(env: Env, reason_code: u32) -> Option<u32> {
+        storage::extend_instance_ttl(&env);
+        storage::get_slash_tier(&env, reason_code)
+    }
+
+    /// Addresses currently holding `role`.
+    pub fn get_role_holders(env: Env, role: Role) -> Vec<Address> {
+        storage::extend_instance_ttl(&env);
+        storage::get_role_holders(&env, &role)
+    }
+
+    /// Whether `account` currently holds `role`.
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        storage::extend_instance_ttl(&env);
+        storage::has_role(&env, &role, &account)
+    }
+
+    /// Addresses that may veto council proposals.
+    pub fn get_guardians(env: Env) -> Vec<Address> {
+        storage::extend_instance_ttl(&env);
+        storage::get_guardians(&env)
+    }
+
+    /// The guardian veto recorded for `proposal_id`, if it was vetoed.
+    pub fn get_veto(env: Env, proposal_id: u64) -> Option<VetoRecord> {
+        storage::extend_instance_ttl(&env);
+        storage::get_veto(&env, proposal_id)
+    }
+
+    /// Pending unbondings of a node, oldest first, each with its own `unlocks_at`.
+    pub fn get_unbondings(env: Env, address: Address) -> Vec<StakeEntry> {
+        storage::extend_instance_ttl(&env);
+        storage::get_unbondings(&env, &address)
+    }
+
+    /// When each pending unbonding of a node unlocks, in ledgers and seconds, in
+    /// the same order as `get_unbondings`.
+    ///
+    /// The unit the registry locks in is exact; the other is an estimate at
+    /// `SECONDS_PER_LEDGER` seconds per ledger from the current ledger.
+    pub fn get_unlock_etas(env: Env, address: Address) -> Vec<UnlockEta> {
+        storage::extend_instance_ttl(&env);
+        unlock_etas(&env, &storage::get_unbondings(&env, &address))
+    }
+
+    /// A page of registered nodes, in registration order, optionally only those
+    /// in `status` and `region`.
+    ///
+    /// Scans at most `limit` index positions (capped at `MAX_NODE_PAGE_SIZE`)
+    /// starting at `cursor`, so a filtered page can hold fewer nodes than
+    /// `limit`, or none, while `next_cursor` is still set. Start with cursor 0
+    /// and continue until `next_cursor` is `None`.
+    pub fn list_nodes(
+        env: Env,
+        cursor: u32,
+        limit: u32,
+        status: Option<NodeStatus>,
+        region: Option<String>,
+    ) -> NodePage {
+        storage::extend_instance_ttl(&env);
+        node_page(&env, cursor, limit, |node| {
+            status.as_ref().is_none_or(|status| *status == node.status)
+                && region
+                    .as_ref()
+                    .is_none_or(|region| *region == node.metadata.region)
+        })
+    }
+
+    /// A page of registered nodes that support `transport`, in registration
+    /// order, optionally only those in `status`.
+    ///
+    /// Pages like `list_nodes`: at most `limit` index positions are scanned
+    /// from `cursor`, so a page can be empty while `next_cursor` is still set.
+    pub fn list_nodes_by_transport(
+        env: Env,
+        cursor: u32,
+        limit: u32,
+        transport: Transport,
+        status: Option<NodeStatus>,
+    ) -> NodePage {
+        storage::extend_instance_ttl(&env);
+        node_page(&env, cursor, limit, |node| {
+            node.metadata.transports.contains(transport)
+                && status.as_ref().is_none_or(|status| *status == node.status)
+        })
+    }
+
+    /// Bring a page of nodes in line with the current minimum stake.
+    ///
+    /// Permissionless. Scans at most `limit` index positions (capped at
+    /// `MAX_NODE_PAGE_SIZE`) starting at `cursor`, in the same order as
+    /// `list_nodes`. An `Active` node whose bonded stake (own plus delegated)
+    /// is below the minimum moves to `Inactive`, but only once the grace period
+    /// after the last raise has ended. An `Inactive` node that meets the minimum
+    /// moves back to `Active`. `Slashed`, `Jailed` and `Exiting` nodes are left alone.
+    ///
+    /// Returns the cursor for the next page, or `None` once the index is exhausted.
+    ///
+    /// # Errors
+    /// - `ContractError::Overflow` if a node's bonded stake overflows.
+    pub fn sweep_stake_status(
+        env: Env,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<Option<u32>, ContractError> {
+        storage::extend_instance_ttl(&env);
+        let min_stake = storage::get_min_stake(&env);
+        let in_grace = storage::get_min_stake_grace_until(&env)
+            .is_some_and(|grace_until| env.ledger().timestamp() < grace_until);
+
+        let indexed = storage::get_indexed_node_count(&env);
+        let end = cursor
+            .saturating_add(limit.min(storage::MAX_NODE_PAGE_SIZE))
+            .min(indexed);
+        for position in cursor..end {
+            let Some(mut node) = storage::get_indexed_node(&env, position)
+                .and_then(|address| storage::get_node(&env, &address))
+            else {
+                continue;
+            };
+            let bonded = bonded_stake(&env, &node.address, node.stake)?;
+            let event = match node.status {
+                NodeStatus::Active if bonded < min_stake && !in_grace => {
+                    node.status = NodeStatus::Inactive;
+                    "stake_demoted"
+                }
+                NodeStatus::Inactive if bonded >= min_stake => {
+                    node.status = NodeStatus::Active;
+                    "stake_reactivated"
+                }
+                _ => continue,
+            };
+            storage::set_node(&env, &node.address, &node);
+            EVENTS.publish(&env, event, (node.address, bonded, min_stake));
+        }
+
+        Ok((end < indexed).then_some(end))
+    }
+
+    /// Number of indexed nodes in `status`, optionally only those in `region`.
+    pub fn count_nodes(env: Env, status: NodeStatus, region: Option<String>) -> u32 {
+        storage::extend_instance_ttl(&env);
+        match region {
+            Some(region) => storage::get_region_status_count(&env, &region, &status),
+            None => storage::get_status_count(&env, &status),
+        }
+    }
+
+    /// Current registry configuration: minimum stake, lock period, staking token, council and treasury.
+    pub fn get_config(env: Env) -> RegistryConfig {
+        storage::extend_instance_ttl(&env);
+        RegistryConfig {
+            min_stake: storage::get_min_stake(&env),
+            stake_lock_period: storage::get_stake_lock_period(&env),
+            lock_unit: storage::get_lock_unit(&env),
+            min_stake_grace_until: storage::get_min_stake_grace_until(&env),
+            token: storage::get_token_address(&env),
+            council: storage::get_admin_council(&env),
+            treasury: storage::get_treasury(&env),
+            dispute_resolver: storage::get_dispute_resolver(&env),
+        }
+    }
+
+    /// A page of the action log, oldest first, starting at entry `start_id`.
+    ///
+    /// Returns at most `limit` entries, capped at `MAX_LOG_PAGE_SIZE`; an empty
+    /// page means `start_id` is past the end of the log.
+    pub fn get_action_log(env: Env, start_id: u64, limit: u32) -> Vec<ActionLogEntry> {
+        storage::extend_instance_ttl(&env);
+        let mut page = Vec::new(&env);
+        for id in log_page(start_id, limit, storage::get_action_log_count(&env)) {
+            if let Some(entry) = storage::get_action_log_entry(&env, id) {
+                page.push_back(entry);
+            }
+        }
+        page
+    }
+
+    /// Number of entries in the action log.
+    pub fn get_action_log_count(env: Env) -> u64 {
+        storage::extend_instance_ttl(&env);
+        storage::get_action_log_count(&env)
+    }
+
+    /// Whether `function` is currently paused. Intended for off-chain relays to poll.
+    pub fn is_paused(env: Env, function: PausableFn) -> bool {
+        storage::extend_instance_ttl(&env);
+        storage::is_paused(&env, &function)
+    }
+
+    /// Open a council proposal for a privileged action.
+    ///
+    /// The proposer's approval is recorded immediately, so with a threshold of 1
+    /// the proposal can be executed right away. Holders of the role covering
+    /// `action` (see `ProposalAction::required_role`) may also propose it; such a
+    /// proposal needs no further approvals.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `proposer`: Council member or role holder creating the proposal. Must authorize this call.
+    /// - `action`: The privileged action to apply once the proposal is approved.
+    ///
+    /// # Returns
+    /// The newly assigned `proposal_id`.
+    ///
+    /// # Errors
+    /// - `ContractError::NotCouncilMember` if `proposer` is neither on the admin council
+    ///   nor holds the role covering `action`.
+    pub fn propose(
+        env: Env,
+        proposer: Address,
+        action: ProposalAction,
+    ) -> Result<u64, ContractError> {
+        storage::extend_instance_ttl(&env);
+        proposer.require_auth();
+        if !has_role_for(&env, &action, &proposer) {
+            storage::get_admin_council(&env).require_member(&proposer)?;
+        }
+
+        let (created_at, expires_at) = proposal_window(&env);
+        let proposal = Proposal {
+            id: storage::next_proposal_id(&env),
+            proposer: proposer.clone(),
+            action,
+            approvals: Vec::from_array(&env, [proposer.clone()]),
+            status: ProposalStatus::Pending,
+            created_at,
+            expires_at,
+        };
+        storage::set_proposal(&env, &proposal);
+
+        EVENTS.proposal_created(&env, &proposer, proposal.id, proposal.action);
+
+        Ok(proposal.id)
+    }
+
+    /// Approve a pending council proposal.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `approver`: Council member approving the proposal. Must authorize this call.
+    /// - `proposal_id`: ID of the proposal to approve.
+    ///
+    /// # Errors
+    /// - `ContractError::NotCouncilMember` if `approver` is not on the admin council.
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    /// - `ContractError::ProposalNotPending` if the proposal was executed or cancelled.
+    /// - `ContractError::ProposalExpired` if the approval window has passed.
+    /// - `ContractError::AlreadyApproved` if `approver` already approved this proposal.
+    pub fn approve(env: Env, approver: Address, proposal_id: u64) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        approver.require_auth();
+        let council = storage::get_admin_council(&env);
+        council.require_member(&approver)?;
+
+        let mut proposal = load_pending_proposal(&env, proposal_id)?;
+        council.record_approval(&mut proposal.approvals, &approver)?;
+        storage::set_proposal(&env, &proposal);
+
+        EVENTS.proposal_approved(&env, &approver, proposal_id, proposal.approvals.len());
+
+        Ok(())
+    }
+
+    /// Apply a council proposal once enough distinct members have approved it.
+    ///
+    /// Anyone may call this; only approvals from addresses that are still on the
+    /// council count towards the threshold. A proposal whose proposer still holds
+    /// the role covering its action skips the threshold check. Timelocked actions (see
+    /// `ProposalAction::is_timelocked`) take two calls: the first queues the
+    /// proposal with an eta `TIMELOCK_DELAY` ledgers out, and a call at or after
+    /// the eta applies it.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `proposal_id`: ID of the proposal to execute.
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    /// - `ContractError::ProposalNotPending` if the proposal was executed or cancelled.
+    /// - `ContractError::ProposalExpired` if the approval window has passed.
+    /// - `ContractError::InsufficientApprovals` if the council threshold is not met and
+    ///   the proposer does not hold the role covering the action.
+    /// - `ContractError::TimelockActive` if the proposal is queued and its eta has not been reached.
+    /// - Any error returned by the underlying action.
+    pub fn execute(env: Env, proposal_id: u64) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        let mut proposal =
+            storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+
+        if proposal.status == ProposalStatus::Queued {
+            let eta = storage::get_proposal_eta(&env, proposal_id)
+                .ok_or(ContractError::ProposalNotQueued)?;
+            if (env.ledger().sequence() as u64) < eta {
+                return Err(ContractError::TimelockActive);
+            }
+        } else {
+            require_open(&env, &proposal.status, proposal.expires_at)?;
+            if !has_role_for(&env, &proposal.action, &proposal.proposer) {
+                storage::get_admin_council(&env).require_threshold(&proposal.approvals)?;
+            }
+
+            if proposal.action.is_timelocked() {
+                let eta = timelock_eta(&env);
+                proposal.status = ProposalStatus::Queued;
+                storage::set_proposal(&env, &proposal);
+                storage::set_proposal_eta(&env, proposal_id, eta);
+
+                EVENTS.proposal_queued(&env, proposal_id, eta);
+
+                return Ok(());
+            }
+        }
+
+        proposal.status = ProposalStatus::Executed;
+        storage::set_proposal(&env, &proposal);
+
+        match proposal.action.clone() {
+            ProposalAction::Slash(node_address, reason_code, reason) => {
+                Self::slash(env.clone(), node_address, reason_code, reason)?
+            }
+            ProposalAction::Jail(node_address, reason) => {
+                Self::jail(env.clone(), node_address, reason)?
+            }
+            ProposalAction::ReinstateNode(node_address) => {
+                Self::reinstate_node(env.clone(), node_address)?
+            }
+            ProposalAction::AddMember(member) => Self::add_member(env.clone(), member)?,
+            ProposalAction::RemoveMember(member) => Self::remove_member(env.clone(), member)?,
+            ProposalAction::ReplaceMember(old_member, new_member) => {
+                Self::replace_member(env.clone(), old_member, new_member)?
+            }
+            ProposalAction::SetThreshold(threshold) => Self::set_threshold(env.clone(), threshold)?,
+            ProposalAction::Upgrade(new_wasm_hash) => Self::upgrade(env.clone(), new_wasm_hash)?,
+            ProposalAction::Unpause(functions) => Self::unpause(env.clone(), functions)?,
+            ProposalAction::CancelQueued(queued_id) => Self::cancel_queued(env.clone(), queued_id)?,
+            ProposalAction::GrantRole(role, account) => {
+                Self::grant_role(env.clone(), role, account)?
+            }
+            ProposalAction::RevokeRole(role, account) => {
+                Self::revoke_role(env.clone(), role, account)?
+            }
+            ProposalAction::SetGuardians(guardians) => Self::set_guardians(env.clone(), guardians)?,
+            ProposalAction::SetStakeToken(token) => Self::set_stake_token(env.clone(), token)?,
+            ProposalAction::SetSlashTier(reason_code, bps) => {
+                Self::set_slash_tier(env.clone(), reason_code, bps)?
+            }
+            ProposalAction::SetTreasury(treasury) => Self::set_treasury(env.clone(), treasury)?,
+            ProposalAction::SetDisputeResolver(dispute_resolver) => {
+                Self::set_dispute_resolver(env.clone(), dispute_resolver)?
+            }
+            ProposalAction::SetLockUnit(unit, stake_lock_period) => {
+                Self::set_lock_unit(env.clone(), unit, stake_lock_period)?
+            }
+            ProposalAction::SetMinStake(min_stake) => Self::set_min_stake(env.clone(), min_stake)?,
+            ProposalAction::SetStakeLockPeriod(stake_lock_period) => {
+                Self::set_stake_lock_period(env.clone(), stake_lock_period)?
+            }
+        }
+
+        log_action(&env, proposal_id, proposal.action, proposal.approvals);
+
+        EVENTS.proposal_executed(&env, proposal_id);
+
+        Ok(())
+    }
+
+    /// Veto a council proposal before its action is applied.
+    ///
+    /// Guardians cannot propose or approve; they can only stop a proposal while it
+    /// is pending and unexpired, or while it is queued and its eta has not been
+    /// reached. The proposal is marked `Vetoed` and can no longer be executed.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `guardian`: Guardian vetoing the proposal. Must authorize this call.
+    /// - `proposal_id`: ID of the proposal to veto.
+    /// - `reason`: Why the proposal is vetoed; stored with the veto and emitted as an event.
+    ///
+    /// # Errors
+    /// - `ContractError::NotGuardian` if `guardian` is not in the guardian set.
+    /// - `ContractError::InvalidVetoReason` if `reason` is empty or longer than `MAX_VETO_REASON_LEN`.
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    /// - `ContractError::VetoWindowClosed` if the proposal can no longer be vetoed.
+    pub fn veto(
+        env: Env,
+        guardian: Address,
+        proposal_id: u64,
+        reason: String,
+    ) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        guardian.require_auth();
+        guardians::require_guardian(&storage::get_guardians(&env), &guardian)?;
+        guardians::validate_reason(&reason)?;
+
+        let mut proposal =
+            storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+        guardians::require_vetoable(
+            &env,
+            &proposal.status,
+            proposal.expires_at,
+            storage::get_proposal_eta(&env, proposal_id),
+        )?;
+
+        proposal.status = ProposalStatus::Vetoed;
+        storage::set_proposal(&env, &proposal);
+        storage::set_veto(
+            &env,
+            proposal_id,
+            &VetoRecord {
+                guardian: guardian.clone(),
+                reason: reason.clone(),
+                vetoed_at: env.ledger().sequence() as u64,
+            },
+        );
+
+        EVENTS.proposal_vetoed(&env, &guardian, proposal_id, &reason);
+
+        Ok(())
+    }
+
+    /// Withdraw a pending proposal. Only the original proposer may cancel it.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `proposer`: The address that created the proposal. Must authorize this call.
+    /// - `proposal_id`: ID of the proposal to cancel.
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    /// - `ContractError::ProposalNotPending` if the proposal was executed or cancelled.
+    /// - `ContractError::Unauthorized` if `proposer` did not create the proposal.
+    pub fn cancel(env: Env, proposer: Address, proposal_id: u64) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        proposer.require_auth();
+
+        let mut proposal =
+            storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+        if proposal.status != ProposalStatus::Pending {
+            return Err(ContractError::ProposalNotPending);
+        }
+        if proposal.proposer != proposer {
+            return Err(ContractError::Unauthorized);
+        }
+
+        proposal.status = ProposalStatus::Cancelled;
+        storage::set_proposal(&env, &proposal);
+
+        EVENTS.proposal_cancelled(&env, &proposer, proposal_id);
+
+        Ok(())
+    }
+
+    /// Fetch a council proposal by its ID.
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Result<Proposal, ContractError> {
+        storage::extend_instance_ttl(&env);
+        storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)
+    }
+
+    /// Earliest ledger sequence at which a queued proposal can be executed.
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotQueued` if the proposal was never queued.
+    pub fn get_proposal_eta(env: Env, proposal_id: u64) -> Result<u64, ContractError> {
+        storage::extend_instance_ttl(&env);
+        storage::get_proposal_eta(&env, proposal_id).ok_or(ContractError::ProposalNotQueued)
+    }
+
+    /// Bring stored data up to this build's `SCHEMA_VERSION` after an upgrade.
+    ///
+    /// Permissionless and idempotent: each step rewrites one schema version into
+    /// the next, and calling it on an up-to-date contract changes nothing.
+    ///
+    /// # Returns
+    /// The schema version after migration.
+    ///
+    /// # Errors
+    /// - `ContractError::UnsupportedSchemaVersion` if storage was written by a newer build.
+    pub fn migrate(env: Env) -> Result<u32, ContractError> {
+        storage::extend_instance_ttl(&env);
+        let from = storage::get_schema_version(&env);
+        if from > storage::SCHEMA_VERSION {
+            return Err(ContractError::UnsupportedSchemaVersion);
+        }
+
+        let mut version = from;
+        while version < storage::SCHEMA_VERSION {
+            migrate_step(&env, version)?;
+            version += 1;
+        }
+
+        if version != from {
+            storage::set_schema_version(&env, version);
+            EVENTS.migrated(&env, from, version);
+        }
+
+        Ok(version)
+    }
+
+    /// Storage schema version currently recorded by the contract.
+    pub fn version(env: Env) -> u32 {
+        storage::extend_instance_ttl(&env);
+        storage::get_schema_version(&env)
+    }
+
+    /// Retrieves a registered relay node's details.
+    ///
+    /// This is a view-only function that returns the `RelayNode` struct
+    /// associated with the given address. It does not require authorization.
+    /// An address a node moved away from through `accept_transfer` resolves to
+    /// the node at its new address.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `address`: The Stellar account address of the relay node to lookup.
+    ///
+    /// # Returns
+    /// - `Ok(RelayNode)`: The registered node details if found.
+    ///
+    /// # Errors
+    /// - `ContractError::NotRegistered`: If the address is not registered in the registry.
+    pub fn get_node(env: Env, address: Address) -> Result<RelayNode, ContractError> {
+        storage::extend_instance_ttl(&env);
+        let address = resolve_address(&env, address);
+        storage::get_node(&env, &address).ok_or(ContractError::NotRegistered)
+    }
+
+    /// Checks if a relay node is currently active.
+    ///
+    /// This is a view-only function that returns true if the given address is
+    /// a registered relay node with a status of `NodeStatus::Active`. It does not
+    /// require authorization. This function never errors; it returns false for any unknown or inactive address.
+    /// Like `get_node`, it follows the forwarding record of a transferred node.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `address`: The Stellar account address of the relay node to check.
+    ///
+    /// # Returns
     /// - `true`: If the node exists and its status is `NodeStatus::Active`.
     /// - `false`: If the node is not registered, or its status is not active.
     pub fn is_active(env: Env, address: Address) -> bool {
         storage::extend_instance_ttl(&env);
+        let address = resolve_address(&env, address);
         matches!(
             storage::get_node(&env, &address).map(|n| n.status),
             Some(NodeStatus::Active)
         )
     }
+
+    /// Returns the amount currently staked by a relay node.
+    ///
+    /// This is a view-only function used by the governance contract to weigh
+    /// votes. It never errors; unregistered addresses have a stake of 0.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `address`: The Stellar account address of the relay node.
+    pub fn get_stake(env: Env, address: Address) -> i128 {
+        storage::extend_instance_ttl(&env);
+        storage::get_node(&env, &address).map_or(0, |n| n.stake)
+    }
+
+    /// Returns the stake bonded to `address` — its own plus delegated — as it
+    /// stood at the end of ledger `ledger`.
+    ///
+    /// Governance snapshots voting power with this view, so stake moved in
+    /// after a proposal opened does not count toward it. Unregistered
+    /// addresses have a bonded stake of 0.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `address`: The Stellar account address of the relay node.
+    /// - `ledger`: Ledger sequence to read the stake at.
+    pub fn get_bonded_stake_at(env: Env, address: Address, ledger: u32) -> i128 {
+        storage::extend_instance_ttl(&env);
+        storage::get_bonded_stake_at(&env, &address, ledger)
+    }
 }
@@ -4,11 +4,19 @@
 //! storage using Soroban's `Env::storage()` API.
 //!
 //! ## Storage keys to implement
-//! - `DataKey::RelayNode(Address)` — Stores a `RelayNode` struct keyed by address
+//! - `DataKey::NodeRecord(Address)` — Stores a `RelayNode` struct keyed by address
+//! - `DataKey::RelayNode(Address)` — A node last written before schema version 4, in the old layout
 //! - `DataKey::NodeCount` — Tracks total number of registered relay nodes
-//! - `DataKey::MinStake` — Minimum required stake amount (set at initialization)
-//! - `DataKey::StakeLockPeriod` — Number of ledgers a node must wait before unstaking
+//! - `DataKey::MinStake` — Minimum required stake amount (set at initialization, changed by the council)
+//! - `DataKey::MinStakeGraceUntil` — End of the grace period after the minimum stake was raised
+//! - `DataKey::StakeLockPeriod` — Ledgers or seconds, per `DataKey::LockUnit`, a node must wait before unstaking
+//! - `DataKey::LockUnit` — Clock lock periods are measured on; absent means seconds
+//! - `DataKey::LegacyLockUnit` — Clock of unbondings queued before schema version 6
+//! - `DataKey::TotalStaked` — Staking tokens the registry holds for nodes and delegators
 //! - `DataKey::Admin` — Address authorized to slash nodes and update config
+//! - `DataKey::Proposal(u64)` — Stores a council `Proposal` keyed by proposal_id
+//! - `DataKey::ProposalCount` — Monotonically incrementing proposal ID counter
+//! - `DataKey::StakeCheckpoint(Address, u32)` — Bonded stake history, read by governance
 //!
 //! ## Functions to implement
 //! - `get_node(env, address) -> Option<RelayNode>` — Load a relay node from storage
@@ -16,63 +24,451 @@
 //! - `remove_node(env, address)` — Remove a relay node from storage
 //! - `get_node_count(env) -> u32` — Get the total number of registered nodes
 //! - `increment_node_count(env)` — Increment the node count by 1
+//! - `decrement_node_count(env)` — Decrement the node count by 1 when a node exits
 //! - `get_min_stake(env) -> i128` — Load the minimum stake requirement
-//! - `get_stake_lock_period(env) -> u32` — Load the stake lock period in ledgers
+//! - `get_stake_lock_period(env) -> u32` — Load the stake lock period, in the lock unit
+//! - `get_lock_unit(env) -> LockUnit` — Load whether lock periods count ledgers or seconds
 //!
 //! implementation tracked in GitHub issue
 
 #![allow(unused)]
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
 
-use crate::types::RelayNode;
+use crate::types::{
+    ActionLogEntry, JailRecord, LivenessFault, LockUnit, NodeMetadata, NodeStatus, PausableFn,
+    Proposal, RelayNode, ReputationRecord, Role, SigningKeyRecord, StakeCheckpoint, StakeEntry,
+    UptimeRecord, VetoRecord,
+};
 
-// Bump by ~30 days (assuming ~5 seconds per ledger)
-const LEDGER_BUMP_AMOUNT: u32 = 518_400;
-// Bump if remaining life is less than ~15 days
-const LEDGER_BUMP_THRESHOLD: u32 = 259_200;
+pub use conduit_common::ttl::extend_instance_ttl;
+use conduit_common::ttl::extend_persistent_ttl;
 
-pub fn extend_instance_ttl(env: &Env) {
-    env.storage()
-        .instance()
-        .extend_ttl(LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
-}
+/// Storage layout version written by this build. Bump it together with a new
+/// step in `migrate_step` whenever a persisted layout changes.
+///
+/// - 1: one pending unstake per node under `LockEntry(Address)`.
+/// - 2: a queue of pending unbondings per node under `Unbonding(Address)`.
+/// - 3: registered nodes are indexed under `NodeIndex(u32)` and counted per
+///   status and region.
+/// - 4: node records carry the extended `NodeMetadata` and are stored under
+///   `NodeRecord(Address)`.
+/// - 5: the staking tokens the registry holds are tracked under `TotalStaked`.
+/// - 6: each unbonding records its `LockUnit`; queues are stored under
+///   `UnbondingQueue(Address)` and `DelegatorUnbondingQueue(Address, Address)`.
+pub const SCHEMA_VERSION: u32 = 6;
+
+/// Expected seconds between ledgers, used to estimate unlock times in the unit
+/// the registry does not lock in.
+pub const SECONDS_PER_LEDGER: u64 = 5;
+
+/// Seconds `Active` nodes below a raised minimum stake keep their status before
+/// `sweep_stake_status` may demote them (one week).
+pub const MIN_STAKE_GRACE_PERIOD: u64 = 604_800;
+
+/// Seconds in one heartbeat slot; a node is live in a slot if it sends a heartbeat during it.
+pub const HEARTBEAT_INTERVAL: u64 = 3_600;
+
+/// Heartbeat slots per uptime epoch (one day).
+pub const SLOTS_PER_EPOCH: u64 = 24;
+
+/// Share of a node's reputation score that decays away each uptime epoch, in basis points.
+pub const REPUTATION_DECAY_BPS: u32 = 1_000;
+
+/// Reputation gained for each settled batch.
+pub const BATCH_REPUTATION: i64 = 10;
+
+/// Reputation gained for an epoch that met the node's uptime commitment.
+pub const LIVENESS_REPUTATION: i64 = 20;
+
+/// Reputation lost for an epoch that fell short of the node's uptime commitment.
+pub const LIVENESS_FAULT_REPUTATION: i64 = -100;
+
+/// How long a jailed node stays out of the relay set, in seconds (one day).
+pub const JAIL_PERIOD: u64 = 86_400;
+
+/// Share of a node's own stake forfeited each time it is jailed, in basis points.
+pub const JAIL_PENALTY_BPS: u32 = 100;
+
+/// Jailings within `JAIL_RECORD_WINDOW` of each other at which the latest also
+/// queues a timelocked full slash.
+pub const JAILS_BEFORE_SLASH: u32 = 3;
+
+/// Time after a jailing for which it counts towards `JAILS_BEFORE_SLASH`, in
+/// seconds (30 days).
+pub const JAIL_RECORD_WINDOW: u64 = 2_592_000;
+
+/// Reputation gained for a won dispute.
+pub const DISPUTE_WIN_REPUTATION: i64 = 50;
+
+/// Reputation lost for a lost dispute.
+pub const DISPUTE_LOSS_REPUTATION: i64 = -200;
+
+/// Reputation lost to a full slash; a partial slash loses its share of it.
+pub const SLASH_REPUTATION: i64 = -1_000;
+
+/// Number of nodes kept on the reputation board behind `top_nodes`.
+pub const TOP_NODES_CAPACITY: u32 = 20;
+
+/// Maximum length of `NodeMetadata::region`, in bytes.
+pub const MAX_REGION_LEN: u32 = 32;
+
+/// Maximum length of `NodeMetadata::endpoint`, in bytes.
+pub const MAX_ENDPOINT_LEN: u32 = 128;
+
+/// Maximum length of `NodeMetadata::software_version`, in bytes.
+pub const MAX_SOFTWARE_VERSION_LEN: u32 = 32;
+
+/// Maximum number of index positions `list_nodes` scans in one call.
+pub const MAX_NODE_PAGE_SIZE: u32 = 50;
+
+/// Maximum number of forwarding records followed when resolving a node that
+/// changed address more than once.
+pub const MAX_FORWARDING_HOPS: u32 = 8;
+
+/// Maximum number of relays `select_relays` returns.
+pub const MAX_RELAY_SELECTION: u32 = 16;
+
+/// Maximum number of index positions `select_relays` reads candidates from in
+/// one call.
+pub const MAX_SELECTION_WINDOW: u32 = 64;
+
+/// Maximum number of pending unbondings a node can have at once.
+pub const MAX_UNBONDING_ENTRIES: u32 = 32;
+
+/// Maximum number of delegators a node can have at once, counting those still unbonding.
+pub const MAX_DELEGATORS: u32 = 32;
+
+/// Basis points in a whole stake.
+pub const MAX_BPS: u32 = 10_000;
+
+/// Reason code seeded at initialization with a tier that slashes the whole stake.
+pub const FULL_SLASH_REASON: u32 = 0;
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     RelayNode(Address),
+    NodeRecord(Address),
     NodeCount,
     MinStake,
+    MinStakeGraceUntil,
     StakeLockPeriod,
+    LockUnit,
+    LegacyLockUnit,
+    TotalStaked,
     AdminCouncil,
     TokenAddress,
     LockEntry(Address),
+    Unbonding(Address),
+    UnbondingQueue(Address),
+    Proposal(u64),
+    ProposalCount,
+    ProposalEta(u64),
+    SchemaVersion,
+    Paused(PausableFn),
+    RoleHolders(Role),
+    Guardians,
+    Veto(u64),
+    ActionLog(u64),
+    ActionLogCount,
+    SlashTier(u32),
+    Treasury,
+    Delegation(Address, Address),
+    Delegators(Address),
+    DelegatedStake(Address),
+    DelegatorUnbonding(Address, Address),
+    DelegatorUnbondingQueue(Address, Address),
+    Commission(Address),
+    NodeIndex(u32),
+    NodePosition(Address),
+    IndexedNodeCount,
+    StatusCount(NodeStatus),
+    RegionStatusCount(String, NodeStatus),
+    Uptime(Address),
+    LivenessFault(Address),
+    Jail(Address),
+    SigningKey(Address, u32),
+    SigningKeyCount(Address),
+    DisputeResolver,
+    Reputation(Address),
+    TopNodes,
+    PendingTransfer(Address),
+    Forwarding(Address),
+    VoteLock(Address),
+    StakeCheckpoint(Address, u32),
+    StakeCheckpointCount(Address),
+}
+
+/// Metadata of a node written before schema version 4.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NodeMetadataV3 {
+    pub region: String,
+    pub capacity: u32,
+    pub uptime_commitment: u32,
+}
+
+/// A node written before schema version 4, still stored under `RelayNode(Address)`.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RelayNodeV3 {
+    pub address: Address,
+    pub stake: i128,
+    pub status: NodeStatus,
+    pub metadata: NodeMetadataV3,
+    pub registered_at: u64,
+    pub last_active: u64,
+}
+
+impl RelayNodeV3 {
+    /// The node in the current layout, with none of the newer metadata set.
+    fn upgrade(self, env: &Env) -> RelayNode {
+        RelayNode {
+            address: self.address,
+            stake: self.stake,
+            status: self.status,
+            metadata: NodeMetadata {
+                region: self.metadata.region,
+                capacity: self.metadata.capacity,
+                uptime_commitment: self.metadata.uptime_commitment,
+                endpoint: None,
+                software_version: None,
+                transports: Vec::new(env),
+                contact_hash: None,
+            },
+            registered_at: self.registered_at,
+            last_active: self.last_active,
+        }
+    }
+}
+
+/// A pending unbonding written before schema version 6, without its unit.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StakeEntryV5 {
+    pub address: Address,
+    pub amount: i128,
+    pub unlocks_at: u64,
+}
+
+impl StakeEntryV5 {
+    /// The entry in the current layout, on the clock legacy entries were queued on.
+    fn upgrade(self, env: &Env) -> StakeEntry {
+        StakeEntry {
+            address: self.address,
+            amount: self.amount,
+            unlocks_at: self.unlocks_at,
+            unit: get_legacy_lock_unit(env),
+        }
+    }
+}
+
+/// Entries of a pre-version 6 unbonding queue stored under `key`, if any.
+fn get_legacy_unbondings(env: &Env, key: &DataKey) -> Option<Vec<StakeEntry>> {
+    let legacy = env
+        .storage()
+        .persistent()
+        .get::<_, Vec<StakeEntryV5>>(key)?;
+    extend_persistent_ttl(env, key);
+    let mut unbondings = Vec::new(env);
+    for entry in legacy.iter() {
+        unbondings.push_back(entry.upgrade(env));
+    }
+    Some(unbondings)
 }
 
+/// A relay node. Nodes last written before schema version 4 are read from
+/// their old layout and move to `NodeRecord(Address)` the next time they are
+/// written (see `set_node`).
 pub fn get_node(env: &Env, address: &Address) -> Option<RelayNode> {
-    let key = DataKey::RelayNode(address.clone());
+    let key = DataKey::NodeRecord(address.clone());
     if let Some(node) = env.storage().persistent().get::<_, RelayNode>(&key) {
-        env.storage()
-            .persistent()
-            .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
-        Some(node)
-    } else {
-        None
+        extend_persistent_ttl(env, &key);
+        return Some(node);
     }
+    let key = DataKey::RelayNode(address.clone());
+    let legacy = env.storage().persistent().get::<_, RelayNodeV3>(&key)?;
+    extend_persistent_ttl(env, &key);
+    Some(legacy.upgrade(env))
 }
 
+/// Persist a relay node, keeping the node index and the status counts in step.
 pub fn set_node(env: &Env, address: &Address, node: &RelayNode) {
-    let key = DataKey::RelayNode(address.clone());
+    let previous = get_node(env, address);
+    let previous_stake = previous.as_ref().map(|old| old.stake);
+
+    // Nodes registered before schema version 3 are indexed, and start being
+    // counted, the first time they are written again.
+    let counted = if get_node_position(env, address).is_some() {
+        previous
+    } else {
+        append_to_index(env, address);
+        None
+    };
+    match counted {
+        Some(old) if old.status == node.status && old.metadata.region == node.metadata.region => {}
+        Some(old) => {
+            uncount_node(env, &old);
+            count_node(env, node);
+        }
+        None => count_node(env, node),
+    }
+
+    remove_node_record(env, address);
+    let key = DataKey::NodeRecord(address.clone());
     env.storage().persistent().set(&key, node);
+    extend_persistent_ttl(env, &key);
+
+    if previous_stake != Some(node.stake) {
+        let previous_bonded = previous_stake.map_or(0, |stake| {
+            stake.saturating_add(get_delegated_stake(env, address))
+        });
+        checkpoint_bonded_stake(env, address, previous_bonded);
+    }
+}
+
+/// Remove a node record in either layout.
+fn remove_node_record(env: &Env, address: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::RelayNode(address.clone()));
     env.storage()
         .persistent()
-        .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+        .remove(&DataKey::NodeRecord(address.clone()));
 }
 
+/// Remove a relay node, leaving an empty slot at its index position.
 pub fn remove_node(env: &Env, address: &Address) {
+    let previous_bonded = get_bonded_stake(env, address);
+    if let Some(position) = get_node_position(env, address) {
+        if let Some(node) = get_node(env, address) {
+            uncount_node(env, &node);
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::NodeIndex(position));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::NodePosition(address.clone()));
+    }
+    remove_node_record(env, address);
+    checkpoint_bonded_stake(env, address, previous_bonded);
+}
+
+/// Move a relay node record to `new`, keeping its index position and, since
+/// status and region are unchanged, the status counts.
+pub fn move_node(env: &Env, old: &Address, new: &Address, node: &RelayNode) {
+    let old_bonded = get_bonded_stake(env, old);
+    remove_node_record(env, old);
+    checkpoint_bonded_stake(env, old, old_bonded);
+    let Some(position) = get_node_position(env, old) else {
+        set_node(env, new, node);
+        return;
+    };
+    let new_bonded = get_bonded_stake(env, new);
     env.storage()
         .persistent()
-        .remove(&DataKey::RelayNode(address.clone()));
+        .remove(&DataKey::NodePosition(old.clone()));
+    let index_key = DataKey::NodeIndex(position);
+    env.storage().persistent().set(&index_key, new);
+    extend_persistent_ttl(env, &index_key);
+    let position_key = DataKey::NodePosition(new.clone());
+    env.storage().persistent().set(&position_key, &position);
+    extend_persistent_ttl(env, &position_key);
+    let key = DataKey::NodeRecord(new.clone());
+    env.storage().persistent().set(&key, node);
+    extend_persistent_ttl(env, &key);
+    checkpoint_bonded_stake(env, new, new_bonded);
+}
+
+/// Number of node index positions handed out, including emptied ones.
+pub fn get_indexed_node_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::IndexedNodeCount)
+        .unwrap_or(0)
+}
+
+/// Address stored at `position` of the node index, unless the slot was emptied.
+pub fn get_indexed_node(env: &Env, position: u32) -> Option<Address> {
+    let key = DataKey::NodeIndex(position);
+    let address = env.storage().persistent().get::<_, Address>(&key);
+    if address.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    address
+}
+
+fn get_node_position(env: &Env, address: &Address) -> Option<u32> {
+    let key = DataKey::NodePosition(address.clone());
+    let position = env.storage().persistent().get::<_, u32>(&key);
+    if position.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    position
+}
+
+fn append_to_index(env: &Env, address: &Address) {
+    let position = get_indexed_node_count(env);
+    let index_key = DataKey::NodeIndex(position);
+    env.storage().persistent().set(&index_key, address);
+    extend_persistent_ttl(env, &index_key);
+    let position_key = DataKey::NodePosition(address.clone());
+    env.storage().persistent().set(&position_key, &position);
+    extend_persistent_ttl(env, &position_key);
+    env.storage().instance().set(
+        &DataKey::IndexedNodeCount,
+        &position.checked_add(1).expect("node index overflow"),
+    );
+}
+
+/// Number of indexed nodes in `status`.
+pub fn get_status_count(env: &Env, status: &NodeStatus) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::StatusCount(status.clone()))
+        .unwrap_or(0)
+}
+
+/// Number of indexed nodes in `status` whose metadata names `region`.
+pub fn get_region_status_count(env: &Env, region: &String, status: &NodeStatus) -> u32 {
+    let key = DataKey::RegionStatusCount(region.clone(), status.clone());
+    let count = env.storage().persistent().get::<_, u32>(&key);
+    if count.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    count.unwrap_or(0)
+}
+
+fn count_node(env: &Env, node: &RelayNode) {
+    let status = &node.status;
+    let region = &node.metadata.region;
+    env.storage().instance().set(
+        &DataKey::StatusCount(status.clone()),
+        &(get_status_count(env, status) + 1),
+    );
+    let key = DataKey::RegionStatusCount(region.clone(), status.clone());
+    env.storage()
+        .persistent()
+        .set(&key, &(get_region_status_count(env, region, status) + 1));
+    extend_persistent_ttl(env, &key);
+}
+
+fn uncount_node(env: &Env, node: &RelayNode) {
+    let status = &node.status;
+    let region = &node.metadata.region;
+    env.storage().instance().set(
+        &DataKey::StatusCount(status.clone()),
+        &get_status_count(env, status).saturating_sub(1),
+    );
+    let key = DataKey::RegionStatusCount(region.clone(), status.clone());
+    match get_region_status_count(env, region, status) {
+        0 | 1 => env.storage().persistent().remove(&key),
+        count => {
+            env.storage().persistent().set(&key, &(count - 1));
+            extend_persistent_ttl(env, &key);
+        }
+    }
 }
 
 pub fn get_node_count(env: &Env) -> u32 {
@@ -93,6 +489,12 @@ pub fn increment_node_count(env: &Env) {
     set_node_count(env, next);
 }
 
+pub fn decrement_node_count(env: &Env) {
+    // Saturates so that a count that drifted below the real number of nodes
+    // cannot underflow.
+    set_node_count(env, get_node_count(env).saturating_sub(1));
+}
+
 pub fn get_min_stake(env: &Env) -> i128 {
     env.storage()
         .instance()
@@ -104,6 +506,18 @@ pub fn set_min_stake(env: &Env, min_stake: i128) {
     env.storage().instance().set(&DataKey::MinStake, &min_stake);
 }
 
+/// Ledger timestamp until which under-staked `Active` nodes are not demoted,
+/// set when the minimum stake was last raised.
+pub fn get_min_stake_grace_until(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&DataKey::MinStakeGraceUntil)
+}
+
+pub fn set_min_stake_grace_until(env: &Env, grace_until: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MinStakeGraceUntil, &grace_until);
+}
+
 pub fn get_stake_lock_period(env: &Env) -> u32 {
     env.storage()
         .instance()
@@ -117,6 +531,47 @@ pub fn set_stake_lock_period(env: &Env, period: u32) {
         .set(&DataKey::StakeLockPeriod, &period);
 }
 
+/// Clock lock periods are measured on. Registries initialized before the unit
+/// was configurable have always locked on ledger timestamps.
+pub fn get_lock_unit(env: &Env) -> LockUnit {
+    env.storage()
+        .instance()
+        .get(&DataKey::LockUnit)
+        .unwrap_or(LockUnit::Seconds)
+}
+
+pub fn set_lock_unit(env: &Env, unit: LockUnit) {
+    env.storage().instance().set(&DataKey::LockUnit, &unit);
+}
+
+/// Clock of the unbondings queued before schema version 6, pinned by the
+/// migration to the unit in force at the time.
+pub fn get_legacy_lock_unit(env: &Env) -> LockUnit {
+    env.storage()
+        .instance()
+        .get(&DataKey::LegacyLockUnit)
+        .unwrap_or_else(|| get_lock_unit(env))
+}
+
+pub fn set_legacy_lock_unit(env: &Env, unit: LockUnit) {
+    env.storage()
+        .instance()
+        .set(&DataKey::LegacyLockUnit, &unit);
+}
+
+/// Staking tokens held for nodes and delegators: bonded, unbonding and not yet
+/// withdrawn. Tokens sent to the registry outside `stake` and `delegate` are not counted.
+pub fn get_total_staked(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalStaked)
+        .unwrap_or(0)
+}
+
+pub fn set_total_staked(env: &Env, total: i128) {
+    env.storage().instance().set(&DataKey::TotalStaked, &total);
+}
+
 pub fn get_admin_council(env: &Env) -> crate::types::AdminCouncil {
     env.storage()
         .instance()
@@ -143,28 +598,22 @@ pub fn set_token_address(env: &Env, token_address: &Address) {
         .set(&DataKey::TokenAddress, token_address);
 }
 
-pub fn get_lock_entry(env: &Env, address: &Address) -> Option<crate::types::StakeEntry> {
+/// The single pending unstake of the version 1 layout. Only read to fold it
+/// into the node's unbonding queue.
+pub fn get_lock_entry(env: &Env, address: &Address) -> Option<StakeEntry> {
     let key = DataKey::LockEntry(address.clone());
-    if let Some(entry) = env
-        .storage()
-        .persistent()
-        .get::<_, crate::types::StakeEntry>(&key)
-    {
-        env.storage()
-            .persistent()
-            .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
-        Some(entry)
+    if let Some(entry) = env.storage().persistent().get::<_, StakeEntryV5>(&key) {
+        extend_persistent_ttl(env, &key);
+        Some(entry.upgrade(env))
     } else {
         None
     }
 }
 
-pub fn set_lock_entry(env: &Env, address: &Address, entry: &crate::types::StakeEntry) {
+pub fn set_lock_entry(env: &Env, address: &Address, entry: &StakeEntryV5) {
     let key = DataKey::LockEntry(address.clone());
     env.storage().persistent().set(&key, entry);
-    env.storage()
-        .persistent()
-        .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+    extend_persistent_ttl(env, &key);
 }
 
 pub fn remove_lock_entry(env: &Env, address: &Address) {
@@ -172,3 +621,641 @@ pub fn remove_lock_entry(env: &Env, address: &Address) {
         .persistent()
         .remove(&DataKey::LockEntry(address.clone()));
 }
+
+/// Pending unbondings of a node, oldest first. A queue written before schema
+/// version 6 is read from `Unbonding(Address)`, and a version 1 `LockEntry`
+/// left over from before the upgrade is returned at the front of the queue.
+pub fn get_unbondings(env: &Env, address: &Address) -> Vec<StakeEntry> {
+    let key = DataKey::UnbondingQueue(address.clone());
+    let mut unbondings = match env.storage().persistent().get::<_, Vec<StakeEntry>>(&key) {
+        Some(unbondings) => {
+            extend_persistent_ttl(env, &key);
+            unbondings
+        }
+        None => get_legacy_unbondings(env, &DataKey::Unbonding(address.clone()))
+            .unwrap_or_else(|| Vec::new(env)),
+    };
+    if let Some(legacy) = get_lock_entry(env, address) {
+        unbondings.push_front(legacy);
+    }
+    unbondings
+}
+
+/// Replace a node's unbonding queue, dropping any legacy queue or version 1
+/// `LockEntry` (which `get_unbondings` has already folded in).
+pub fn set_unbondings(env: &Env, address: &Address, unbondings: &Vec<StakeEntry>) {
+    remove_lock_entry(env, address);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Unbonding(address.clone()));
+    let key = DataKey::UnbondingQueue(address.clone());
+    if unbondings.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, unbondings);
+        extend_persistent_ttl(env, &key);
+    }
+}
+
+pub fn get_proposal(env: &Env, proposal_id: u64) -> Option<Proposal> {
+    let key = DataKey::Proposal(proposal_id);
+    if let Some(proposal) = env.storage().persistent().get::<_, Proposal>(&key) {
+        extend_persistent_ttl(env, &key);
+        Some(proposal)
+    } else {
+        None
+    }
+}
+
+pub fn set_proposal(env: &Env, proposal: &Proposal) {
+    let key = DataKey::Proposal(proposal.id);
+    env.storage().persistent().set(&key, proposal);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn get_proposal_eta(env: &Env, proposal_id: u64) -> Option<u64> {
+    let key = DataKey::ProposalEta(proposal_id);
+    let eta = env.storage().persistent().get::<_, u64>(&key);
+    if eta.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    eta
+}
+
+pub fn set_proposal_eta(env: &Env, proposal_id: u64, eta: u64) {
+    let key = DataKey::ProposalEta(proposal_id);
+    env.storage().persistent().set(&key, &eta);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn next_proposal_id(env: &Env) -> u64 {
+    let next = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&DataKey::ProposalCount)
+        .unwrap_or(0)
+        .checked_add(1)
+        .expect("proposal count overflow");
+    env.storage().instance().set(&DataKey::ProposalCount, &next);
+    next
+}
+
+/// Schema version of the stored data. Deployments that predate version
+/// tracking use the version 1 layout.
+pub fn get_schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(1)
+}
+
+pub fn set_schema_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SchemaVersion, &version);
+}
+
+pub fn is_paused(env: &Env, function: &PausableFn) -> bool {
+    env.storage()
+        .instance()
+        .has(&DataKey::Paused(function.clone()))
+}
+
+pub fn set_paused(env: &Env, function: &PausableFn, paused: bool) {
+    let key = DataKey::Paused(function.clone());
+    if paused {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+}
+
+/// Addresses holding `role`, in the order they were granted it.
+pub fn get_role_holders(env: &Env, role: &Role) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::RoleHolders(role.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_role_holders(env: &Env, role: &Role, holders: &Vec<Address>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RoleHolders(role.clone()), holders);
+}
+
+pub fn has_role(env: &Env, role: &Role, account: &Address) -> bool {
+    get_role_holders(env, role).contains(account)
+}
+
+pub fn get_guardians(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Guardians)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_guardians(env: &Env, guardians: &Vec<Address>) {
+    env.storage().instance().set(&DataKey::Guardians, guardians);
+}
+
+pub fn get_veto(env: &Env, proposal_id: u64) -> Option<VetoRecord> {
+    let key = DataKey::Veto(proposal_id);
+    let veto = env.storage().persistent().get::<_, VetoRecord>(&key);
+    if veto.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    veto
+}
+
+pub fn set_veto(env: &Env, proposal_id: u64, veto: &VetoRecord) {
+    let key = DataKey::Veto(proposal_id);
+    env.storage().persistent().set(&key, veto);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn get_action_log_entry(env: &Env, id: u64) -> Option<ActionLogEntry> {
+    let key = DataKey::ActionLog(id);
+    let entry = env.storage().persistent().get::<_, ActionLogEntry>(&key);
+    if entry.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    entry
+}
+
+pub fn set_action_log_entry(env: &Env, entry: &ActionLogEntry) {
+    let key = DataKey::ActionLog(entry.id);
+    env.storage().persistent().set(&key, entry);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn get_action_log_count(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ActionLogCount)
+        .unwrap_or(0)
+}
+
+pub fn next_action_log_id(env: &Env) -> u64 {
+    let next = get_action_log_count(env)
+        .checked_add(1)
+        .expect("action log count overflow");
+    env.storage()
+        .instance()
+        .set(&DataKey::ActionLogCount, &next);
+    next
+}
+
+/// Basis points slashed for `reason_code`, if a tier is configured.
+pub fn get_slash_tier(env: &Env, reason_code: u32) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::SlashTier(reason_code))
+}
+
+pub fn set_slash_tier(env: &Env, reason_code: u32, bps: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SlashTier(reason_code), &bps);
+}
+
+pub fn get_treasury(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Treasury)
+}
+
+pub fn set_treasury(env: &Env, treasury: &Address) {
+    env.storage().instance().set(&DataKey::Treasury, treasury);
+}
+
+pub fn get_dispute_resolver(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::DisputeResolver)
+}
+
+pub fn set_dispute_resolver(env: &Env, dispute_resolver: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::DisputeResolver, dispute_resolver);
+}
+
+/// Amount `delegator` has bonded to `node`.
+pub fn get_delegation(env: &Env, node: &Address, delegator: &Address) -> i128 {
+    let key = DataKey::Delegation(node.clone(), delegator.clone());
+    let amount = env.storage().persistent().get::<_, i128>(&key);
+    if amount.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    amount.unwrap_or(0)
+}
+
+/// Persist a delegation, removing the entry once it reaches zero.
+pub fn set_delegation(env: &Env, node: &Address, delegator: &Address, amount: i128) {
+    let key = DataKey::Delegation(node.clone(), delegator.clone());
+    if amount == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &amount);
+        extend_persistent_ttl(env, &key);
+    }
+}
+
+/// Delegators of `node` that still have a delegation or a pending unbonding.
+pub fn get_delegators(env: &Env, node: &Address) -> Vec<Address> {
+    let key = DataKey::Delegators(node.clone());
+    if let Some(delegators) = env.storage().persistent().get::<_, Vec<Address>>(&key) {
+        extend_persistent_ttl(env, &key);
+        delegators
+    } else {
+        Vec::new(env)
+    }
+}
+
+pub fn set_delegators(env: &Env, node: &Address, delegators: &Vec<Address>) {
+    let key = DataKey::Delegators(node.clone());
+    if delegators.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, delegators);
+        extend_persistent_ttl(env, &key);
+    }
+}
+
+/// Total amount currently delegated to `node`, excluding pending unbondings.
+pub fn get_delegated_stake(env: &Env, node: &Address) -> i128 {
+    let key = DataKey::DelegatedStake(node.clone());
+    let total = env.storage().persistent().get::<_, i128>(&key);
+    if total.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    total.unwrap_or(0)
+}
+
+pub fn set_delegated_stake(env: &Env, node: &Address, total: i128) {
+    let previous_bonded = get_bonded_stake(env, node);
+    let key = DataKey::DelegatedStake(node.clone());
+    if total == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &total);
+        extend_persistent_ttl(env, &key);
+    }
+    checkpoint_bonded_stake(env, node, previous_bonded);
+}
+
+/// Stake bonded to `address`: its own stake plus what is delegated to it, or
+/// 0 if it is not a registered node.
+pub fn get_bonded_stake(env: &Env, address: &Address) -> i128 {
+    get_node(env, address).map_or(0, |node| {
+        node.stake.saturating_add(get_delegated_stake(env, address))
+    })
+}
+
+fn get_stake_checkpoint_count(env: &Env, address: &Address) -> u32 {
+    let key = DataKey::StakeCheckpointCount(address.clone());
+    let count = env.storage().persistent().get::<_, u32>(&key);
+    if count.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    count.unwrap_or(0)
+}
+
+/// Load a checkpoint by its 1-based position in `address`'s stake history.
+fn get_stake_checkpoint(env: &Env, address: &Address, index: u32) -> StakeCheckpoint {
+    let key = DataKey::StakeCheckpoint(address.clone(), index);
+    let checkpoint = env
+        .storage()
+        .persistent()
+        .get::<_, StakeCheckpoint>(&key)
+        .expect("stake checkpoint missing");
+    extend_persistent_ttl(env, &key);
+    checkpoint
+}
+
+fn set_stake_checkpoint(env: &Env, address: &Address, index: u32, checkpoint: &StakeCheckpoint) {
+    let key = DataKey::StakeCheckpoint(address.clone(), index);
+    env.storage().persistent().set(&key, checkpoint);
+    extend_persistent_ttl(env, &key);
+}
+
+fn push_stake_checkpoint(env: &Env, address: &Address, checkpoint: &StakeCheckpoint) {
+    let index = get_stake_checkpoint_count(env, address)
+        .checked_add(1)
+        .expect("stake checkpoint count overflow");
+    set_stake_checkpoint(env, address, index, checkpoint);
+    let key = DataKey::StakeCheckpointCount(address.clone());
+    env.storage().persistent().set(&key, &index);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Record `address`'s bonded stake as of the current ledger, after a write
+/// that may have changed it from `previous`. A second change within the same
+/// ledger overwrites that ledger's checkpoint.
+///
+/// Addresses with no history yet — every node bonded before checkpoints were
+/// kept — first get a checkpoint from ledger 0 holding `previous`.
+fn checkpoint_bonded_stake(env: &Env, address: &Address, previous: i128) {
+    let bonded = get_bonded_stake(env, address);
+    let ledger = env.ledger().sequence();
+    let count = get_stake_checkpoint_count(env, address);
+    if count == 0 {
+        if bonded == previous {
+            return;
+        }
+        if previous != 0 {
+            push_stake_checkpoint(
+                env,
+                address,
+                &StakeCheckpoint {
+                    ledger: 0,
+                    bonded: previous,
+                },
+            );
+        }
+    } else {
+        let last = get_stake_checkpoint(env, address, count);
+        if last.ledger == ledger {
+            set_stake_checkpoint(env, address, count, &StakeCheckpoint { ledger, bonded });
+            return;
+        }
+        if last.bonded == bonded {
+            return;
+        }
+    }
+    push_stake_checkpoint(env, address, &StakeCheckpoint { ledger, bonded });
+}
+
+/// Bonded stake of `address` at the end of `ledger`. An address with no
+/// checkpoints has not changed its stake since they were introduced.
+pub fn get_bonded_stake_at(env: &Env, address: &Address, ledger: u32) -> i128 {
+    let count = get_stake_checkpoint_count(env, address);
+    if count == 0 {
+        return get_bonded_stake(env, address);
+    }
+
+    // Latest checkpoint at or before `ledger`, by binary search over 1..=count.
+    let (mut low, mut high) = (1, count);
+    let mut found = 0;
+    while low <= high {
+        let mid = low + (high - low) / 2;
+        if get_stake_checkpoint(env, address, mid).ledger <= ledger {
+            found = mid;
+            low = mid + 1;
+        } else {
+            high = mid - 1;
+        }
+    }
+    if found == 0 {
+        0
+    } else {
+        get_stake_checkpoint(env, address, found).bonded
+    }
+}
+
+/// Pending undelegations of `delegator` from `node`, oldest first. A queue
+/// written before schema version 6 is read from `DelegatorUnbonding`.
+pub fn get_delegator_unbondings(env: &Env, node: &Address, delegator: &Address) -> Vec<StakeEntry> {
+    let key = DataKey::DelegatorUnbondingQueue(node.clone(), delegator.clone());
+    if let Some(unbondings) = env.storage().persistent().get::<_, Vec<_>>(&key) {
+        extend_persistent_ttl(env, &key);
+        unbondings
+    } else {
+        get_legacy_unbondings(
+            env,
+            &DataKey::DelegatorUnbonding(node.clone(), delegator.clone()),
+        )
+        .unwrap_or_else(|| Vec::new(env))
+    }
+}
+
+pub fn set_delegator_unbondings(
+    env: &Env,
+    node: &Address,
+    delegator: &Address,
+    unbondings: &Vec<StakeEntry>,
+) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::DelegatorUnbonding(
+            node.clone(),
+            delegator.clone(),
+        ));
+    let key = DataKey::DelegatorUnbondingQueue(node.clone(), delegator.clone());
+    if unbondings.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, unbondings);
+        extend_persistent_ttl(env, &key);
+    }
+}
+
+/// Commission the operator of `node` keeps from its delegators' rewards, in basis points.
+pub fn get_commission(env: &Env, node: &Address) -> u32 {
+    let key = DataKey::Commission(node.clone());
+    let bps = env.storage().persistent().get::<_, u32>(&key);
+    if bps.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    bps.unwrap_or(0)
+}
+
+pub fn set_commission(env: &Env, node: &Address, bps: u32) {
+    let key = DataKey::Commission(node.clone());
+    env.storage().persistent().set(&key, &bps);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn remove_commission(env: &Env, node: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Commission(node.clone()));
+}
+
+pub fn get_uptime(env: &Env, address: &Address) -> Option<UptimeRecord> {
+    let key = DataKey::Uptime(address.clone());
+    let record = env.storage().persistent().get::<_, UptimeRecord>(&key);
+    if record.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    record
+}
+
+pub fn set_uptime(env: &Env, address: &Address, record: &UptimeRecord) {
+    let key = DataKey::Uptime(address.clone());
+    env.storage().persistent().set(&key, record);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn remove_uptime(env: &Env, address: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Uptime(address.clone()));
+}
+
+/// Latest liveness fault recorded for a node.
+pub fn get_liveness_fault(env: &Env, address: &Address) -> Option<LivenessFault> {
+    let key = DataKey::LivenessFault(address.clone());
+    let fault = env.storage().persistent().get::<_, LivenessFault>(&key);
+    if fault.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    fault
+}
+
+pub fn set_liveness_fault(env: &Env, address: &Address, fault: &LivenessFault) {
+    let key = DataKey::LivenessFault(address.clone());
+    env.storage().persistent().set(&key, fault);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn remove_liveness_fault(env: &Env, address: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::LivenessFault(address.clone()));
+}
+
+/// Jail history of a node, if it has ever been jailed.
+pub fn get_jail(env: &Env, address: &Address) -> Option<JailRecord> {
+    let key = DataKey::Jail(address.clone());
+    let record = env.storage().persistent().get::<_, JailRecord>(&key);
+    if record.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    record
+}
+
+pub fn set_jail(env: &Env, address: &Address, record: &JailRecord) {
+    let key = DataKey::Jail(address.clone());
+    env.storage().persistent().set(&key, record);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn remove_jail(env: &Env, address: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Jail(address.clone()));
+}
+
+/// Number of signing keys a node has registered, including rotated ones.
+pub fn get_signing_key_count(env: &Env, address: &Address) -> u32 {
+    let key = DataKey::SigningKeyCount(address.clone());
+    let count = env.storage().persistent().get::<_, u32>(&key);
+    if count.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    count.unwrap_or(0)
+}
+
+/// Load a node's signing key by its 1-based position in the key history.
+pub fn get_signing_key(env: &Env, address: &Address, index: u32) -> Option<SigningKeyRecord> {
+    let key = DataKey::SigningKey(address.clone(), index);
+    let record = env.storage().persistent().get::<_, SigningKeyRecord>(&key);
+    if record.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    record
+}
+
+pub fn set_signing_key(env: &Env, address: &Address, index: u32, record: &SigningKeyRecord) {
+    let key = DataKey::SigningKey(address.clone(), index);
+    env.storage().persistent().set(&key, record);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Append a signing key to a node's key history and return its position.
+pub fn push_signing_key(env: &Env, address: &Address, record: &SigningKeyRecord) -> u32 {
+    let index = get_signing_key_count(env, address)
+        .checked_add(1)
+        .expect("signing key count overflow");
+    set_signing_key(env, address, index, record);
+    let key = DataKey::SigningKeyCount(address.clone());
+    env.storage().persistent().set(&key, &index);
+    extend_persistent_ttl(env, &key);
+    index
+}
+
+pub fn get_reputation(env: &Env, address: &Address) -> Option<ReputationRecord> {
+    let key = DataKey::Reputation(address.clone());
+    let record = env.storage().persistent().get::<_, ReputationRecord>(&key);
+    if record.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    record
+}
+
+pub fn set_reputation(env: &Env, address: &Address, record: &ReputationRecord) {
+    let key = DataKey::Reputation(address.clone());
+    env.storage().persistent().set(&key, record);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn remove_reputation(env: &Env, address: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Reputation(address.clone()));
+}
+
+/// Nodes with the highest reputation, best first, at most `TOP_NODES_CAPACITY`.
+pub fn get_top_nodes(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::TopNodes)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_top_nodes(env: &Env, nodes: &Vec<Address>) {
+    env.storage().instance().set(&DataKey::TopNodes, nodes);
+}
+
+/// Address a node's operator has offered to move the node to.
+pub fn get_pending_transfer(env: &Env, node: &Address) -> Option<Address> {
+    let key = DataKey::PendingTransfer(node.clone());
+    let new_address = env.storage().persistent().get::<_, Address>(&key);
+    if new_address.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    new_address
+}
+
+pub fn set_pending_transfer(env: &Env, node: &Address, new_address: &Address) {
+    let key = DataKey::PendingTransfer(node.clone());
+    env.storage().persistent().set(&key, new_address);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn remove_pending_transfer(env: &Env, node: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PendingTransfer(node.clone()));
+}
+
+/// Address a node moved to when it left `old` through an ownership transfer.
+pub fn get_forwarding(env: &Env, old: &Address) -> Option<Address> {
+    let key = DataKey::Forwarding(old.clone());
+    let new_address = env.storage().persistent().get::<_, Address>(&key);
+    if new_address.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    new_address
+}
+
+pub fn set_forwarding(env: &Env, old: &Address, new_address: &Address) {
+    let key = DataKey::Forwarding(old.clone());
+    env.storage().persistent().set(&key, new_address);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Last ledger of the latest-ending open vote the node's stake was counted in;
+/// 0 if it never voted.
+pub fn get_vote_lock(env: &Env, address: &Address) -> u64 {
+    let key = DataKey::VoteLock(address.clone());
+    let until = env.storage().persistent().get::<_, u64>(&key);
+    if until.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    until.unwrap_or(0)
+}
+
+pub fn set_vote_lock(env: &Env, address: &Address, until: u64) {
+    let key = DataKey::VoteLock(address.clone());
+    env.storage().persistent().set(&key, &until);
+    extend_persistent_ttl(env, &key);
+}
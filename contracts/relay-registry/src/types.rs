@@ -6,7 +6,7 @@
 //! - `RelayNode` — The primary struct representing a registered relay node, including:
 //!   - `address: Address` — The Stellar account address of the relay node
 //!   - `stake: i128` — Current staked token amount
-//!   - `status: NodeStatus` — Active, Inactive, or Slashed
+//!   - `status: NodeStatus` — Active, Inactive, Slashed, Exiting, or Jailed
 //!   - `metadata: NodeMetadata` — Region, capacity, uptime commitment
 //!   - `registered_at: u64` — Ledger timestamp of registration
 //!   - `last_active: u64` — Ledger timestamp of last activity
@@ -14,22 +14,18 @@
 //!   - `region: String` — Geographic region of the relay node
 //!   - `capacity: u32` — Maximum transactions per batch
 //!   - `uptime_commitment: u32` — Percentage uptime commitment (0–100)
-//! - `NodeStatus` — Enum with variants: `Active`, `Inactive`, `Slashed`
-//! - `StakeEntry` — Represents a pending unstake operation with unlock ledger
+//! - `NodeStatus` — Enum with variants: `Active`, `Inactive`, `Slashed`, `Exiting`, `Jailed`
+//! - `StakeEntry` — Represents a pending unstake operation with its unlock point
+//! - `LockUnit` — Whether lock periods count ledgers or seconds
+//! - `UnlockEta` — When a pending unbonding unlocks, in both ledgers and seconds
+//! - `Proposal` — A council proposal collecting M-of-N approvals for a privileged action
 //!
 //! implementation tracked in GitHub issue
 
-use soroban_sdk::{contracttype, Address, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
 
-/// A multi-signature admin council requiring threshold approvals.
-#[contracttype]
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct AdminCouncil {
-    /// List of council member addresses (max 10)
-    pub members: Vec<Address>,
-    /// Minimum number of members required to authorize a sensitive action
-    pub threshold: u32,
-}
+pub use conduit_common::council::{AdminCouncil, ProposalStatus, MAX_COUNCIL_MEMBERS};
+pub use conduit_common::guardians::VetoRecord;
 
 /// Represents the operational status of a relay node within the protocol.
 #[contracttype]
@@ -41,18 +37,45 @@ pub enum NodeStatus {
     Inactive,
     /// Node has been slashed due to misbehavior and cannot participate.
     Slashed,
+    /// Node is leaving the registry and waits out the lock period on its stake.
+    Exiting,
+    /// Node is serving a temporary jail sentence and cannot relay until it calls
+    /// `unjail` after the release time kept in its `JailRecord`.
+    Jailed,
+}
+
+/// A mesh transport a relay node can carry signed transactions over.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Transport {
+    /// Bluetooth Low Energy.
+    Ble,
+    /// WiFi Direct.
+    WifiDirect,
+    /// LoRa long-range radio.
+    LoRa,
+    /// Internet uplink, used to settle batches on Stellar.
+    Internet,
 }
 
 /// Metadata associated with a relay node, describing its operational characteristics.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct NodeMetadata {
-    /// Geographic region where the relay node is located.
+    /// Geographic region where the relay node is located, at most `MAX_REGION_LEN` bytes.
     pub region: String,
     /// Maximum number of transactions the node can handle per batch.
     pub capacity: u32,
     /// Uptime commitment percentage (0-100) that the node promises to maintain.
     pub uptime_commitment: u32,
+    /// Endpoint peers reach the node's mesh transport at, at most `MAX_ENDPOINT_LEN` bytes.
+    pub endpoint: Option<String>,
+    /// Relay software version the node runs, at most `MAX_SOFTWARE_VERSION_LEN` bytes.
+    pub software_version: Option<String>,
+    /// Transports the node supports, each listed once.
+    pub transports: Vec<Transport>,
+    /// Hash of the operator's off-chain contact details.
+    pub contact_hash: Option<BytesN<32>>,
 }
 
 /// Represents a registered relay node in the protocol, including its stake, status, and metadata.
@@ -73,7 +96,7 @@ pub struct RelayNode {
     pub last_active: u64,
 }
 
-/// Represents a pending unstake operation that is subject to a lock period.
+/// Represents a pending unstake or undelegation that is subject to a lock period.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct StakeEntry {
@@ -81,6 +104,324 @@ pub struct StakeEntry {
     pub address: Address,
     /// The amount of tokens unbonded.
     pub amount: i128,
-    /// Ledger number when the unstaked tokens can be withdrawn.
+    /// Point at which the unstaked tokens can be withdrawn, on the `unit` clock.
     pub unlocks_at: u64,
+    /// Clock `unlocks_at` is measured on: the registry's `LockUnit` when the
+    /// unbonding started.
+    pub unit: LockUnit,
+}
+
+/// Clock that stake lock periods and `StakeEntry::unlocks_at` are measured on.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LockUnit {
+    /// Ledger sequence numbers.
+    Ledgers,
+    /// Seconds of ledger timestamp.
+    Seconds,
+}
+
+/// When a pending unbonding unlocks, in both units.
+///
+/// The value in the entry's `LockUnit` is exact; the other one is estimated
+/// from the current ledger at `SECONDS_PER_LEDGER` seconds per ledger.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnlockEta {
+    /// The amount of tokens unbonded.
+    pub amount: i128,
+    /// Ledger sequence at which the tokens can be withdrawn.
+    pub ledger: u64,
+    /// Ledger timestamp at which the tokens can be withdrawn.
+    pub timestamp: u64,
+}
+
+/// Heartbeat tally of a relay node for the current uptime epoch.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UptimeRecord {
+    /// Epoch the heartbeat tally belongs to.
+    pub epoch: u64,
+    /// First heartbeat slot of `epoch` the node is expected to be live in.
+    pub tracked_from: u64,
+    /// Distinct slots of `epoch` with a heartbeat.
+    pub heartbeats: u32,
+    /// Slot of the latest counted heartbeat.
+    pub last_slot: u64,
+    /// Uptime percentage (0-100) of the previous epoch, once one has been tracked.
+    pub last_uptime: Option<u32>,
+    /// Epoch in which `check_liveness` last evaluated `last_uptime`.
+    pub checked_epoch: u64,
+}
+
+/// A node's uptime falling short of its `uptime_commitment`, recorded by `check_liveness`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LivenessFault {
+    /// Epoch whose uptime fell short.
+    pub epoch: u64,
+    /// Measured uptime percentage.
+    pub uptime: u32,
+    /// Committed uptime percentage at the time of the check.
+    pub commitment: u32,
+}
+
+/// A node's jail history, kept after release so repeated jailing can escalate.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JailRecord {
+    /// Timestamp from which the node may call `unjail`.
+    pub until: u64,
+    /// Timestamp of the most recent jailing.
+    pub jailed_at: u64,
+    /// Jailings within `JAIL_RECORD_WINDOW` of each other, including the latest.
+    pub jail_count: u32,
+    /// Council proposal fully slashing the node, queued when this jailing
+    /// reached `JAILS_BEFORE_SLASH`. The node stays jailed while it is queued.
+    pub pending_slash: Option<u64>,
+}
+
+/// A node's reputation, built from settled batches, disputes, slashes and liveness.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReputationRecord {
+    /// Score as of `epoch`. Loses `REPUTATION_DECAY_BPS` of its value every epoch.
+    pub score: i64,
+    /// Uptime epoch the score has been decayed to.
+    pub epoch: u64,
+    /// Settled batches reported for the node.
+    pub settled_batches: u32,
+    /// Disputes the node won.
+    pub disputes_won: u32,
+    /// Disputes the node lost.
+    pub disputes_lost: u32,
+}
+
+/// An address's bonded stake — its own plus delegated — from a ledger onward.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StakeCheckpoint {
+    /// Ledger sequence from which `bonded` applies.
+    pub ledger: u32,
+    /// Node stake plus delegated stake; 0 for an unregistered address.
+    pub bonded: i128,
+}
+
+/// An Ed25519 signing key a node registered, with the period it was in use.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SigningKeyRecord {
+    /// Raw 32-byte Ed25519 public key.
+    pub public_key: BytesN<32>,
+    /// Ledger timestamp from which the key signs for the node.
+    pub active_from: u64,
+    /// Ledger timestamp at which the key was rotated out, if it has been.
+    pub active_until: Option<u64>,
+}
+
+/// A page of nodes returned by `list_nodes` and `list_nodes_by_transport`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NodePage {
+    /// Matching nodes, in registration order.
+    pub nodes: Vec<RelayNode>,
+    /// Cursor to pass to the next call, or `None` once the index is exhausted.
+    pub next_cursor: Option<u32>,
+}
+
+/// Registry configuration returned by `get_config`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegistryConfig {
+    /// Minimum stake a node needs to be `Active`.
+    pub min_stake: i128,
+    /// Lock period applied to unstaked tokens, in `lock_unit`.
+    pub stake_lock_period: u32,
+    /// Whether `stake_lock_period` counts ledgers or seconds.
+    pub lock_unit: LockUnit,
+    /// Ledger timestamp until which `Active` nodes below a raised `min_stake`
+    /// keep their status, if the minimum has been raised.
+    pub min_stake_grace_until: Option<u64>,
+    /// SAC token nodes stake in.
+    pub token: Address,
+    /// Council authorized to change the configuration through proposals.
+    pub council: AdminCouncil,
+    /// Treasury contract that receives slashed stake, once configured.
+    pub treasury: Option<Address>,
+    /// Dispute resolver consulted before a node may exit, once configured.
+    pub dispute_resolver: Option<Address>,
+}
+
+/// An entrypoint that the council can pause in an emergency.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PausableFn {
+    /// `register`: Register a new relay node.
+    Register,
+    /// `update_metadata`: Update a node's metadata.
+    UpdateMetadata,
+    /// `stake`: Deposit stake.
+    Stake,
+    /// `unstake`: Begin unbonding stake.
+    Unstake,
+    /// `finalize_unstake`: Withdraw matured unbonded stake.
+    FinalizeUnstake,
+    /// `delegate`: Bond tokens to a node as a delegator.
+    Delegate,
+    /// `undelegate`: Begin unbonding delegated tokens.
+    Undelegate,
+    /// `finalize_undelegate`: Withdraw matured undelegated tokens.
+    FinalizeUndelegate,
+    /// `begin_exit`: Start leaving the registry.
+    BeginExit,
+    /// `complete_exit`: Withdraw the stake and remove the node record.
+    CompleteExit,
+    /// `accept_transfer`: Move a node to its new operator address.
+    AcceptTransfer,
+    /// `unjail`: Release a node whose jail sentence has run out.
+    Unjail,
+    /// `propose_transfer`: Offer a node to a new operator address.
+    ProposeTransfer,
+    /// `cancel_transfer`: Withdraw a pending node transfer.
+    CancelTransfer,
+    /// `set_commission`: Change a node's delegator commission.
+    SetCommission,
+    /// `heartbeat`: Record a node's liveness for the current slot.
+    Heartbeat,
+    /// `register_signing_key`: Register a node's first relay signing key.
+    RegisterSigningKey,
+    /// `rotate_signing_key`: Replace a node's relay signing key.
+    RotateSigningKey,
+}
+
+/// A narrow permission the council can grant so that an address can perform one
+/// kind of privileged action without a council vote.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Role {
+    /// May slash, jail and reinstate nodes (`Slash`, `Jail`, `ReinstateNode`).
+    Slasher,
+    /// May report settled batches for reputation (`record_batch`), e.g. the fee distributor.
+    BatchReporter,
+    /// May report dispute outcomes for reputation (`record_dispute`), e.g. the dispute resolver.
+    DisputeReporter,
+    /// May lock a voting node against ownership transfers (`record_vote`), e.g. governance.
+    VoteRecorder,
+}
+
+/// A privileged action that can only be performed through an approved council proposal.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProposalAction {
+    /// Slash a node by the severity tier of a reason code, with a human-readable note.
+    Slash(Address, u32, String),
+    /// Jail a node for `JAIL_PERIOD`, with a human-readable note.
+    Jail(Address, String),
+    /// Move a slashed node back to `Inactive`.
+    ReinstateNode(Address),
+    /// Add a member to the admin council.
+    AddMember(Address),
+    /// Remove a member from the admin council.
+    RemoveMember(Address),
+    /// Replace an existing council member with a new address.
+    ReplaceMember(Address, Address),
+    /// Change the number of approvals required to execute a proposal.
+    SetThreshold(u32),
+    /// Replace the contract WASM with an uploaded build, identified by its hash.
+    Upgrade(BytesN<32>),
+    /// Lift an emergency pause on the listed entrypoints.
+    Unpause(Vec<PausableFn>),
+    /// Cancel a queued timelocked action before it is executed.
+    CancelQueued(u64),
+    /// Grant a role to an address.
+    GrantRole(Role, Address),
+    /// Revoke a role from an address.
+    RevokeRole(Role, Address),
+    /// Replace the guardian set that may veto proposals.
+    SetGuardians(Vec<Address>),
+    /// Switch staking to another SAC token once no tokens are held in the current one.
+    SetStakeToken(Address),
+    /// Set the basis points slashed for a reason code.
+    SetSlashTier(u32, u32),
+    /// Set the treasury contract that receives slashed stake.
+    SetTreasury(Address),
+    /// Set the dispute resolver that must have no open dispute for a node to exit.
+    SetDisputeResolver(Address),
+    /// Switch lock periods to another unit, restating the period in it. Existing
+    /// unbondings keep the unit they were queued with.
+    SetLockUnit(LockUnit, u32),
+    /// Change the minimum stake; raising it starts a grace period before under-staked nodes are demoted.
+    SetMinStake(i128),
+    /// Change the lock period applied to new unbondings, in the current lock unit.
+    SetStakeLockPeriod(u32),
+}
+
+impl ProposalAction {
+    /// Role whose holders may perform the action without council approvals, if any.
+    pub fn required_role(&self) -> Option<Role> {
+        match self {
+            ProposalAction::Slash(..)
+            | ProposalAction::Jail(..)
+            | ProposalAction::ReinstateNode(_) => Some(Role::Slasher),
+            _ => None,
+        }
+    }
+
+    /// Whether the action waits out `TIMELOCK_DELAY` after approval before it applies.
+    pub fn is_timelocked(&self) -> bool {
+        matches!(
+            self,
+            ProposalAction::Slash(..)
+                | ProposalAction::Upgrade(_)
+                | ProposalAction::GrantRole(..)
+                | ProposalAction::AddMember(_)
+                | ProposalAction::RemoveMember(_)
+                | ProposalAction::ReplaceMember(..)
+                | ProposalAction::SetThreshold(_)
+                | ProposalAction::SetGuardians(_)
+                | ProposalAction::SetStakeToken(_)
+                | ProposalAction::SetSlashTier(..)
+                | ProposalAction::SetTreasury(_)
+                | ProposalAction::SetDisputeResolver(_)
+                | ProposalAction::SetLockUnit(..)
+                | ProposalAction::SetMinStake(_)
+                | ProposalAction::SetStakeLockPeriod(_)
+        )
+    }
+}
+
+/// A council proposal for a privileged action, approved by distinct council members.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proposal {
+    /// Unique monotonic identifier for this proposal.
+    pub id: u64,
+    /// Council member that created the proposal.
+    pub proposer: Address,
+    /// The action applied when the proposal is executed.
+    pub action: ProposalAction,
+    /// Distinct council members that approved the proposal (includes the proposer).
+    pub approvals: Vec<Address>,
+    /// Current lifecycle status of the proposal.
+    pub status: ProposalStatus,
+    /// Ledger sequence when the proposal was created.
+    pub created_at: u64,
+    /// Last ledger sequence at which the proposal can be approved or executed.
+    pub expires_at: u64,
+}
+
+/// A privileged action the contract has applied, kept as an append-only audit record.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ActionLogEntry {
+    /// Position in the action log, starting at 1.
+    pub id: u64,
+    /// Proposal that carried the action.
+    pub proposal_id: u64,
+    /// The applied action, including its arguments.
+    pub action: ProposalAction,
+    /// Addresses that approved the proposal, proposer included.
+    pub approvals: Vec<Address>,
+    /// Ledger sequence at which the action was applied.
+    pub ledger: u64,
 }
@@ -1,12 +1,18 @@
 #![cfg(test)]
 
+use conduit_common::council::TIMELOCK_DELAY;
 use relay_registry::{
-    types::{AdminCouncil, NodeMetadata, NodeStatus},
+    errors::ContractError,
+    storage,
+    types::{
+        AdminCouncil, LockUnit, NodeMetadata, NodeStatus, PausableFn, ProposalAction,
+        ProposalStatus, Role, Transport, UnlockEta,
+    },
     RelayRegistryContract, RelayRegistryContractClient,
 };
 use soroban_sdk::{
-    testutils::{Address as _, Ledger},
-    token, Address, Env, String,
+    testutils::{Address as _, EnvTestConfig, Ledger},
+    token, vec, Address, BytesN, Env, String, Vec,
 };
 
 fn setup<'a>() -> (Env, RelayRegistryContractClient<'a>, Address) {
@@ -23,10 +29,68 @@ fn setup<'a>() -> (Env, RelayRegistryContractClient<'a>, Address) {
         threshold: 1,
     };
 
-    client.initialize(&council, &100i128, &10u32);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.initialize(&council, &token, &100i128, &10u32, &LockUnit::Seconds);
     (env, client, admin)
 }
 
+/// Admin client of the SAC token the registry was initialized with, for minting test stake.
+fn staking_token<'a>(client: &RelayRegistryContractClient<'a>) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(&client.env, &client.get_config().token)
+}
+
+/// Deploy a treasury in the staking token and point the registry's slashing at it.
+fn setup_treasury<'a>(
+    client: &RelayRegistryContractClient<'a>,
+    admin: &Address,
+) -> treasury::TreasuryContractClient<'a> {
+    let env = &client.env;
+    let treasury_id = env.register(treasury::TreasuryContract, ());
+    let treasury_client = treasury::TreasuryContractClient::new(env, &treasury_id);
+    let council = treasury::types::AdminCouncil {
+        members: Vec::from_array(env, [admin.clone()]),
+        threshold: 1,
+    };
+    treasury_client.initialize(&council, &client.get_config().token);
+    council_execute(client, admin, &ProposalAction::SetTreasury(treasury_id));
+    treasury_client
+}
+
+/// Propose `action` as `admin` and execute it (single-member council), waiting
+/// out the timelock if the action is queued.
+fn council_execute(client: &RelayRegistryContractClient, admin: &Address, action: &ProposalAction) {
+    let proposal_id = client.propose(admin, action);
+    client.execute(&proposal_id);
+    if client.get_proposal(&proposal_id).status == ProposalStatus::Queued {
+        execute_after_timelock(&client.env, client, proposal_id);
+    }
+}
+
+/// Advance past the timelock delay and apply an already queued proposal.
+fn execute_after_timelock(env: &Env, client: &RelayRegistryContractClient, proposal_id: u64) {
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    client.execute(&proposal_id);
+}
+
+/// Queue a proposal, advance past the timelock delay and try to apply it.
+fn try_execute_after_timelock(
+    env: &Env,
+    client: &RelayRegistryContractClient,
+    proposal_id: u64,
+) -> Result<(), ContractError> {
+    client.execute(&proposal_id);
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    match client.try_execute(&proposal_id) {
+        Ok(_) => Ok(()),
+        Err(Ok(err)) => Err(err),
+        Err(Err(err)) => panic!("unexpected invoke error: {:?}", err),
+    }
+}
+
 #[test]
 fn test_update_metadata_success() {
     let (env, client, _) = setup();
@@ -35,6 +99,10 @@ fn test_update_metadata_success() {
         region: String::from_str(&env, "us-east"),
         capacity: 1000,
         uptime_commitment: 99,
+        endpoint: None,
+        software_version: None,
+        transports: Vec::new(&env),
+        contact_hash: None,
     };
 
     client.register(&node_addr, &initial_metadata);
@@ -49,6 +117,10 @@ fn test_update_metadata_success() {
         region: String::from_str(&env, "eu-west"),
         capacity: 2000,
         uptime_commitment: 98,
+        endpoint: None,
+        software_version: None,
+        transports: Vec::new(&env),
+        contact_hash: None,
     };
 
     client.update_metadata(&node_addr, &new_metadata);
@@ -70,6 +142,10 @@ fn test_update_metadata_preserves_status_and_stake() {
         region: String::from_str(&env, "us-east"),
         capacity: 1000,
         uptime_commitment: 99,
+        endpoint: None,
+        software_version: None,
+        transports: Vec::new(&env),
+        contact_hash: None,
     };
 
     client.register(&node_addr, &metadata);
@@ -81,6 +157,10 @@ fn test_update_metadata_preserves_status_and_stake() {
         region: String::from_str(&env, "eu-west"),
         capacity: 2000,
         uptime_commitment: 98,
+        endpoint: None,
+        software_version: None,
+        transports: Vec::new(&env),
+        contact_hash: None,
     };
     client.update_metadata(&node_addr, &new_metadata);
 
@@ -98,6 +178,10 @@ fn test_update_metadata_not_registered() {
         region: String::from_str(&env, "eu-west"),
         capacity: 2000,
         uptime_commitment: 98,
+        endpoint: None,
+        software_version: None,
+        transports: Vec::new(&env),
+        contact_hash: None,
     };
 
     client.update_metadata(&node_addr, &new_metadata);
@@ -112,6 +196,10 @@ fn test_update_metadata_invalid_commitment() {
         region: String::from_str(&env, "us-east"),
         capacity: 1000,
         uptime_commitment: 99,
+        endpoint: None,
+        software_version: None,
+        transports: Vec::new(&env),
+        contact_hash: None,
     };
 
     client.register(&node_addr, &metadata);
@@ -120,6 +208,10 @@ fn test_update_metadata_invalid_commitment() {
         region: String::from_str(&env, "eu-west"),
         capacity: 2000,
         uptime_commitment: 105, // > 100
+        endpoint: None,
+        software_version: None,
+        transports: Vec::new(&env),
+        contact_hash: None,
     };
 
     client.update_metadata(&node_addr, &new_metadata);
@@ -134,6 +226,10 @@ fn test_update_metadata_region_too_long() {
         region: String::from_str(&env, "us-east"),
         capacity: 1000,
         uptime_commitment: 99,
+        endpoint: None,
+        software_version: None,
+        transports: Vec::new(&env),
+        contact_hash: None,
     };
 
     client.register(&node_addr, &metadata);
@@ -145,11 +241,60 @@ fn test_update_metadata_region_too_long() {
         region: String::from_str(&env, long_region),
         capacity: 2000,
         uptime_commitment: 100,
+        endpoint: None,
+        software_version: None,
+        transports: Vec::new(&env),
+        contact_hash: None,
     };
 
     client.update_metadata(&node_addr, &new_metadata);
 }
 
+#[test]
+fn test_register_and_update_metadata_validate_alike() {
+    let (env, client, _) = setup();
+    let node_addr = Address::generate(&env);
+    let valid = NodeMetadata {
+        region: String::from_str(&env, "us-east"),
+        capacity: 1000,
+        uptime_commitment: 99,
+        endpoint: Some(String::from_str(&env, "lora://868.1/sf7")),
+        software_version: Some(String::from_str(&env, "1.4.2")),
+        transports: Vec::from_array(&env, [Transport::Ble, Transport::LoRa]),
+        contact_hash: Some(BytesN::from_array(&env, &[7; 32])),
+    };
+
+    let mut long_region = valid.clone();
+    long_region.region = String::from_str(&env, "this-region-string-is-too-long-xx");
+    let mut empty_endpoint = valid.clone();
+    empty_endpoint.endpoint = Some(String::from_str(&env, ""));
+    let mut long_version = valid.clone();
+    long_version.software_version = Some(String::from_str(&env, &"9".repeat(33)));
+    let mut repeated_transport = valid.clone();
+    repeated_transport.transports.push_back(Transport::Ble);
+
+    let invalid = [
+        long_region,
+        empty_endpoint,
+        long_version,
+        repeated_transport,
+    ];
+    for metadata in invalid.iter() {
+        assert_eq!(
+            client.try_register(&node_addr, metadata),
+            Err(Ok(ContractError::InvalidMetadata))
+        );
+    }
+    client.register(&node_addr, &valid);
+    assert_eq!(client.get_node(&node_addr).metadata, valid);
+    for metadata in invalid.iter() {
+        assert_eq!(
+            client.try_update_metadata(&node_addr, metadata),
+            Err(Ok(ContractError::InvalidMetadata))
+        );
+    }
+}
+
 #[test]
 #[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
 fn test_update_metadata_auth_required_clean() {
@@ -169,7 +314,13 @@ fn test_update_metadata_auth_required_clean() {
     // we just don't call `mock_all_auths` and let it panic on `initialize` because
     // `require_auth` isn't called in `initialize`!
     // Wait, `initialize` does not call `require_auth`!
-    client.initialize(&council, &100i128, &10u32);
+    client.initialize(
+        &council,
+        &Address::generate(&env),
+        &100i128,
+        &10u32,
+        &LockUnit::Seconds,
+    );
 
     let node_addr = Address::generate(&env);
 
@@ -180,6 +331,10 @@ fn test_update_metadata_auth_required_clean() {
         region: String::from_str(&env, "eu-west"),
         capacity: 2000,
         uptime_commitment: 98,
+        endpoint: None,
+        software_version: None,
+        transports: Vec::new(&env),
+        contact_hash: None,
     };
     client.update_metadata(&node_addr, &new_metadata);
 }
@@ -188,26 +343,17 @@ fn test_update_metadata_auth_required_clean() {
 fn test_unstake_creates_lock_entry() {
     let (env, client, _admin) = setup();
 
-    // We must deploy a deterministic token contract and initialize it for stakes
-    let token_admin = Address::generate(&env);
-    let token_contract = env.register_stellar_asset_contract_v2(token_admin.clone());
-    let token_client = token::StellarAssetClient::new(&env, &token_contract.address());
-    let token_address = token_client.address.clone();
-
-    // Set the token address in the registry manually by calling the storage helper directly
-    // because the client's initialize() in this contract version didn't natively take a token address.
-    // However, wait, in our setup we don't have access to storage helpers from the test if we only use the client?
-    // Let's look at `storage::set_token_address(&env, &token_address)`
-    // It's a public function.
-    env.as_contract(&client.address, || {
-        relay_registry::storage::set_token_address(&env, &token_address);
-    });
+    let token_client = staking_token(&client);
 
     let node_addr = Address::generate(&env);
     let metadata = NodeMetadata {
         region: String::from_str(&env, "us-east"),
         capacity: 1000,
         uptime_commitment: 99,
+        endpoint: None,
+        software_version: None,
+        transports: Vec::new(&env),
+        contact_hash: None,
     };
 
     // Mint tokens to the node so it can stake
@@ -236,20 +382,17 @@ fn test_unstake_creates_lock_entry() {
 fn test_finalize_unstake_success_after_lock() {
     let (env, client, _admin) = setup();
 
-    let token_admin = Address::generate(&env);
-    let token_contract = env.register_stellar_asset_contract_v2(token_admin.clone());
-    let token_client = token::StellarAssetClient::new(&env, &token_contract.address());
-    let token_address = token_client.address.clone();
-
-    env.as_contract(&client.address, || {
-        relay_registry::storage::set_token_address(&env, &token_address);
-    });
+    let token_client = staking_token(&client);
 
     let node_addr = Address::generate(&env);
     let metadata = NodeMetadata {
         region: String::from_str(&env, "us-east"),
         capacity: 1000,
         uptime_commitment: 99,
+        endpoint: None,
+        software_version: None,
+        transports: Vec::new(&env),
+        contact_hash: None,
     };
 
     token_client.mint(&node_addr, &500);
@@ -258,11 +401,11 @@ fn test_finalize_unstake_success_after_lock() {
     client.stake(&node_addr, &200);
     client.unstake(&node_addr, &50);
 
-    // Advance time past the 10 ledger lock period
+    // Advance time past the 10 second lock period
     env.ledger().with_mut(|l| l.timestamp += 11);
 
     // Node balance should be 300 (500 minted - 200 staked)
-    let token_client_standard = token::Client::new(&env, &token_contract.address());
+    let token_client_standard = token::Client::new(&env, &token_client.address);
     assert_eq!(token_client_standard.balance(&node_addr), 300);
 
     // Finalize
@@ -287,17 +430,9 @@ fn test_finalize_unstake_no_entry() {
 
 #[test]
 fn test_reinstate_node_from_slashed_to_inactive_and_restake() {
-    let (env, client, _admin) = setup();
-
-    // Configure a token contract and set it as the staking token.
-    let token_admin = Address::generate(&env);
-    let token_contract = env.register_stellar_asset_contract_v2(token_admin.clone());
-    let token_client = token::StellarAssetClient::new(&env, &token_contract.address());
-    let token_address = token_client.address.clone();
+    let (env, client, admin) = setup();
 
-    env.as_contract(&client.address, || {
-        relay_registry::storage::set_token_address(&env, &token_address);
-    });
+    let token_client = staking_token(&client);
 
     // Register a node and stake enough to become active.
     let node_addr = Address::generate(&env);
@@ -305,9 +440,14 @@ fn test_reinstate_node_from_slashed_to_inactive_and_restake() {
         region: String::from_str(&env, "us-east"),
         capacity: 1000,
         uptime_commitment: 99,
+        endpoint: None,
+        software_version: None,
+        transports: Vec::new(&env),
+        contact_hash: None,
     };
 
     token_client.mint(&node_addr, &500);
+    let treasury = setup_treasury(&client, &admin);
 
     client.register(&node_addr, &metadata);
     client.stake(&node_addr, &200);
@@ -317,14 +457,27 @@ fn test_reinstate_node_from_slashed_to_inactive_and_restake() {
     assert_eq!(active_node.stake, 200);
 
     // Slash the node via the admin council.
-    client.slash(&node_addr, &String::from_str(&env, "misbehavior"));
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::Slash(
+            node_addr.clone(),
+            storage::FULL_SLASH_REASON,
+            String::from_str(&env, "misbehavior"),
+        ),
+    );
 
     let slashed_node = client.get_node(&node_addr);
     assert_eq!(slashed_node.status, NodeStatus::Slashed);
     assert_eq!(slashed_node.stake, 0);
+    assert_eq!(treasury.get_balance(), 200);
 
     // Reinstate the slashed node back to Inactive.
-    client.reinstate_node(&node_addr);
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::ReinstateNode(node_addr.clone()),
+    );
 
     let reinstated_node = client.get_node(&node_addr);
     assert_eq!(reinstated_node.status, NodeStatus::Inactive);
@@ -341,40 +494,44 @@ fn test_reinstate_node_from_slashed_to_inactive_and_restake() {
 #[test]
 #[should_panic(expected = "Error(Contract, #16)")] // NodeNotSlashed
 fn test_reinstate_node_when_inactive_fails() {
-    let (env, client, _admin) = setup();
+    let (env, client, admin) = setup();
     let node_addr = Address::generate(&env);
     let metadata = NodeMetadata {
         region: String::from_str(&env, "us-east"),
         capacity: 1000,
         uptime_commitment: 99,
+        endpoint: None,
+        software_version: None,
+        transports: Vec::new(&env),
+        contact_hash: None,
     };
 
     client.register(&node_addr, &metadata);
 
     // Node is Inactive by default after registration; reinstatement should fail.
-    client.reinstate_node(&node_addr);
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::ReinstateNode(node_addr.clone()),
+    );
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #16)")] // NodeNotSlashed
 fn test_reinstate_node_when_active_fails() {
-    let (env, client, _admin) = setup();
-
-    // Configure token for staking.
-    let token_admin = Address::generate(&env);
-    let token_contract = env.register_stellar_asset_contract_v2(token_admin.clone());
-    let token_client = token::StellarAssetClient::new(&env, &token_contract.address());
-    let token_address = token_client.address.clone();
+    let (env, client, admin) = setup();
 
-    env.as_contract(&client.address, || {
-        relay_registry::storage::set_token_address(&env, &token_address);
-    });
+    let token_client = staking_token(&client);
 
     let node_addr = Address::generate(&env);
     let metadata = NodeMetadata {
         region: String::from_str(&env, "us-east"),
         capacity: 1000,
         uptime_commitment: 99,
+        endpoint: None,
+        software_version: None,
+        transports: Vec::new(&env),
+        contact_hash: None,
     };
 
     token_client.mint(&node_addr, &500);
@@ -383,15 +540,2000 @@ fn test_reinstate_node_when_active_fails() {
     client.stake(&node_addr, &200);
 
     // Node is Active; reinstatement should fail.
-    client.reinstate_node(&node_addr);
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::ReinstateNode(node_addr.clone()),
+    );
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #2)")] // NotRegistered
 fn test_reinstate_node_not_registered_fails() {
-    let (env, client, _admin) = setup();
+    let (env, client, admin) = setup();
     let node_addr = Address::generate(&env);
 
     // Node was never registered; should fail with NotRegistered.
-    client.reinstate_node(&node_addr);
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::ReinstateNode(node_addr.clone()),
+    );
+}
+
+fn setup_council<'a>(
+    size: u32,
+    threshold: u32,
+) -> (Env, RelayRegistryContractClient<'a>, Vec<Address>) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(RelayRegistryContract, ());
+    let client = RelayRegistryContractClient::new(&env, &contract_id);
+
+    let mut members = Vec::new(&env);
+    for _ in 0..size {
+        members.push_back(Address::generate(&env));
+    }
+    let council = AdminCouncil {
+        members: members.clone(),
+        threshold,
+    };
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.initialize(&council, &token, &100i128, &10u32, &LockUnit::Seconds);
+    (env, client, members)
+}
+
+fn register_node(env: &Env, client: &RelayRegistryContractClient) -> Address {
+    let node_addr = Address::generate(env);
+    let metadata = NodeMetadata {
+        region: String::from_str(env, "us-east"),
+        capacity: 1000,
+        uptime_commitment: 99,
+        endpoint: None,
+        software_version: None,
+        transports: Vec::new(env),
+        contact_hash: None,
+    };
+    client.register(&node_addr, &metadata);
+    node_addr
+}
+
+#[test]
+fn test_proposal_executes_with_any_threshold_subset() {
+    let (env, client, members) = setup_council(3, 2);
+    let node_addr = register_node(&env, &client);
+
+    // The last two members approve; the first listed member never signs.
+    let proposer = members.get(2).unwrap();
+    let proposal_id = client.propose(
+        &proposer,
+        &ProposalAction::Slash(
+            node_addr.clone(),
+            storage::FULL_SLASH_REASON,
+            String::from_str(&env, "misbehavior"),
+        ),
+    );
+    assert_eq!(
+        client.try_execute(&proposal_id),
+        Err(Ok(ContractError::InsufficientApprovals))
+    );
+
+    client.approve(&members.get(1).unwrap(), &proposal_id);
+    client.execute(&proposal_id);
+    execute_after_timelock(&env, &client, proposal_id);
+
+    assert_eq!(client.get_node(&node_addr).status, NodeStatus::Slashed);
+    let proposal = client.get_proposal(&proposal_id);
+    assert_eq!(proposal.status, ProposalStatus::Executed);
+    assert_eq!(proposal.approvals.len(), 2);
+    assert_eq!(
+        client.try_execute(&proposal_id),
+        Err(Ok(ContractError::ProposalNotPending))
+    );
+}
+
+#[test]
+fn test_propose_non_member_fails() {
+    let (env, client, _) = setup_council(2, 2);
+    let outsider = Address::generate(&env);
+    let res = client.try_propose(&outsider, &ProposalAction::ReinstateNode(outsider.clone()));
+    assert_eq!(res, Err(Ok(ContractError::NotCouncilMember)));
+}
+
+#[test]
+fn test_approve_twice_fails() {
+    let (env, client, members) = setup_council(3, 3);
+    let node_addr = register_node(&env, &client);
+    let proposer = members.get(0).unwrap();
+    let proposal_id = client.propose(&proposer, &ProposalAction::ReinstateNode(node_addr));
+
+    let res = client.try_approve(&proposer, &proposal_id);
+    assert_eq!(res, Err(Ok(ContractError::AlreadyApproved)));
+}
+
+#[test]
+fn test_proposal_expires() {
+    let (env, client, members) = setup_council(2, 2);
+    let node_addr = register_node(&env, &client);
+    let proposal_id = client.propose(
+        &members.get(0).unwrap(),
+        &ProposalAction::ReinstateNode(node_addr),
+    );
+
+    let expires_at = client.get_proposal(&proposal_id).expires_at;
+    env.ledger()
+        .with_mut(|l| l.sequence_number = expires_at as u32 + 1);
+
+    let res = client.try_approve(&members.get(1).unwrap(), &proposal_id);
+    assert_eq!(res, Err(Ok(ContractError::ProposalExpired)));
+}
+
+#[test]
+fn test_cancel_proposal() {
+    let (env, client, members) = setup_council(2, 2);
+    let node_addr = register_node(&env, &client);
+    let proposer = members.get(0).unwrap();
+    let proposal_id = client.propose(&proposer, &ProposalAction::ReinstateNode(node_addr));
+
+    let res = client.try_cancel(&members.get(1).unwrap(), &proposal_id);
+    assert_eq!(res, Err(Ok(ContractError::Unauthorized)));
+
+    client.cancel(&proposer, &proposal_id);
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        ProposalStatus::Cancelled
+    );
+
+    let res = client.try_approve(&members.get(1).unwrap(), &proposal_id);
+    assert_eq!(res, Err(Ok(ContractError::ProposalNotPending)));
+}
+
+#[test]
+fn test_add_and_remove_council_member() {
+    let (env, client, members) = setup_council(2, 2);
+    let proposer = members.get(0).unwrap();
+    let approver = members.get(1).unwrap();
+    let new_member = Address::generate(&env);
+
+    let proposal_id = client.propose(&proposer, &ProposalAction::AddMember(new_member.clone()));
+    client.approve(&approver, &proposal_id);
+    client.execute(&proposal_id);
+    execute_after_timelock(&env, &client, proposal_id);
+
+    // The new member can now take part in proposals.
+    let node_addr = register_node(&env, &client);
+    let proposal_id = client.propose(
+        &new_member,
+        &ProposalAction::Slash(
+            node_addr.clone(),
+            storage::FULL_SLASH_REASON,
+            String::from_str(&env, "misbehavior"),
+        ),
+    );
+    client.approve(&approver, &proposal_id);
+    client.execute(&proposal_id);
+    execute_after_timelock(&env, &client, proposal_id);
+    assert_eq!(client.get_node(&node_addr).status, NodeStatus::Slashed);
+
+    let proposal_id = client.propose(&new_member, &ProposalAction::RemoveMember(proposer.clone()));
+    client.approve(&approver, &proposal_id);
+    client.execute(&proposal_id);
+    execute_after_timelock(&env, &client, proposal_id);
+
+    let res = client.try_propose(&proposer, &ProposalAction::SetThreshold(1));
+    assert_eq!(res, Err(Ok(ContractError::NotCouncilMember)));
+}
+
+#[test]
+fn test_replace_member_drops_old_approvals() {
+    let (env, client, members) = setup_council(3, 2);
+    let lost_key = members.get(0).unwrap();
+    let replacement = Address::generate(&env);
+    let node_addr = register_node(&env, &client);
+
+    // A proposal approved only by the member who is about to be replaced.
+    let stale_id = client.propose(&lost_key, &ProposalAction::ReinstateNode(node_addr));
+
+    let proposal_id = client.propose(
+        &members.get(1).unwrap(),
+        &ProposalAction::ReplaceMember(lost_key.clone(), replacement.clone()),
+    );
+    client.approve(&members.get(2).unwrap(), &proposal_id);
+    client.execute(&proposal_id);
+    execute_after_timelock(&env, &client, proposal_id);
+
+    client.approve(&replacement, &stale_id);
+    assert_eq!(
+        client.try_execute(&stale_id),
+        Err(Ok(ContractError::InsufficientApprovals))
+    );
+}
+
+#[test]
+fn test_set_threshold_above_member_count_fails() {
+    let (env, client, members) = setup_council(2, 1);
+    let proposal_id = client.propose(&members.get(0).unwrap(), &ProposalAction::SetThreshold(3));
+    assert_eq!(
+        try_execute_after_timelock(&env, &client, proposal_id),
+        Err(ContractError::InvalidCouncilConfig)
+    );
+
+    let proposal_id = client.propose(&members.get(0).unwrap(), &ProposalAction::SetThreshold(2));
+    client.execute(&proposal_id);
+    execute_after_timelock(&env, &client, proposal_id);
+
+    let proposal_id = client.propose(
+        &members.get(0).unwrap(),
+        &ProposalAction::RemoveMember(members.get(1).unwrap()),
+    );
+    client.approve(&members.get(1).unwrap(), &proposal_id);
+    assert_eq!(
+        try_execute_after_timelock(&env, &client, proposal_id),
+        Err(ContractError::InvalidCouncilConfig)
+    );
+}
+
+#[test]
+fn test_council_member_cap() {
+    let (env, client, members) = setup_council(10, 1);
+    let proposal_id = client.propose(
+        &members.get(0).unwrap(),
+        &ProposalAction::AddMember(Address::generate(&env)),
+    );
+    assert_eq!(
+        try_execute_after_timelock(&env, &client, proposal_id),
+        Err(ContractError::InvalidCouncilConfig)
+    );
+}
+
+#[test]
+fn test_initialize_rejects_oversized_or_duplicate_council() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(RelayRegistryContract, ());
+    let client = RelayRegistryContractClient::new(&env, &contract_id);
+
+    let mut members = Vec::new(&env);
+    for _ in 0..11 {
+        members.push_back(Address::generate(&env));
+    }
+    let council = AdminCouncil {
+        members,
+        threshold: 1,
+    };
+    assert_eq!(
+        client.try_initialize(
+            &council,
+            &Address::generate(&env),
+            &100i128,
+            &10u32,
+            &LockUnit::Seconds
+        ),
+        Err(Ok(ContractError::InvalidCouncilConfig))
+    );
+
+    let member = Address::generate(&env);
+    let council = AdminCouncil {
+        members: Vec::from_array(&env, [member.clone(), member]),
+        threshold: 2,
+    };
+    assert_eq!(
+        client.try_initialize(
+            &council,
+            &Address::generate(&env),
+            &100i128,
+            &10u32,
+            &LockUnit::Seconds
+        ),
+        Err(Ok(ContractError::InvalidCouncilConfig))
+    );
+}
+
+#[test]
+fn test_version_recorded_on_initialize() {
+    let (_env, client, _) = setup();
+    assert_eq!(client.version(), storage::SCHEMA_VERSION);
+}
+
+#[test]
+fn test_migrate_is_noop_when_up_to_date() {
+    let (_env, client, _) = setup();
+    assert_eq!(client.migrate(), storage::SCHEMA_VERSION);
+    assert_eq!(client.migrate(), storage::SCHEMA_VERSION);
+    assert_eq!(client.version(), storage::SCHEMA_VERSION);
+}
+
+#[test]
+fn test_migrate_rejects_newer_schema() {
+    let (env, client, _) = setup();
+    env.as_contract(&client.address, || {
+        storage::set_schema_version(&env, storage::SCHEMA_VERSION + 1)
+    });
+    assert_eq!(
+        client.try_migrate(),
+        Err(Ok(ContractError::UnsupportedSchemaVersion))
+    );
+}
+
+#[test]
+fn test_upgrade_requires_council_proposal() {
+    let (env, client, admin) = setup();
+    let wasm_hash = BytesN::from_array(&env, &[7u8; 32]);
+
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_propose(&outsider, &ProposalAction::Upgrade(wasm_hash.clone())),
+        Err(Ok(ContractError::NotCouncilMember))
+    );
+
+    // An upgrade is queued first, so guardians can veto it during the delay.
+    let proposal_id = client.propose(&admin, &ProposalAction::Upgrade(wasm_hash));
+    client.execute(&proposal_id);
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        ProposalStatus::Queued
+    );
+    assert_eq!(
+        client.try_execute(&proposal_id),
+        Err(Ok(ContractError::TimelockActive))
+    );
+
+    // The hash was never uploaded, so the host rejects the swap and the
+    // proposal stays queued.
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    assert!(client.try_execute(&proposal_id).is_err());
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        ProposalStatus::Queued
+    );
+}
+
+#[test]
+fn test_pause_stake_still_allows_finalize_unstake() {
+    let (env, client, admin) = setup();
+
+    let token_client = staking_token(&client);
+
+    let node_addr = register_node(&env, &client);
+    token_client.mint(&node_addr, &500);
+    client.stake(&node_addr, &200);
+    client.unstake(&node_addr, &50);
+
+    let halted = Vec::from_array(&env, [PausableFn::Stake, PausableFn::Unstake]);
+    client.pause(&admin, &halted);
+    assert!(client.is_paused(&PausableFn::Stake));
+    assert!(!client.is_paused(&PausableFn::FinalizeUnstake));
+    assert_eq!(
+        client.try_stake(&node_addr, &100),
+        Err(Ok(ContractError::Paused))
+    );
+    assert_eq!(
+        client.try_unstake(&node_addr, &50),
+        Err(Ok(ContractError::Paused))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp += 11);
+    assert_eq!(client.finalize_unstake(&node_addr), 50);
+
+    council_execute(&client, &admin, &ProposalAction::Unpause(halted));
+    assert!(!client.is_paused(&PausableFn::Stake));
+    client.stake(&node_addr, &100);
+}
+
+#[test]
+fn test_pause_covers_operator_entrypoints() {
+    let (env, client, admin) = setup();
+    let node_addr = register_node(&env, &client);
+    let new_addr = Address::generate(&env);
+    let key = BytesN::from_array(&env, &[1u8; 32]);
+    let signature = BytesN::from_array(&env, &[2u8; 64]);
+
+    let halted = Vec::from_array(
+        &env,
+        [
+            PausableFn::ProposeTransfer,
+            PausableFn::CancelTransfer,
+            PausableFn::SetCommission,
+            PausableFn::Heartbeat,
+            PausableFn::RegisterSigningKey,
+            PausableFn::RotateSigningKey,
+        ],
+    );
+    client.pause(&admin, &halted);
+
+    assert_eq!(
+        client.try_propose_transfer(&node_addr, &new_addr),
+        Err(Ok(ContractError::Paused))
+    );
+    assert_eq!(
+        client.try_cancel_transfer(&node_addr),
+        Err(Ok(ContractError::Paused))
+    );
+    assert_eq!(
+        client.try_set_commission(&node_addr, &500),
+        Err(Ok(ContractError::Paused))
+    );
+    assert_eq!(
+        client.try_heartbeat(&node_addr),
+        Err(Ok(ContractError::Paused))
+    );
+    assert_eq!(
+        client.try_register_signing_key(&node_addr, &key, &signature),
+        Err(Ok(ContractError::Paused))
+    );
+    assert_eq!(
+        client.try_rotate_signing_key(&node_addr, &key, &signature),
+        Err(Ok(ContractError::Paused))
+    );
+
+    council_execute(&client, &admin, &ProposalAction::Unpause(halted));
+    client.set_commission(&node_addr, &500);
+    client.heartbeat(&node_addr);
+    client.propose_transfer(&node_addr, &new_addr);
+    client.cancel_transfer(&node_addr);
+}
+
+#[test]
+fn test_pause_requires_council_member() {
+    let (env, client, _) = setup();
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_pause(&outsider, &Vec::from_array(&env, [PausableFn::Register])),
+        Err(Ok(ContractError::NotCouncilMember))
+    );
+    assert!(!client.is_paused(&PausableFn::Register));
+}
+
+#[test]
+fn test_slash_is_timelocked_and_cancellable() {
+    let (env, client, admin) = setup();
+    let node_addr = register_node(&env, &client);
+
+    let proposal_id = client.propose(
+        &admin,
+        &ProposalAction::Slash(
+            node_addr.clone(),
+            storage::FULL_SLASH_REASON,
+            String::from_str(&env, "misbehavior"),
+        ),
+    );
+    client.execute(&proposal_id);
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        ProposalStatus::Queued
+    );
+    assert_eq!(
+        client.try_execute(&proposal_id),
+        Err(Ok(ContractError::TimelockActive))
+    );
+    assert_eq!(client.get_node(&node_addr).status, NodeStatus::Inactive);
+
+    council_execute(&client, &admin, &ProposalAction::CancelQueued(proposal_id));
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    assert_eq!(
+        client.try_execute(&proposal_id),
+        Err(Ok(ContractError::ProposalNotPending))
+    );
+    assert_eq!(client.get_node(&node_addr).status, NodeStatus::Inactive);
+}
+
+#[test]
+fn test_get_stake_reports_node_stake() {
+    let (env, client, _) = setup();
+    let token_client = staking_token(&client);
+
+    let node_addr = register_node(&env, &client);
+    assert_eq!(client.get_stake(&node_addr), 0);
+    token_client.mint(&node_addr, &500);
+    client.stake(&node_addr, &200);
+    assert_eq!(client.get_stake(&node_addr), 200);
+    assert_eq!(client.get_stake(&Address::generate(&env)), 0);
+}
+
+#[test]
+fn test_get_bonded_stake_at_reads_stake_history() {
+    let (env, client, _) = setup();
+    let token_client = staking_token(&client);
+    let node_addr = register_node(&env, &client);
+    let delegator = Address::generate(&env);
+    token_client.mint(&node_addr, &500);
+    token_client.mint(&delegator, &500);
+    env.ledger().with_mut(|l| l.sequence_number += 10);
+
+    let staked_at = env.ledger().sequence();
+    client.stake(&node_addr, &200);
+    env.ledger().with_mut(|l| l.sequence_number += 10);
+    let delegated_at = env.ledger().sequence();
+    client.delegate(&delegator, &node_addr, &100);
+    // A second change in the same ledger replaces that ledger's checkpoint.
+    client.stake(&node_addr, &50);
+    env.ledger().with_mut(|l| l.sequence_number += 10);
+
+    assert_eq!(client.get_bonded_stake_at(&node_addr, &(staked_at - 1)), 0);
+    assert_eq!(client.get_bonded_stake_at(&node_addr, &staked_at), 200);
+    assert_eq!(
+        client.get_bonded_stake_at(&node_addr, &(delegated_at - 1)),
+        200
+    );
+    assert_eq!(client.get_bonded_stake_at(&node_addr, &delegated_at), 350);
+    assert_eq!(
+        client.get_bonded_stake_at(&node_addr, &env.ledger().sequence()),
+        350
+    );
+    assert_eq!(
+        client.get_bonded_stake_at(&Address::generate(&env), &delegated_at),
+        0
+    );
+}
+
+#[test]
+fn test_get_bonded_stake_at_covers_stake_bonded_before_checkpoints() {
+    let (env, client, _) = setup();
+    let token_client = staking_token(&client);
+    let node_addr = register_node(&env, &client);
+    token_client.mint(&node_addr, &500);
+    client.stake(&node_addr, &200);
+
+    // Drop the history, as for a node bonded before checkpoints were kept.
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .remove(&storage::DataKey::StakeCheckpoint(node_addr.clone(), 1));
+        env.storage()
+            .persistent()
+            .remove(&storage::DataKey::StakeCheckpointCount(node_addr.clone()));
+    });
+    env.ledger().with_mut(|l| l.sequence_number += 10);
+    let before = env.ledger().sequence() - 1;
+    assert_eq!(client.get_bonded_stake_at(&node_addr, &before), 200);
+
+    client.stake(&node_addr, &100);
+    assert_eq!(client.get_bonded_stake_at(&node_addr, &before), 200);
+    assert_eq!(
+        client.get_bonded_stake_at(&node_addr, &env.ledger().sequence()),
+        300
+    );
+}
+
+#[test]
+fn test_slasher_role_skips_council_threshold() {
+    let (env, client, members) = setup_council(3, 2);
+    let slasher = Address::generate(&env);
+    let node_addr = register_node(&env, &client);
+
+    let grant_id = client.propose(
+        &members.get(0).unwrap(),
+        &ProposalAction::GrantRole(Role::Slasher, slasher.clone()),
+    );
+    client.approve(&members.get(1).unwrap(), &grant_id);
+    client.execute(&grant_id);
+    execute_after_timelock(&env, &client, grant_id);
+    assert!(client.has_role(&Role::Slasher, &slasher));
+    assert_eq!(
+        client.get_role_holders(&Role::Slasher),
+        Vec::from_array(&env, [slasher.clone()])
+    );
+
+    // The slasher can act alone, but the slash still waits out the timelock.
+    let slash_id = client.propose(
+        &slasher,
+        &ProposalAction::Slash(
+            node_addr.clone(),
+            storage::FULL_SLASH_REASON,
+            String::from_str(&env, "misbehavior"),
+        ),
+    );
+    client.execute(&slash_id);
+    assert_eq!(
+        client.get_proposal(&slash_id).status,
+        ProposalStatus::Queued
+    );
+    execute_after_timelock(&env, &client, slash_id);
+    assert_eq!(client.get_node(&node_addr).status, NodeStatus::Slashed);
+
+    // Roles cover only their own actions.
+    assert_eq!(
+        client.try_propose(&slasher, &ProposalAction::SetThreshold(1)),
+        Err(Ok(ContractError::NotCouncilMember))
+    );
+
+    // A pending proposal falls back to the council threshold once the role is revoked.
+    let reinstate_id = client.propose(&slasher, &ProposalAction::ReinstateNode(node_addr.clone()));
+    let revoke_id = client.propose(
+        &members.get(0).unwrap(),
+        &ProposalAction::RevokeRole(Role::Slasher, slasher.clone()),
+    );
+    client.approve(&members.get(1).unwrap(), &revoke_id);
+    client.execute(&revoke_id);
+    assert!(!client.has_role(&Role::Slasher, &slasher));
+    assert_eq!(
+        client.try_execute(&reinstate_id),
+        Err(Ok(ContractError::InsufficientApprovals))
+    );
+
+    let revoke_again_id = client.propose(
+        &members.get(0).unwrap(),
+        &ProposalAction::RevokeRole(Role::Slasher, slasher.clone()),
+    );
+    client.approve(&members.get(1).unwrap(), &revoke_again_id);
+    assert_eq!(
+        client.try_execute(&revoke_again_id),
+        Err(Ok(ContractError::RoleNotHeld))
+    );
+}
+
+#[test]
+fn test_guardian_vetoes_queued_slash() {
+    let (env, client, admin) = setup();
+    let guardian = Address::generate(&env);
+    let node_addr = register_node(&env, &client);
+
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::SetGuardians(Vec::from_array(&env, [guardian.clone()])),
+    );
+    assert_eq!(
+        client.get_guardians(),
+        Vec::from_array(&env, [guardian.clone()])
+    );
+
+    let slash = ProposalAction::Slash(
+        node_addr.clone(),
+        storage::FULL_SLASH_REASON,
+        String::from_str(&env, "misbehavior"),
+    );
+    let proposal_id = client.propose(&admin, &slash);
+    client.execute(&proposal_id);
+
+    let reason = String::from_str(&env, "council key compromised");
+    assert_eq!(
+        client.try_veto(&admin, &proposal_id, &reason),
+        Err(Ok(ContractError::NotGuardian))
+    );
+    assert_eq!(
+        client.try_veto(&guardian, &proposal_id, &String::from_str(&env, "")),
+        Err(Ok(ContractError::InvalidVetoReason))
+    );
+    client.veto(&guardian, &proposal_id, &reason);
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        ProposalStatus::Vetoed
+    );
+    let veto = client.get_veto(&proposal_id).unwrap();
+    assert_eq!(veto.guardian, guardian);
+    assert_eq!(veto.reason, reason);
+
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    assert_eq!(
+        client.try_execute(&proposal_id),
+        Err(Ok(ContractError::ProposalNotPending))
+    );
+    assert_eq!(client.get_node(&node_addr).status, NodeStatus::Inactive);
+
+    // The veto window closes once a queued action reaches its eta.
+    let late_id = client.propose(&admin, &slash);
+    client.execute(&late_id);
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    assert_eq!(
+        client.try_veto(&guardian, &late_id, &reason),
+        Err(Ok(ContractError::VetoWindowClosed))
+    );
+    client.execute(&late_id);
+    assert_eq!(client.get_node(&node_addr).status, NodeStatus::Slashed);
+    assert_eq!(client.get_veto(&late_id), None);
+}
+
+#[test]
+fn test_action_log_records_slash_and_reinstate() {
+    let (env, client, members) = setup_council(3, 2);
+    let node_addr = register_node(&env, &client);
+    let proposer = members.get(0).unwrap();
+    let approver = members.get(2).unwrap();
+
+    let slash = ProposalAction::Slash(
+        node_addr.clone(),
+        storage::FULL_SLASH_REASON,
+        String::from_str(&env, "double relay"),
+    );
+    let slash_id = client.propose(&proposer, &slash);
+    client.approve(&approver, &slash_id);
+    client.execute(&slash_id);
+    assert_eq!(client.get_action_log_count(), 0);
+    execute_after_timelock(&env, &client, slash_id);
+
+    let reinstate = ProposalAction::ReinstateNode(node_addr.clone());
+    let reinstate_id = client.propose(&proposer, &reinstate);
+    client.approve(&approver, &reinstate_id);
+    client.execute(&reinstate_id);
+
+    assert_eq!(client.get_action_log_count(), 2);
+    let log = client.get_action_log(&1, &10);
+    assert_eq!(log.len(), 2);
+    let first = log.get(0).unwrap();
+    assert_eq!(first.id, 1);
+    assert_eq!(first.proposal_id, slash_id);
+    assert_eq!(first.action, slash);
+    assert_eq!(
+        first.approvals,
+        Vec::from_array(&env, [proposer.clone(), approver.clone()])
+    );
+    assert_eq!(first.ledger, env.ledger().sequence() as u64);
+    assert_eq!(log.get(1).unwrap().action, reinstate);
+
+    let page = client.get_action_log(&2, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().proposal_id, reinstate_id);
+    assert!(client.get_action_log(&3, &10).is_empty());
+}
+
+#[test]
+fn test_get_config_and_stake_token_migration() {
+    let (env, client, admin) = setup();
+    let config = client.get_config();
+    assert_eq!(config.min_stake, 100);
+    assert_eq!(config.stake_lock_period, 10);
+    assert_eq!(
+        config.council.members,
+        Vec::from_array(&env, [admin.clone()])
+    );
+
+    let old_token = staking_token(&client);
+    let node_addr = register_node(&env, &client);
+    old_token.mint(&node_addr, &500);
+    client.stake(&node_addr, &200);
+
+    let new_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let switch_id = client.propose(&admin, &ProposalAction::SetStakeToken(new_token.clone()));
+    client.execute(&switch_id);
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    assert_eq!(
+        client.try_execute(&switch_id),
+        Err(Ok(ContractError::StakeOutstanding))
+    );
+
+    // Once every node has withdrawn, the council can move staking to the new
+    // token, even if someone sent the registry a stroop of the old one.
+    client.unstake(&node_addr, &200);
+    env.ledger().with_mut(|l| l.timestamp += 10);
+    client.finalize_unstake(&node_addr);
+    old_token.mint(&client.address, &1);
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::SetStakeToken(new_token.clone()),
+    );
+    assert_eq!(client.get_config().token, new_token);
+
+    staking_token(&client).mint(&node_addr, &150);
+    client.stake(&node_addr, &150);
+    assert_eq!(client.get_stake(&node_addr), 150);
+}
+
+#[test]
+fn test_multiple_unbondings_release_when_matured() {
+    let (env, client, _) = setup();
+    let node_addr = register_node(&env, &client);
+    staking_token(&client).mint(&node_addr, &500);
+    client.stake(&node_addr, &400);
+
+    client.unstake(&node_addr, &50);
+    env.ledger().with_mut(|l| l.timestamp += 5);
+    client.unstake(&node_addr, &70);
+    env.ledger().with_mut(|l| l.timestamp += 3);
+    client.unstake(&node_addr, &30);
+
+    let unbondings = client.get_unbondings(&node_addr);
+    assert_eq!(unbondings.len(), 3);
+    assert_eq!(unbondings.get(0).unwrap().amount, 50);
+    assert_eq!(unbondings.get(2).unwrap().amount, 30);
+    assert_eq!(client.get_stake(&node_addr), 250);
+
+    assert_eq!(
+        client.try_finalize_unstake(&node_addr),
+        Err(Ok(ContractError::LockPeriodActive))
+    );
+
+    // The first two have unlocked; the third stays queued.
+    env.ledger().with_mut(|l| l.timestamp += 7);
+    assert_eq!(client.finalize_unstake(&node_addr), 120);
+    let remaining = client.get_unbondings(&node_addr);
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining.get(0).unwrap().amount, 30);
+
+    env.ledger().with_mut(|l| l.timestamp += 3);
+    assert_eq!(client.finalize_unstake(&node_addr), 30);
+    assert!(client.get_unbondings(&node_addr).is_empty());
+    assert_eq!(
+        token::Client::new(&env, &client.get_config().token).balance(&node_addr),
+        250
+    );
+}
+
+#[test]
+fn test_ledger_lock_unit_and_unlock_etas() {
+    let (env, client, admin) = setup();
+    let node_addr = register_node(&env, &client);
+    staking_token(&client).mint(&node_addr, &500);
+    env.ledger().with_mut(|l| {
+        l.sequence_number = 1_000;
+        l.timestamp = 50_000;
+    });
+
+    // Seconds-based by default: the timestamp is exact, the ledger an estimate.
+    assert_eq!(client.get_config().lock_unit, LockUnit::Seconds);
+    client.stake(&node_addr, &200);
+    client.unstake(&node_addr, &50);
+    assert_eq!(
+        client.get_unlock_etas(&node_addr).get(0).unwrap(),
+        UnlockEta {
+            amount: 50,
+            ledger: 1_002,
+            timestamp: 50_010,
+        }
+    );
+
+    // The unit can change while stake is held; the pending unbonding keeps its own.
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::SetLockUnit(LockUnit::Ledgers, 20),
+    );
+    let config = client.get_config();
+    assert_eq!(config.lock_unit, LockUnit::Ledgers);
+    assert_eq!(config.stake_lock_period, 20);
+    let seconds_entry = client.get_unbondings(&node_addr).get(0).unwrap();
+    assert_eq!(seconds_entry.unit, LockUnit::Seconds);
+    assert_eq!(seconds_entry.unlocks_at, 50_010);
+
+    // Ledger-based locks ignore the timestamp and mature on the sequence number.
+    client.unstake(&node_addr, &30);
+    let ledger = env.ledger().sequence() as u64;
+    let timestamp = env.ledger().timestamp();
+    assert_eq!(
+        client.get_unlock_etas(&node_addr).get(1).unwrap(),
+        UnlockEta {
+            amount: 30,
+            ledger: ledger + 20,
+            timestamp: timestamp + 20 * storage::SECONDS_PER_LEDGER,
+        }
+    );
+    let ledger_entry = client.get_unbondings(&node_addr).get(1).unwrap();
+    assert_eq!(ledger_entry.unit, LockUnit::Ledgers);
+    assert_eq!(ledger_entry.unlocks_at, ledger + 20);
+
+    // The seconds-based unbonding still matures on the timestamp alone.
+    env.ledger().with_mut(|l| l.timestamp = 50_010);
+    assert_eq!(client.finalize_unstake(&node_addr), 50);
+    assert_eq!(
+        client.try_finalize_unstake(&node_addr),
+        Err(Ok(ContractError::LockPeriodActive))
+    );
+    env.ledger().with_mut(|l| l.sequence_number += 20);
+    assert_eq!(client.finalize_unstake(&node_addr), 30);
+}
+
+#[test]
+fn test_legacy_unbondings_keep_their_lock_unit() {
+    let (env, client, admin) = setup();
+    let node_addr = register_node(&env, &client);
+    staking_token(&client).mint(&node_addr, &500);
+    client.stake(&node_addr, &300);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+
+    // Simulate a version 5 queue, which did not record the unit of its entries.
+    env.as_contract(&client.address, || {
+        storage::set_schema_version(&env, 5);
+        let legacy = vec![
+            &env,
+            storage::StakeEntryV5 {
+                address: node_addr.clone(),
+                amount: 40,
+                unlocks_at: 1_010,
+            },
+        ];
+        let key = storage::DataKey::Unbonding(node_addr.clone());
+        env.storage().persistent().set(&key, &legacy);
+        conduit_common::ttl::extend_persistent_ttl(&env, &key);
+    });
+    assert_eq!(client.migrate(), storage::SCHEMA_VERSION);
+
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::SetLockUnit(LockUnit::Ledgers, 20),
+    );
+    let entry = client.get_unbondings(&node_addr).get(0).unwrap();
+    assert_eq!(entry.unit, LockUnit::Seconds);
+    assert_eq!(entry.unlocks_at, 1_010);
+
+    env.ledger().with_mut(|l| l.timestamp = 1_010);
+    assert_eq!(client.finalize_unstake(&node_addr), 40);
+    assert!(client.get_unbondings(&node_addr).is_empty());
+}
+
+#[test]
+fn test_legacy_lock_entry_joins_unbonding_queue() {
+    let (env, client, _) = setup();
+    let node_addr = register_node(&env, &client);
+    staking_token(&client).mint(&node_addr, &500);
+    client.stake(&node_addr, &300);
+
+    // Simulate storage written by a version 1 build holding 40 in a single lock entry.
+    env.as_contract(&client.address, || {
+        storage::set_schema_version(&env, 1);
+        storage::set_lock_entry(
+            &env,
+            &node_addr,
+            &storage::StakeEntryV5 {
+                address: node_addr.clone(),
+                amount: 40,
+                unlocks_at: 0,
+            },
+        );
+    });
+    assert_eq!(client.migrate(), storage::SCHEMA_VERSION);
+
+    client.unstake(&node_addr, &60);
+    let unbondings = client.get_unbondings(&node_addr);
+    assert_eq!(unbondings.len(), 2);
+    assert_eq!(unbondings.get(0).unwrap().amount, 40);
+
+    assert_eq!(client.finalize_unstake(&node_addr), 40);
+    assert_eq!(client.get_unbondings(&node_addr).len(), 1);
+}
+
+#[test]
+fn test_migrate_seeds_total_staked_from_balance() {
+    let (env, client, _) = setup();
+    let node_addr = register_node(&env, &client);
+    staking_token(&client).mint(&node_addr, &500);
+    client.stake(&node_addr, &300);
+
+    // Simulate storage written by a version 4 build, which did not count stake.
+    env.as_contract(&client.address, || {
+        storage::set_schema_version(&env, 4);
+        storage::set_total_staked(&env, 0);
+    });
+    assert_eq!(client.migrate(), storage::SCHEMA_VERSION);
+    let total = env.as_contract(&client.address, || storage::get_total_staked(&env));
+    assert_eq!(total, 300);
+}
+
+/// Queue `action` as `admin`, wait out the timelock, and try to apply it.
+fn try_council_execute(
+    client: &RelayRegistryContractClient,
+    admin: &Address,
+    action: &ProposalAction,
+) -> Result<(), ContractError> {
+    let proposal_id = client.propose(admin, action);
+    client.execute(&proposal_id);
+    client
+        .env
+        .ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    client
+        .try_execute(&proposal_id)
+        .map(|_| ())
+        .map_err(|err| err.unwrap())
+}
+
+#[test]
+fn test_partial_slash_reaches_pending_unbondings() {
+    let (env, client, admin) = setup();
+    let treasury = setup_treasury(&client, &admin);
+    let node_addr = register_node(&env, &client);
+    staking_token(&client).mint(&node_addr, &1000);
+    client.stake(&node_addr, &1000);
+    client.unstake(&node_addr, &400);
+
+    council_execute(&client, &admin, &ProposalAction::SetSlashTier(7, 2500));
+    assert_eq!(client.get_slash_tier(&7), Some(2500));
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::Slash(node_addr.clone(), 7, String::from_str(&env, "downtime")),
+    );
+
+    let node = client.get_node(&node_addr);
+    assert_eq!(node.stake, 450);
+    assert_eq!(node.status, NodeStatus::Active);
+    assert_eq!(
+        client.get_unbondings(&node_addr).get(0).unwrap().amount,
+        300
+    );
+    assert_eq!(treasury.get_balance(), 250);
+    assert_eq!(
+        token::Client::new(&env, &client.get_config().token).balance(&treasury.address),
+        250
+    );
+
+    env.ledger().with_mut(|l| l.timestamp += 10);
+    assert_eq!(client.finalize_unstake(&node_addr), 300);
+}
+
+#[test]
+fn test_full_slash_forfeits_unbonding_stake() {
+    let (env, client, admin) = setup();
+    let treasury = setup_treasury(&client, &admin);
+    let node_addr = register_node(&env, &client);
+    staking_token(&client).mint(&node_addr, &300);
+    client.stake(&node_addr, &300);
+
+    // Unstaking everything ahead of the slash does not shield the stake.
+    client.unstake(&node_addr, &300);
+    assert_eq!(client.get_node(&node_addr).status, NodeStatus::Inactive);
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::Slash(
+            node_addr.clone(),
+            storage::FULL_SLASH_REASON,
+            String::from_str(&env, "equivocation"),
+        ),
+    );
+
+    assert_eq!(client.get_node(&node_addr).status, NodeStatus::Slashed);
+    assert!(client.get_unbondings(&node_addr).is_empty());
+    assert_eq!(treasury.get_balance(), 300);
+    env.ledger().with_mut(|l| l.timestamp += 10);
+    assert_eq!(
+        client.try_finalize_unstake(&node_addr),
+        Err(Ok(ContractError::NoPendingUnstake))
+    );
+}
+
+#[test]
+fn test_slash_tier_and_treasury_errors() {
+    let (env, client, admin) = setup();
+    assert_eq!(
+        client.get_slash_tier(&storage::FULL_SLASH_REASON),
+        Some(storage::MAX_BPS)
+    );
+    assert_eq!(client.get_config().treasury, None);
+    assert_eq!(
+        try_council_execute(&client, &admin, &ProposalAction::SetSlashTier(1, 0)),
+        Err(ContractError::InvalidSlashBps)
+    );
+    assert_eq!(
+        try_council_execute(&client, &admin, &ProposalAction::SetSlashTier(1, 10_001)),
+        Err(ContractError::InvalidSlashBps)
+    );
+
+    let node_addr = register_node(&env, &client);
+    staking_token(&client).mint(&node_addr, &200);
+    client.stake(&node_addr, &200);
+    let reason = String::from_str(&env, "misbehavior");
+    assert_eq!(
+        try_council_execute(
+            &client,
+            &admin,
+            &ProposalAction::Slash(node_addr.clone(), 1, reason.clone()),
+        ),
+        Err(ContractError::UnknownSlashReason)
+    );
+    assert_eq!(
+        try_council_execute(
+            &client,
+            &admin,
+            &ProposalAction::Slash(node_addr.clone(), storage::FULL_SLASH_REASON, reason),
+        ),
+        Err(ContractError::TreasuryNotSet)
+    );
+    assert_eq!(client.get_stake(&node_addr), 200);
+}
+
+#[test]
+fn test_delegation_activates_node_and_unbonds_through_lock() {
+    let (env, client, _) = setup();
+    let node_addr = register_node(&env, &client);
+    let delegator = Address::generate(&env);
+    staking_token(&client).mint(&delegator, &100);
+
+    client.delegate(&delegator, &node_addr, &100);
+    let node = client.get_node(&node_addr);
+    assert_eq!(node.status, NodeStatus::Active);
+    assert_eq!(node.stake, 0);
+    assert_eq!(client.get_delegation(&node_addr, &delegator), 100);
+    assert_eq!(client.get_delegated_stake(&node_addr), 100);
+
+    client.undelegate(&delegator, &node_addr, &40);
+    assert_eq!(client.get_node(&node_addr).status, NodeStatus::Inactive);
+    assert_eq!(
+        client.try_undelegate(&delegator, &node_addr, &61),
+        Err(Ok(ContractError::InsufficientStake))
+    );
+    assert_eq!(
+        client.try_finalize_undelegate(&delegator, &node_addr),
+        Err(Ok(ContractError::LockPeriodActive))
+    );
+    env.ledger().with_mut(|l| l.timestamp += 10);
+    assert_eq!(client.finalize_undelegate(&delegator, &node_addr), 40);
+
+    client.undelegate(&delegator, &node_addr, &60);
+    assert_eq!(
+        client.get_delegators(&node_addr),
+        Vec::from_array(&env, [delegator.clone()])
+    );
+    env.ledger().with_mut(|l| l.timestamp += 10);
+    assert_eq!(client.finalize_undelegate(&delegator, &node_addr), 60);
+    assert!(client.get_delegators(&node_addr).is_empty());
+    assert_eq!(
+        token::Client::new(&env, &client.get_config().token).balance(&delegator),
+        100
+    );
+}
+
+#[test]
+fn test_split_rewards_applies_commission() {
+    let (env, client, _) = setup();
+    let node_addr = register_node(&env, &client);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let token_client = staking_token(&client);
+    token_client.mint(&node_addr, &100);
+    token_client.mint(&first, &200);
+    token_client.mint(&second, &100);
+    client.stake(&node_addr, &100);
+    client.delegate(&first, &node_addr, &200);
+    client.delegate(&second, &node_addr, &100);
+
+    assert_eq!(
+        client.try_set_commission(&node_addr, &10_001),
+        Err(Ok(ContractError::InvalidCommission))
+    );
+    client.set_commission(&node_addr, &2000);
+    assert_eq!(client.get_commission(&node_addr), 2000);
+
+    // Delegators back 300 of 400: a pool of 75, less 20% commission, split 2:1.
+    assert_eq!(
+        client.split_rewards(&node_addr, &100),
+        Vec::from_array(
+            &env,
+            [
+                (node_addr.clone(), 40),
+                (first.clone(), 40),
+                (second.clone(), 20)
+            ]
+        )
+    );
+    let solo = register_node(&env, &client);
+    assert_eq!(
+        client.split_rewards(&solo, &100),
+        Vec::from_array(&env, [(solo.clone(), 100)])
+    );
+}
+
+#[test]
+fn test_slash_cuts_delegations_and_undelegations() {
+    let (env, client, admin) = setup();
+    let treasury = setup_treasury(&client, &admin);
+    let node_addr = register_node(&env, &client);
+    let delegator = Address::generate(&env);
+    let token_client = staking_token(&client);
+    token_client.mint(&node_addr, &100);
+    token_client.mint(&delegator, &300);
+    client.stake(&node_addr, &100);
+    client.delegate(&delegator, &node_addr, &300);
+    client.undelegate(&delegator, &node_addr, &100);
+
+    council_execute(&client, &admin, &ProposalAction::SetSlashTier(3, 5000));
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::Slash(node_addr.clone(), 3, String::from_str(&env, "censorship")),
+    );
+
+    assert_eq!(client.get_stake(&node_addr), 50);
+    assert_eq!(client.get_delegation(&node_addr, &delegator), 100);
+    assert_eq!(client.get_delegated_stake(&node_addr), 100);
+    assert_eq!(
+        client
+            .get_delegator_unbondings(&node_addr, &delegator)
+            .get(0)
+            .unwrap()
+            .amount,
+        50
+    );
+    assert_eq!(treasury.get_balance(), 200);
+    assert_eq!(client.get_node(&node_addr).status, NodeStatus::Active);
+}
+
+fn register_in_region(env: &Env, client: &RelayRegistryContractClient, region: &str) -> Address {
+    let node_addr = Address::generate(env);
+    let metadata = NodeMetadata {
+        region: String::from_str(env, region),
+        capacity: 1000,
+        uptime_commitment: 99,
+        endpoint: None,
+        software_version: None,
+        transports: Vec::new(env),
+        contact_hash: None,
+    };
+    client.register(&node_addr, &metadata);
+    node_addr
+}
+
+#[test]
+fn test_list_nodes_pages_and_filters() {
+    let (env, client, _) = setup();
+    let first = register_in_region(&env, &client, "us-east");
+    let second = register_in_region(&env, &client, "eu-west");
+    let third = register_in_region(&env, &client, "us-east");
+    staking_token(&client).mint(&first, &100);
+    client.stake(&first, &100);
+
+    let page = client.list_nodes(&0, &2, &None, &None);
+    assert_eq!(page.nodes.len(), 2);
+    assert_eq!(page.nodes.get(0).unwrap().address, first);
+    assert_eq!(page.nodes.get(1).unwrap().address, second);
+    assert_eq!(page.next_cursor, Some(2));
+    let page = client.list_nodes(&2, &2, &None, &None);
+    assert_eq!(page.nodes.len(), 1);
+    assert_eq!(page.nodes.get(0).unwrap().address, third);
+    assert_eq!(page.next_cursor, None);
+
+    let us_east = String::from_str(&env, "us-east");
+    let page = client.list_nodes(&0, &10, &Some(NodeStatus::Active), &Some(us_east.clone()));
+    assert_eq!(page.nodes.len(), 1);
+    assert_eq!(page.nodes.get(0).unwrap().address, first);
+
+    assert_eq!(client.count_nodes(&NodeStatus::Active, &None), 1);
+    assert_eq!(client.count_nodes(&NodeStatus::Inactive, &None), 2);
+    assert_eq!(
+        client.count_nodes(&NodeStatus::Inactive, &Some(us_east.clone())),
+        1
+    );
+
+    // Moving a node to another region moves it between region counts.
+    let eu_west = String::from_str(&env, "eu-west");
+    client.update_metadata(
+        &third,
+        &NodeMetadata {
+            region: eu_west.clone(),
+            capacity: 1000,
+            uptime_commitment: 99,
+            endpoint: None,
+            software_version: None,
+            transports: Vec::new(&env),
+            contact_hash: None,
+        },
+    );
+    assert_eq!(client.count_nodes(&NodeStatus::Inactive, &Some(us_east)), 0);
+    assert_eq!(client.count_nodes(&NodeStatus::Inactive, &Some(eu_west)), 2);
+}
+
+#[test]
+fn test_legacy_node_joins_index_when_next_written() {
+    let (env, client, _) = setup();
+    let node_addr = Address::generate(&env);
+    let legacy = storage::RelayNodeV3 {
+        address: node_addr.clone(),
+        stake: 0,
+        status: NodeStatus::Inactive,
+        metadata: storage::NodeMetadataV3 {
+            region: String::from_str(&env, "us-east"),
+            capacity: 1000,
+            uptime_commitment: 99,
+        },
+        registered_at: 0,
+        last_active: 0,
+    };
+    // Simulate a node written by a build without the node index or the
+    // extended metadata.
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&storage::DataKey::RelayNode(node_addr.clone()), &legacy);
+    });
+    assert!(client.list_nodes(&0, &10, &None, &None).nodes.is_empty());
+    let metadata = client.get_node(&node_addr).metadata;
+    assert_eq!((metadata.capacity, metadata.endpoint), (1000, None));
+    assert!(metadata.transports.is_empty());
+
+    staking_token(&client).mint(&node_addr, &100);
+    client.stake(&node_addr, &100);
+    let page = client.list_nodes(&0, &10, &None, &None);
+    assert_eq!(page.nodes.get(0).unwrap().address, node_addr);
+    assert_eq!(client.count_nodes(&NodeStatus::Active, &None), 1);
+    assert_eq!(client.count_nodes(&NodeStatus::Inactive, &None), 0);
+    env.as_contract(&client.address, || {
+        let storage = env.storage().persistent();
+        assert!(!storage.has(&storage::DataKey::RelayNode(node_addr.clone())));
+        assert!(storage.has(&storage::DataKey::NodeRecord(node_addr.clone())));
+    });
+}
+
+#[test]
+fn test_list_nodes_by_transport() {
+    let (env, client, _) = setup();
+    let lora = register_node(&env, &client);
+    let ble = register_node(&env, &client);
+    let both = register_node(&env, &client);
+    for (node_addr, transports) in [
+        (&lora, [Transport::LoRa].as_slice()),
+        (&ble, [Transport::Ble].as_slice()),
+        (&both, [Transport::Ble, Transport::LoRa].as_slice()),
+    ] {
+        let mut metadata = client.get_node(node_addr).metadata;
+        metadata.transports = Vec::from_slice(&env, transports);
+        client.update_metadata(node_addr, &metadata);
+    }
+    staking_token(&client).mint(&both, &100);
+    client.stake(&both, &100);
+
+    let page = client.list_nodes_by_transport(&0, &10, &Transport::LoRa, &None);
+    assert_eq!(page.nodes.len(), 2);
+    assert_eq!(page.nodes.get(0).unwrap().address, lora);
+    assert_eq!(page.nodes.get(1).unwrap().address, both);
+    assert_eq!(page.next_cursor, None);
+
+    let page = client.list_nodes_by_transport(&0, &2, &Transport::Ble, &Some(NodeStatus::Active));
+    assert!(page.nodes.is_empty());
+    assert_eq!(page.next_cursor, Some(2));
+    let page = client.list_nodes_by_transport(&2, &2, &Transport::Ble, &Some(NodeStatus::Active));
+    assert_eq!(page.nodes.get(0).unwrap().address, both);
+    assert!(client
+        .list_nodes_by_transport(&0, &10, &Transport::Internet, &None)
+        .nodes
+        .is_empty());
+}
+
+/// Send one heartbeat in each of the next `slots` heartbeat slots.
+fn heartbeat_for(env: &Env, client: &RelayRegistryContractClient, node: &Address, slots: u64) {
+    for _ in 0..slots {
+        client.heartbeat(node);
+        env.ledger()
+            .with_mut(|l| l.timestamp += storage::HEARTBEAT_INTERVAL);
+    }
+}
+
+#[test]
+fn test_check_liveness_jails_node_below_commitment() {
+    let (env, client, admin) = setup();
+    let treasury = setup_treasury(&client, &admin);
+    let token = token::Client::new(&env, &client.get_config().token);
+    let node_addr = register_node(&env, &client);
+    staking_token(&client).mint(&node_addr, &100);
+    client.stake(&node_addr, &100);
+    assert_eq!(client.check_liveness(&node_addr), None);
+
+    // Live for half of the first epoch, with repeated heartbeats counted once per slot.
+    client.heartbeat(&node_addr);
+    heartbeat_for(&env, &client, &node_addr, storage::SLOTS_PER_EPOCH / 2);
+    assert_eq!(
+        client.get_uptime(&node_addr).unwrap().heartbeats,
+        (storage::SLOTS_PER_EPOCH / 2) as u32
+    );
+    env.ledger().with_mut(|l| {
+        l.timestamp = storage::SLOTS_PER_EPOCH * storage::HEARTBEAT_INTERVAL;
+    });
+
+    assert_eq!(client.check_liveness(&node_addr), Some(50));
+    let node = client.get_node(&node_addr);
+    assert_eq!((node.status, node.stake), (NodeStatus::Jailed, 99));
+    assert_eq!(token.balance(&treasury.address), 1);
+    let fault = client.get_liveness_fault(&node_addr).unwrap();
+    assert_eq!((fault.epoch, fault.uptime, fault.commitment), (0, 50, 99));
+    let jail = client.get_jail(&node_addr).unwrap();
+    assert_eq!(jail.jail_count, 1);
+    assert_eq!(jail.until, env.ledger().timestamp() + storage::JAIL_PERIOD);
+
+    // Staking does not release a jailed node; re-checking within the same
+    // epoch only reports the result.
+    staking_token(&client).mint(&node_addr, &1);
+    client.stake(&node_addr, &1);
+    assert_eq!(client.get_node(&node_addr).status, NodeStatus::Jailed);
+    assert_eq!(client.check_liveness(&node_addr), Some(50));
+    assert_eq!(
+        client.try_unjail(&node_addr),
+        Err(Ok(ContractError::JailActive))
+    );
+
+    heartbeat_for(&env, &client, &node_addr, storage::SLOTS_PER_EPOCH);
+    client.unjail(&node_addr);
+    assert_eq!(client.get_node(&node_addr).status, NodeStatus::Active);
+    assert_eq!(
+        client.try_unjail(&node_addr),
+        Err(Ok(ContractError::NodeNotJailed))
+    );
+    assert_eq!(client.check_liveness(&node_addr), Some(100));
+    assert_eq!(client.get_node(&node_addr).status, NodeStatus::Active);
+
+    // A whole epoch without heartbeats counts as zero uptime.
+    env.ledger().with_mut(|l| {
+        l.timestamp += 2 * storage::SLOTS_PER_EPOCH * storage::HEARTBEAT_INTERVAL;
+    });
+    assert_eq!(client.check_liveness(&node_addr), Some(0));
+    assert_eq!(client.get_node(&node_addr).status, NodeStatus::Jailed);
+    assert_eq!(client.get_liveness_fault(&node_addr).unwrap().epoch, 3);
+    assert_eq!(client.get_jail(&node_addr).unwrap().jail_count, 2);
+}
+
+#[test]
+fn test_check_liveness_without_treasury_keeps_jailing() {
+    let (env, client, _) = setup();
+    let node_addr = register_node(&env, &client);
+    staking_token(&client).mint(&node_addr, &100);
+    client.stake(&node_addr, &100);
+    assert_eq!(client.check_liveness(&node_addr), None);
+
+    // With no treasury to forfeit stake to, jailings carry no penalty and the
+    // one that would escalate to a full slash stays a jailing.
+    for jail_count in 1..=storage::JAILS_BEFORE_SLASH {
+        env.ledger().with_mut(|l| {
+            l.timestamp += 2 * storage::SLOTS_PER_EPOCH * storage::HEARTBEAT_INTERVAL;
+        });
+        assert_eq!(client.check_liveness(&node_addr), Some(0));
+        let node = client.get_node(&node_addr);
+        assert_eq!((node.status, node.stake), (NodeStatus::Jailed, 100));
+        assert_eq!(client.get_jail(&node_addr).unwrap().jail_count, jail_count);
+
+        env.ledger()
+            .with_mut(|l| l.timestamp += storage::JAIL_PERIOD);
+        client.unjail(&node_addr);
+    }
+    assert_eq!(client.get_node(&node_addr).status, NodeStatus::Active);
+}
+
+#[test]
+fn test_repeated_jailing_escalates_to_full_slash() {
+    let (env, client, admin) = setup();
+    setup_treasury(&client, &admin);
+    let node_addr = register_node(&env, &client);
+    staking_token(&client).mint(&node_addr, &1_000);
+    client.stake(&node_addr, &1_000);
+    let reason = String::from_str(&env, "censored batches");
+    let jail = ProposalAction::Jail(node_addr.clone(), reason.clone());
+
+    council_execute(&client, &admin, &jail);
+    assert_eq!(client.get_node(&node_addr).stake, 990);
+    assert_eq!(
+        client.try_begin_exit(&node_addr),
+        Err(Ok(ContractError::NodeJailed))
+    );
+    let proposal_id = client.propose(&admin, &jail);
+    assert_eq!(
+        client.try_execute(&proposal_id),
+        Err(Ok(ContractError::NodeJailed))
+    );
+
+    // A jailing outside the record window starts the count again.
+    env.ledger().with_mut(|l| {
+        l.timestamp += storage::JAIL_RECORD_WINDOW + 1;
+    });
+    client.unjail(&node_addr);
+    council_execute(&client, &admin, &jail);
+    assert_eq!(client.get_jail(&node_addr).unwrap().jail_count, 1);
+
+    env.ledger()
+        .with_mut(|l| l.timestamp += storage::JAIL_PERIOD);
+    client.unjail(&node_addr);
+    council_execute(&client, &admin, &jail);
+    assert_eq!(client.get_jail(&node_addr).unwrap().jail_count, 2);
+
+    env.ledger()
+        .with_mut(|l| l.timestamp += storage::JAIL_PERIOD);
+    client.unjail(&node_addr);
+    assert_eq!(client.get_node(&node_addr).status, NodeStatus::Active);
+
+    // The escalating jailing only jails; the full slash waits out the timelock.
+    council_execute(&client, &admin, &jail);
+    let record = client.get_jail(&node_addr).unwrap();
+    assert_eq!(record.jail_count, storage::JAILS_BEFORE_SLASH);
+    let slash_id = record.pending_slash.unwrap();
+    assert_eq!(
+        client.get_proposal(&slash_id).action,
+        ProposalAction::Slash(node_addr.clone(), storage::FULL_SLASH_REASON, reason)
+    );
+    assert_eq!(
+        client.get_proposal(&slash_id).status,
+        ProposalStatus::Queued
+    );
+    assert_eq!(client.get_node(&node_addr).status, NodeStatus::Jailed);
+
+    // The node cannot walk out of jail ahead of the queued slash.
+    env.ledger()
+        .with_mut(|l| l.timestamp += storage::JAIL_PERIOD);
+    assert_eq!(
+        client.try_unjail(&node_addr),
+        Err(Ok(ContractError::JailActive))
+    );
+    assert_eq!(
+        client.try_execute(&slash_id),
+        Err(Ok(ContractError::TimelockActive))
+    );
+    execute_after_timelock(&env, &client, slash_id);
+    let node = client.get_node(&node_addr);
+    assert_eq!((node.status, node.stake), (NodeStatus::Slashed, 0));
+}
+
+#[test]
+fn test_guardian_vetoes_escalated_slash() {
+    let (env, client, admin) = setup();
+    setup_treasury(&client, &admin);
+    let guardian = Address::generate(&env);
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::SetGuardians(Vec::from_array(&env, [guardian.clone()])),
+    );
+    let node_addr = register_node(&env, &client);
+    staking_token(&client).mint(&node_addr, &1_000);
+    client.stake(&node_addr, &1_000);
+
+    // Liveness checks anyone can run escalate like council jailings.
+    for _ in 1..storage::JAILS_BEFORE_SLASH {
+        env.ledger().with_mut(|l| {
+            l.timestamp += 2 * storage::SLOTS_PER_EPOCH * storage::HEARTBEAT_INTERVAL;
+        });
+        assert_eq!(client.check_liveness(&node_addr), Some(0));
+        env.ledger()
+            .with_mut(|l| l.timestamp += storage::JAIL_PERIOD);
+        client.unjail(&node_addr);
+    }
+    env.ledger().with_mut(|l| {
+        l.timestamp += 2 * storage::SLOTS_PER_EPOCH * storage::HEARTBEAT_INTERVAL;
+    });
+    assert_eq!(client.check_liveness(&node_addr), Some(0));
+    let slash_id = client.get_jail(&node_addr).unwrap().pending_slash.unwrap();
+
+    client.veto(
+        &guardian,
+        &slash_id,
+        &String::from_str(&env, "outage was on our side"),
+    );
+    assert_eq!(
+        client.get_proposal(&slash_id).status,
+        ProposalStatus::Vetoed
+    );
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    assert_eq!(
+        client.try_execute(&slash_id),
+        Err(Ok(ContractError::ProposalNotPending))
+    );
+
+    // The node serves its jail sentence and keeps everything but the penalties.
+    env.ledger()
+        .with_mut(|l| l.timestamp += storage::JAIL_PERIOD);
+    client.unjail(&node_addr);
+    let node = client.get_node(&node_addr);
+    assert_eq!((node.status, node.stake), (NodeStatus::Active, 972));
+}
+
+/// Proof of possession for `sk` that `node_addr` submits with its next signing key.
+fn key_proof(
+    env: &Env,
+    client: &RelayRegistryContractClient,
+    node_addr: &Address,
+    sk: &ed25519_dalek::SigningKey,
+) -> (BytesN<32>, BytesN<64>) {
+    use ed25519_dalek::Signer;
+    let public_key = BytesN::from_array(env, &sk.verifying_key().to_bytes());
+    let digest = client.get_key_proof_digest(node_addr, &public_key);
+    let signature = sk.sign(&digest.to_array());
+    (public_key, BytesN::from_array(env, &signature.to_bytes()))
+}
+
+#[test]
+fn test_signing_key_rotation_keeps_history() {
+    let (env, client, _) = setup();
+    let node_addr = register_node(&env, &client);
+    let first = ed25519_dalek::SigningKey::from_bytes(&[1u8; 32]);
+    let second = ed25519_dalek::SigningKey::from_bytes(&[2u8; 32]);
+
+    let (first_pk, first_sig) = key_proof(&env, &client, &node_addr, &first);
+    let (_, wrong_sig) = key_proof(&env, &client, &node_addr, &second);
+    assert_eq!(
+        client.try_register_signing_key(&node_addr, &first_pk, &wrong_sig),
+        Err(Ok(ContractError::InvalidKeyProof))
+    );
+    assert_eq!(
+        client.try_rotate_signing_key(&node_addr, &first_pk, &first_sig),
+        Err(Ok(ContractError::SigningKeyNotSet))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    client.register_signing_key(&node_addr, &first_pk, &first_sig);
+    assert_eq!(client.get_signing_key(&node_addr), Some(first_pk.clone()));
+    assert_eq!(
+        client.try_register_signing_key(&node_addr, &first_pk, &first_sig),
+        Err(Ok(ContractError::SigningKeyAlreadySet))
+    );
+    // The proof is bound to the key's position, so it cannot be replayed later.
+    assert_eq!(
+        client.try_rotate_signing_key(&node_addr, &first_pk, &first_sig),
+        Err(Ok(ContractError::InvalidKeyProof))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = 2_000);
+    let (second_pk, second_sig) = key_proof(&env, &client, &node_addr, &second);
+    client.rotate_signing_key(&node_addr, &second_pk, &second_sig);
+
+    assert_eq!(client.get_signing_key(&node_addr), Some(second_pk.clone()));
+    assert_eq!(client.get_signing_key_at(&node_addr, &999), None);
+    assert_eq!(
+        client.get_signing_key_at(&node_addr, &1_999),
+        Some(first_pk)
+    );
+    assert_eq!(
+        client.get_signing_key_at(&node_addr, &2_000),
+        Some(second_pk)
+    );
+
+    let history = client.get_signing_key_history(&node_addr, &1, &10);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().active_until, Some(2_000));
+    assert_eq!(history.get(1).unwrap().active_until, None);
+}
+
+#[test]
+fn test_exit_unbonds_stake_and_removes_node() {
+    let (env, client, admin) = setup();
+    let node_addr = register_node(&env, &client);
+    staking_token(&client).mint(&node_addr, &150);
+    client.stake(&node_addr, &150);
+
+    let resolver_id = env.register(dispute_resolver::DisputeResolverContract, ());
+    let resolver = dispute_resolver::DisputeResolverContractClient::new(&env, &resolver_id);
+    let resolver_council = AdminCouncil {
+        members: Vec::from_array(&env, [admin.clone()]),
+        threshold: 1,
+    };
+    resolver.initialize(&resolver_council, &100);
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::SetDisputeResolver(resolver_id.clone()),
+    );
+    assert_eq!(client.get_config().dispute_resolver, Some(resolver_id));
+
+    let proof = dispute_resolver::types::RelayChainProof {
+        signature: BytesN::from_array(&env, &[0u8; 64]),
+        chain_hash: BytesN::from_array(&env, &[8u8; 32]),
+        sequence: 1,
+    };
+    let dispute_id =
+        resolver.raise_dispute(&node_addr, &BytesN::from_array(&env, &[9u8; 32]), &proof);
+    assert_eq!(
+        client.try_begin_exit(&node_addr),
+        Err(Ok(ContractError::DisputeOpen))
+    );
+    env.ledger().with_mut(|l| l.sequence_number += 101);
+    resolver.resolve(&dispute_id);
+
+    assert_eq!(
+        client.try_complete_exit(&node_addr),
+        Err(Ok(ContractError::NodeNotExiting))
+    );
+    client.begin_exit(&node_addr);
+    let node = client.get_node(&node_addr);
+    assert_eq!(node.status, NodeStatus::Exiting);
+    assert_eq!(node.stake, 0);
+    assert_eq!(client.count_nodes(&NodeStatus::Exiting, &None), 1);
+    assert_eq!(
+        client.try_stake(&node_addr, &100),
+        Err(Ok(ContractError::NodeExiting))
+    );
+    assert_eq!(
+        client.try_begin_exit(&node_addr),
+        Err(Ok(ContractError::NodeExiting))
+    );
+    assert_eq!(
+        client.try_complete_exit(&node_addr),
+        Err(Ok(ContractError::LockPeriodActive))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp += 10);
+    assert_eq!(client.complete_exit(&node_addr), 150);
+    assert_eq!(
+        client.try_get_node(&node_addr),
+        Err(Ok(ContractError::NotRegistered))
+    );
+    assert_eq!(client.count_nodes(&NodeStatus::Exiting, &None), 0);
+    assert_eq!(
+        env.as_contract(&client.address, || storage::get_node_count(&env)),
+        0
+    );
+    assert_eq!(
+        token::Client::new(&env, &client.get_config().token).balance(&node_addr),
+        150
+    );
+
+    // The address can come back as a new node.
+    let metadata = NodeMetadata {
+        region: String::from_str(&env, "us-east"),
+        capacity: 1000,
+        uptime_commitment: 99,
+        endpoint: None,
+        software_version: None,
+        transports: Vec::new(&env),
+        contact_hash: None,
+    };
+    client.register(&node_addr, &metadata);
+    assert_eq!(client.get_node(&node_addr).status, NodeStatus::Inactive);
+}
+
+#[test]
+fn test_exit_unbonds_and_pays_out_delegators() {
+    let (env, client, _) = setup();
+    let token_client = staking_token(&client);
+    let token = token::Client::new(&env, &client.get_config().token);
+    let node_addr = register_node(&env, &client);
+    token_client.mint(&node_addr, &200);
+    client.stake(&node_addr, &200);
+
+    let idle = Address::generate(&env);
+    let leaving = Address::generate(&env);
+    token_client.mint(&idle, &100);
+    token_client.mint(&leaving, &50);
+    client.delegate(&idle, &node_addr, &100);
+    client.delegate(&leaving, &node_addr, &50);
+    client.undelegate(&leaving, &node_addr, &20);
+
+    // Beginning the exit undelegates everything still bonded.
+    client.begin_exit(&node_addr);
+    assert_eq!(client.get_delegated_stake(&node_addr), 0);
+    assert_eq!(client.get_delegation(&node_addr, &idle), 0);
+    assert_eq!(client.get_delegator_unbondings(&node_addr, &idle).len(), 1);
+    assert_eq!(
+        client.get_delegator_unbondings(&node_addr, &leaving).len(),
+        2
+    );
+    assert_eq!(
+        client.try_complete_exit(&node_addr),
+        Err(Ok(ContractError::LockPeriodActive))
+    );
+
+    // Delegators that never withdraw cannot hold the exit up; they are paid.
+    env.ledger().with_mut(|l| l.timestamp += 10);
+    assert_eq!(client.complete_exit(&node_addr), 200);
+    assert_eq!(token.balance(&node_addr), 200);
+    assert_eq!(token.balance(&idle), 100);
+    assert_eq!(token.balance(&leaving), 50);
+    assert!(client.get_delegators(&node_addr).is_empty());
+    assert!(client
+        .get_delegator_unbondings(&node_addr, &idle)
+        .is_empty());
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_reputation_decays_and_ranks_top_nodes() {
+    let (env, client, admin) = setup();
+    let [first, second, third] = [0; 3].map(|_| register_node(&env, &client));
+    for node in [&first, &second, &third] {
+        staking_token(&client).mint(node, &100);
+        client.stake(node, &100);
+    }
+
+    let reporter = Address::generate(&env);
+    assert_eq!(
+        client.try_record_batch(&reporter, &first),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    for role in [Role::BatchReporter, Role::DisputeReporter] {
+        council_execute(
+            &client,
+            &admin,
+            &ProposalAction::GrantRole(role, reporter.clone()),
+        );
+    }
+
+    for _ in 0..3 {
+        client.record_batch(&reporter, &first);
+    }
+    client.record_batch(&reporter, &second);
+    client.record_dispute(&reporter, &second, &Some(third.clone()));
+    // Parties that are not registered nodes are skipped.
+    client.record_dispute(&reporter, &Address::generate(&env), &None);
+
+    let top = client.top_nodes(&10);
+    assert_eq!(top.len(), 2);
+    assert_eq!(top.get(0).unwrap(), (second.clone(), 60));
+    assert_eq!(top.get(1).unwrap(), (first.clone(), 30));
+    assert_eq!(client.top_nodes(&1).len(), 1);
+    assert_eq!(client.get_reputation(&third).unwrap().score, -200);
+    assert_eq!(client.get_reputation(&first).unwrap().settled_batches, 3);
+
+    // A day later every score has lost REPUTATION_DECAY_BPS (10%).
+    env.ledger()
+        .with_mut(|l| l.timestamp += storage::HEARTBEAT_INTERVAL * storage::SLOTS_PER_EPOCH);
+    assert_eq!(client.get_reputation(&second).unwrap().score, 54);
+    assert_eq!(client.get_reputation(&third).unwrap().score, -180);
+    assert_eq!(client.top_nodes(&10).get(1).unwrap(), (first.clone(), 27));
+
+    // Only active nodes are offered.
+    client.unstake(&second, &100);
+    let top = client.top_nodes(&10);
+    assert_eq!(top.len(), 1);
+    assert_eq!(top.get(0).unwrap(), (first, 27));
+}
+
+#[test]
+fn test_min_stake_changes_sweep_node_status() {
+    let (env, client, admin) = setup();
+    let node_addr = register_node(&env, &client);
+    staking_token(&client).mint(&node_addr, &500);
+    client.stake(&node_addr, &200);
+
+    let invalid_id = client.propose(&admin, &ProposalAction::SetMinStake(0));
+    client.execute(&invalid_id);
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    assert_eq!(
+        client.try_execute(&invalid_id),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+
+    // Raising the minimum leaves under-staked nodes active for the grace period.
+    council_execute(&client, &admin, &ProposalAction::SetMinStake(300));
+    let grace_until = env.ledger().timestamp() + storage::MIN_STAKE_GRACE_PERIOD;
+    let config = client.get_config();
+    assert_eq!(config.min_stake, 300);
+    assert_eq!(config.min_stake_grace_until, Some(grace_until));
+    assert_eq!(client.sweep_stake_status(&0, &10), None);
+    assert!(client.is_active(&node_addr));
+
+    env.ledger().with_mut(|l| l.timestamp = grace_until);
+    client.sweep_stake_status(&0, &10);
+    assert_eq!(client.get_node(&node_addr).status, NodeStatus::Inactive);
+
+    // Lowering it lets the sweep bring nodes that meet the bar back.
+    council_execute(&client, &admin, &ProposalAction::SetMinStake(150));
+    client.sweep_stake_status(&0, &10);
+    assert!(client.is_active(&node_addr));
+
+    // A new lock period applies to unbondings queued afterwards.
+    council_execute(&client, &admin, &ProposalAction::SetStakeLockPeriod(40));
+    assert_eq!(client.get_config().stake_lock_period, 40);
+    client.unstake(&node_addr, &20);
+    assert_eq!(
+        client.get_unbondings(&node_addr).get(0).unwrap().unlocks_at,
+        env.ledger().timestamp() + 40
+    );
+}
+
+#[test]
+fn test_select_relays_is_seeded_and_stake_weighted() {
+    let (env, client, _) = setup();
+    let heavy = register_in_region(&env, &client, "us-east");
+    let light = register_in_region(&env, &client, "eu-west");
+    let idle = register_in_region(&env, &client, "us-east");
+    let minter = staking_token(&client);
+    minter.mint(&heavy, &2_000);
+    minter.mint(&light, &100);
+    client.stake(&heavy, &2_000);
+    client.stake(&light, &100);
+
+    let seed = Some(BytesN::from_array(&env, &[7; 32]));
+    let all = client.select_relays(&seed, &5, &None);
+    assert_eq!(all.len(), 2);
+    assert!(all.contains(&heavy) && all.contains(&light) && !all.contains(&idle));
+    assert_eq!(client.select_relays(&seed, &5, &None), all);
+    assert_eq!(
+        client.select_relays(&seed, &5, &Some(String::from_str(&env, "eu-west"))),
+        Vec::from_array(&env, [light.clone()])
+    );
+    assert!(client.select_relays(&seed, &0, &None).is_empty());
+
+    // With 20x the bonded stake, the heavy node is drawn first far more often.
+    let mut heavy_first = 0;
+    for i in 0..50u8 {
+        let seed = Some(BytesN::from_array(&env, &[i; 32]));
+        if client.select_relays(&seed, &1, &None).get(0).unwrap() == heavy {
+            heavy_first += 1;
+        }
+    }
+    assert!(heavy_first > 40);
+}
+
+#[test]
+fn test_select_relays_reads_a_bounded_window() {
+    let (mut env, client, _) = setup();
+    // Registering past the window makes a snapshot too large to review; the
+    // bound is asserted below instead.
+    env.set_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    let minter = staking_token(&client);
+    let indexed = storage::MAX_SELECTION_WINDOW + 8;
+    let mut nodes = Vec::new(&env);
+    for _ in 0..indexed {
+        let node_addr = register_node(&env, &client);
+        minter.mint(&node_addr, &100);
+        client.stake(&node_addr, &100);
+        nodes.push_back(node_addr);
+    }
+
+    // Every relay drawn sits within one run of `MAX_SELECTION_WINDOW`
+    // consecutive index positions, wrapping around.
+    for i in 0..10u8 {
+        let seed = Some(BytesN::from_array(&env, &[i; 32]));
+        let selected = client.select_relays(&seed, &storage::MAX_RELAY_SELECTION, &None);
+        assert_eq!(selected.len(), storage::MAX_RELAY_SELECTION);
+        let mut positions = Vec::new(&env);
+        for node in selected.iter() {
+            positions.push_back(nodes.first_index_of(node).unwrap());
+        }
+        let fits = (0..indexed).any(|start| {
+            positions.iter().all(|position| {
+                (position + indexed - start) % indexed < storage::MAX_SELECTION_WINDOW
+            })
+        });
+        assert!(fits);
+    }
+}
+
+#[test]
+fn test_transfer_moves_node_and_forwards_old_address() {
+    let (env, client, admin) = setup();
+    let other = register_node(&env, &client);
+    let old = register_node(&env, &client);
+    let new = Address::generate(&env);
+    let delegator = Address::generate(&env);
+    let minter = staking_token(&client);
+    minter.mint(&old, &300);
+    minter.mint(&delegator, &50);
+    client.stake(&old, &300);
+    client.unstake(&old, &30);
+    client.delegate(&delegator, &old, &50);
+    client.set_commission(&old, &500);
+
+    let reporter = Address::generate(&env);
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::GrantRole(Role::BatchReporter, reporter.clone()),
+    );
+    client.record_batch(&reporter, &old);
+
+    assert_eq!(
+        client.try_propose_transfer(&old, &other),
+        Err(Ok(ContractError::AlreadyRegistered))
+    );
+    client.propose_transfer(&old, &new);
+    assert_eq!(client.get_pending_transfer(&old), Some(new.clone()));
+    assert_eq!(
+        client.try_accept_transfer(&old, &Address::generate(&env)),
+        Err(Ok(ContractError::NoPendingTransfer))
+    );
+    client.accept_transfer(&old, &new);
+
+    let node = client.get_node(&new);
+    assert_eq!(node.address, new);
+    assert_eq!(node.stake, 270);
+    assert_eq!(node.status, NodeStatus::Active);
+    assert_eq!(client.get_unbondings(&new).get(0).unwrap().amount, 30);
+    assert!(client.get_unbondings(&old).is_empty());
+    assert_eq!(client.get_delegation(&new, &delegator), 50);
+    assert_eq!(client.get_delegated_stake(&new), 50);
+    assert_eq!(client.get_commission(&new), 500);
+    assert_eq!(client.get_reputation(&new).unwrap().settled_batches, 1);
+    assert_eq!(client.top_nodes(&10).get(0).unwrap().0, new);
+
+    // Old lookups follow the forwarding record; the stake does not, so it is not counted twice.
+    assert_eq!(client.get_node(&old), node);
+    assert!(client.is_active(&old));
+    assert_eq!(client.resolve_node(&old), Some(new.clone()));
+    assert_eq!(client.get_stake(&old), 0);
+    assert_eq!(client.get_pending_transfer(&old), None);
+    client.record_batch(&reporter, &old);
+    assert_eq!(client.get_reputation(&new).unwrap().settled_batches, 2);
+
+    // The node keeps its index position and the counts are unchanged.
+    let page = client.list_nodes(&0, &10, &None, &None);
+    assert_eq!(page.nodes.len(), 2);
+    assert_eq!(page.nodes.get(1).unwrap().address, new);
+    assert_eq!(client.count_nodes(&NodeStatus::Active, &None), 1);
 }
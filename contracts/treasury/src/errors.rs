@@ -17,6 +17,7 @@
 //!
 //! implementation tracked in GitHub issue
 
+use conduit_common::{CouncilError, GuardianError, RoleError};
 use soroban_sdk::contracterror;
 
 /// All error codes returned by the Protocol Treasury contract.
@@ -50,4 +51,86 @@ pub enum ContractError {
 
     /// Program name length is out of bounds.
     InvalidProgramName = 15,
+
+    /// Caller is not a member of the admin council.
+    NotCouncilMember = 16,
+
+    /// The referenced proposal does not exist.
+    ProposalNotFound = 17,
+
+    /// The proposal has already been executed or cancelled.
+    ProposalNotPending = 18,
+
+    /// The proposal's approval window has passed.
+    ProposalExpired = 19,
+
+    /// The council member has already approved this proposal.
+    AlreadyApproved = 20,
+
+    /// Stored data was written by a newer schema version than this build supports.
+    UnsupportedSchemaVersion = 21,
+
+    /// The called function is paused by the council.
+    Paused = 22,
+
+    /// The queued action's timelock delay has not passed yet.
+    TimelockActive = 23,
+
+    /// The proposal is not waiting in the timelock queue.
+    ProposalNotQueued = 24,
+
+    /// Treasury authority has moved to the governance contract; council proposals are disabled.
+    CouncilRetired = 25,
+
+    /// The address already holds the role.
+    RoleAlreadyGranted = 26,
+
+    /// The address does not hold the role.
+    RoleNotHeld = 27,
+
+    /// Guardian set has duplicates or more than `MAX_GUARDIANS` addresses.
+    InvalidGuardians = 28,
+
+    /// Caller is not a guardian.
+    NotGuardian = 29,
+
+    /// The proposal can no longer be vetoed: it was already applied, cancelled
+    /// or vetoed, its approval window passed, or its queued eta was reached.
+    VetoWindowClosed = 30,
+
+    /// The veto reason is empty or longer than `MAX_VETO_REASON_LEN` bytes.
+    InvalidVetoReason = 31,
+}
+
+impl From<CouncilError> for ContractError {
+    fn from(err: CouncilError) -> Self {
+        match err {
+            CouncilError::InvalidCouncilConfig => ContractError::InvalidCouncilConfig,
+            CouncilError::NotCouncilMember => ContractError::NotCouncilMember,
+            CouncilError::AlreadyApproved => ContractError::AlreadyApproved,
+            CouncilError::InsufficientApprovals => ContractError::InsufficientApprovals,
+            CouncilError::ProposalNotPending => ContractError::ProposalNotPending,
+            CouncilError::ProposalExpired => ContractError::ProposalExpired,
+        }
+    }
+}
+
+impl From<RoleError> for ContractError {
+    fn from(err: RoleError) -> Self {
+        match err {
+            RoleError::RoleAlreadyGranted => ContractError::RoleAlreadyGranted,
+            RoleError::RoleNotHeld => ContractError::RoleNotHeld,
+        }
+    }
+}
+
+impl From<GuardianError> for ContractError {
+    fn from(err: GuardianError) -> Self {
+        match err {
+            GuardianError::InvalidGuardians => ContractError::InvalidGuardians,
+            GuardianError::NotGuardian => ContractError::NotGuardian,
+            GuardianError::VetoWindowClosed => ContractError::VetoWindowClosed,
+            GuardianError::InvalidVetoReason => ContractError::InvalidVetoReason,
+        }
+    }
 }
@@ -9,7 +9,7 @@
 //! - Disburse grants and incentives to relay node operators
 //! - Track all inflows and outflows with on-chain transparency
 //! - Enforce spending limits and require multi-sig authorization for withdrawals
-//! - Support future handover to a DAO governance model
+//! - Hand authority over to a DAO governance contract (`SetGovernance`, `governance_execute`)
 //!
 //! ## Functions to implement
 //! - `deposit(env, amount)` — Deposit funds into the protocol treasury
@@ -17,6 +17,12 @@
 //! - `allocate(env, program, amount)` — Allocate budget to a named spending program
 //! - `get_balance(env)` — Fetch the current treasury token balance
 //! - `get_history(env)` — Fetch the full on-chain transaction history
+//! - `propose(env, proposer, action)` — Open a council proposal for a privileged action
+//! - `approve(env, approver, proposal_id)` — Approve a pending proposal as a council member
+//! - `execute(env, proposal_id)` — Apply a proposal once approvals reach the council threshold
+//! - `cancel(env, proposer, proposal_id)` — Withdraw a pending proposal
+//! - `veto(env, guardian, proposal_id, reason)` — Guardian veto of a pending or queued proposal
+//! - `governance_execute(env, action)` — Apply an action on behalf of the governance contract
 //!
 //! ## See also
 //! - `types.rs` — Data structures (TreasuryEntry, AllocationRecord, SpendingProgram)
@@ -27,29 +33,96 @@
 
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, token, Address, Env, String};
+use soroban_sdk::{contract, contractimpl, token, Address, BytesN, Env, String, Vec};
 
 pub mod errors;
 pub mod storage;
 pub mod types;
 
+use conduit_common::audit::log_page;
+use conduit_common::council::{proposal_window, require_open, timelock_eta};
+use conduit_common::ContractEvents;
+use conduit_common::{guardians, roles};
+
 use crate::errors::ContractError;
-use crate::types::{AdminCouncil, EntryKind, SpendingProgram, TreasuryEntry, TreasuryStats};
+use crate::types::{
+    ActionLogEntry, AdminCouncil, EntryKind, PausableFn, Proposal, ProposalAction, ProposalStatus,
+    Role, SpendingProgram, TreasuryEntry, TreasuryStats, VetoRecord,
+};
+
+const EVENTS: ContractEvents = ContractEvents::new("treasury");
+
+/// Append an applied privileged action to the action log.
+fn log_action(
+    env: &Env,
+    proposal_id: Option<u64>,
+    action: ProposalAction,
+    approvals: Vec<Address>,
+) {
+    storage::set_action_log_entry(
+        env,
+        &ActionLogEntry {
+            id: storage::next_action_log_id(env),
+            proposal_id,
+            action,
+            approvals,
+            ledger: env.ledger().sequence() as u64,
+        },
+    );
+}
 
-fn require_council_auth(env: &Env) {
-    let council = storage::get_admin_council(env);
-    let mut authorized = 0u32;
-    for member in council.members.iter() {
-        member.require_auth();
-        authorized += 1;
-        if authorized >= council.threshold {
-            break;
-        }
+/// Load a proposal that can still collect approvals or be executed.
+fn load_pending_proposal(env: &Env, proposal_id: u64) -> Result<Proposal, ContractError> {
+    let proposal =
+        storage::get_proposal(env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+    require_open(env, &proposal.status, proposal.expires_at)?;
+    Ok(proposal)
+}
+
+/// Whether `account` holds the role that lets it perform `action` without council approvals.
+fn has_role_for(env: &Env, action: &ProposalAction, account: &Address) -> bool {
+    action
+        .required_role()
+        .is_some_and(|role| storage::has_role(env, &role, account))
+}
+
+/// Fail with `ContractError::Paused` while the council has `function` paused.
+fn require_not_paused(env: &Env, function: PausableFn) -> Result<(), ContractError> {
+    if storage::is_paused(env, &function) {
+        return Err(ContractError::Paused);
     }
+    Ok(())
+}
 
-    if authorized < council.threshold {
-        panic!("Insufficient approvals");
+/// Fail with `ContractError::CouncilRetired` once authority has moved to governance.
+fn require_council_authority(env: &Env) -> Result<(), ContractError> {
+    if storage::get_governance(env).is_some() {
+        return Err(ContractError::CouncilRetired);
     }
+    Ok(())
+}
+
+/// Whether `proposer` is the governance contract currently holding treasury authority.
+fn is_governance(env: &Env, proposer: &Address) -> bool {
+    storage::get_governance(env).as_ref() == Some(proposer)
+}
+
+/// Ensure `member` may trigger an emergency pause: any guardian, plus the council
+/// while it holds authority, or the governance contract after the handover.
+fn require_pauser(env: &Env, member: &Address) -> Result<(), ContractError> {
+    if storage::get_guardians(env).contains(member) || is_governance(env, member) {
+        return Ok(());
+    }
+    require_council_authority(env)?;
+    storage::get_admin_council(env).require_member(member)?;
+    Ok(())
+}
+
+/// Rewrite stored data from schema `version` to `version + 1`.
+fn migrate_step(_env: &Env, _version: u32) -> Result<(), ContractError> {
+    // No layout changes yet. Each schema bump adds the step for the version it
+    // replaces here.
+    Err(ContractError::UnsupportedSchemaVersion)
 }
 
 #[contract]
@@ -86,13 +159,12 @@ impl TreasuryContract {
             return Err(ContractError::AlreadyInitialized);
         }
 
-        if council.threshold == 0 || council.members.len() < council.threshold {
-            return Err(ContractError::InvalidCouncilConfig);
-        }
+        council.validate()?;
 
         storage::set_admin_council(&env, &council);
         storage::set_token_address(&env, &token_address);
         storage::set_balance(&env, 0);
+        storage::set_schema_version(&env, storage::SCHEMA_VERSION);
 
         Ok(())
     }
@@ -105,10 +177,12 @@ impl TreasuryContract {
     /// - `amount`: Amount to deposit. Must be greater than zero.
     ///
     /// # Errors
+    /// - `ContractError::Paused` if the council has paused this function.
     /// - `ContractError::InvalidAmount` if `amount` is zero or negative.
     /// - `ContractError::Overflow` if the balance arithmetic overflows.
     pub fn deposit(env: Env, from: Address, amount: i128) -> Result<(), ContractError> {
         storage::extend_instance_ttl(&env);
+        require_not_paused(&env, PausableFn::Deposit)?;
         from.require_auth();
 
         if amount <= 0 {
@@ -141,18 +215,14 @@ impl TreasuryContract {
         let token = token::Client::new(&env, &token_address);
         token.transfer(&from, &env.current_contract_address(), &amount);
 
-        env.events().publish(
-            (
-                soroban_sdk::Symbol::new(&env, "treasury"),
-                soroban_sdk::Symbol::new(&env, "deposit"),
-            ),
-            (from.clone(), amount),
-        );
+        EVENTS.publish(&env, "deposit", (from.clone(), amount));
 
         Ok(())
     }
 
-    /// Withdraw funds from the protocol treasury (admin only).
+    /// Withdraw funds from the protocol treasury.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::Withdraw`).
     ///
     /// # Parameters
     /// - `env`: Soroban environment for the current invocation.
@@ -161,18 +231,12 @@ impl TreasuryContract {
     /// - `memo`: Human-readable memo for the withdrawal entry.
     ///
     /// # Errors
+    /// - `ContractError::Paused` if the council has paused this function.
     /// - `ContractError::InvalidAmount` if `amount` is zero or negative.
     /// - `ContractError::InsufficientBalance` if treasury balance is too low.
     /// - `ContractError::Overflow` if arithmetic underflows/overflows.
-    pub fn withdraw(
-        env: Env,
-        to: Address,
-        amount: i128,
-        memo: String,
-    ) -> Result<(), ContractError> {
-        storage::extend_instance_ttl(&env);
-        require_council_auth(&env);
-
+    fn withdraw(env: Env, to: Address, amount: i128, memo: String) -> Result<(), ContractError> {
+        require_not_paused(&env, PausableFn::Withdraw)?;
         if amount <= 0 {
             return Err(ContractError::InvalidAmount);
         }
@@ -206,27 +270,20 @@ impl TreasuryContract {
         let token = token::Client::new(&env, &storage::get_token_address(&env));
         token.transfer(&env.current_contract_address(), &to, &amount);
 
-        env.events().publish(
-            (
-                soroban_sdk::Symbol::new(&env, "treasury"),
-                soroban_sdk::Symbol::new(&env, "withdraw"),
-            ),
-            (to.clone(), amount, memo),
-        );
+        EVENTS.publish(&env, "withdraw", (to.clone(), amount, memo));
 
         Ok(())
     }
 
     /// Create a new spending program with a specified budget.
     ///
+    /// Only reachable through an executed council proposal (`ProposalAction::CreateProgram`).
+    ///
     /// # Parameters
     /// - `env`: Soroban environment.
     /// - `name`: Name of the program (3-64 chars).
     /// - `budget`: Initial budget for the program. Must be > 0.
-    pub fn create_program(env: Env, name: String, budget: i128) -> Result<u64, ContractError> {
-        storage::extend_instance_ttl(&env);
-        require_council_auth(&env);
-
+    fn create_program(env: Env, name: String, budget: i128) -> Result<u64, ContractError> {
         if budget <= 0 {
             return Err(ContractError::InvalidAmount);
         }
@@ -247,26 +304,20 @@ impl TreasuryContract {
 
         storage::set_spending_program(&env, program_id, program);
 
-        env.events().publish(
-            (
-                soroban_sdk::Symbol::new(&env, "treasury"),
-                soroban_sdk::Symbol::new(&env, "create_program"),
-            ),
-            (program_id, name, budget),
-        );
+        EVENTS.publish(&env, "create_program", (program_id, name, budget));
 
         Ok(program_id)
     }
 
     /// Update the budget of an existing spending program.
-    pub fn update_program_budget(
+    ///
+    /// Only reachable through an executed council proposal
+    /// (`ProposalAction::UpdateProgramBudget`).
+    fn update_program_budget(
         env: Env,
         program_id: u64,
         new_budget: i128,
     ) -> Result<(), ContractError> {
-        storage::extend_instance_ttl(&env);
-        require_council_auth(&env);
-
         let mut program = storage::get_spending_program(&env, program_id)
             .ok_or(ContractError::ProgramNotFound)?;
 
@@ -277,35 +328,22 @@ impl TreasuryContract {
         program.budget = new_budget;
         storage::set_spending_program(&env, program_id, program);
 
-        env.events().publish(
-            (
-                soroban_sdk::Symbol::new(&env, "treasury"),
-                soroban_sdk::Symbol::new(&env, "update_budget"),
-            ),
-            (program_id, new_budget),
-        );
+        EVENTS.publish(&env, "update_budget", (program_id, new_budget));
 
         Ok(())
     }
 
     /// Deactivate a spending program.
-    pub fn deactivate_program(env: Env, program_id: u64) -> Result<(), ContractError> {
-        storage::extend_instance_ttl(&env);
-        require_council_auth(&env);
-
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::DeactivateProgram`).
+    fn deactivate_program(env: Env, program_id: u64) -> Result<(), ContractError> {
         let mut program = storage::get_spending_program(&env, program_id)
             .ok_or(ContractError::ProgramNotFound)?;
 
         program.active = false;
         storage::set_spending_program(&env, program_id, program);
 
-        env.events().publish(
-            (
-                soroban_sdk::Symbol::new(&env, "treasury"),
-                soroban_sdk::Symbol::new(&env, "deactivate_program"),
-            ),
-            (program_id,),
-        );
+        EVENTS.publish(&env, "deactivate_program", (program_id,));
 
         Ok(())
     }
@@ -316,7 +354,9 @@ impl TreasuryContract {
         storage::get_spending_program(&env, program_id).ok_or(ContractError::ProgramNotFound)
     }
 
-    /// Allocate treasury funds to a spending program (admin only).
+    /// Allocate treasury funds to a spending program.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::Allocate`).
     ///
     /// # Parameters
     /// - `env`: Soroban environment for the current invocation.
@@ -324,16 +364,15 @@ impl TreasuryContract {
     /// - `amount`: Amount to allocate. Must be greater than zero.
     ///
     /// # Errors
+    /// - `ContractError::Paused` if the council has paused this function.
     /// - `ContractError::InvalidAmount` if `amount` is zero or negative.
     /// - `ContractError::ProgramNotFound` if the program does not exist.
     /// - `ContractError::ProgramInactive` if the program is not active.
     /// - `ContractError::ProgramOverBudget` if the allocation exceeds budget.
     /// - `ContractError::InsufficientBalance` if treasury balance is too low.
     /// - `ContractError::Overflow` if arithmetic overflows.
-    pub fn allocate(env: Env, program_id: u64, amount: i128) -> Result<(), ContractError> {
-        storage::extend_instance_ttl(&env);
-        require_council_auth(&env);
-
+    fn allocate(env: Env, program_id: u64, amount: i128) -> Result<(), ContractError> {
+        require_not_paused(&env, PausableFn::Allocate)?;
         if amount <= 0 {
             return Err(ContractError::InvalidAmount);
         }
@@ -393,17 +432,686 @@ impl TreasuryContract {
         // let token = token::Client::new(&env, &storage::get_token_address(&env));
         // token.transfer(&env.current_contract_address(), &program_recipient_address, &amount);
 
-        env.events().publish(
-            (
-                soroban_sdk::Symbol::new(&env, "treasury"),
-                soroban_sdk::Symbol::new(&env, "allocate"),
-            ),
-            (program_id, amount),
+        EVENTS.publish(&env, "allocate", (program_id, amount));
+
+        Ok(())
+    }
+
+    /// Add a new member to the admin council.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::AddMember`).
+    ///
+    /// # Errors
+    /// - `ContractError::InvalidCouncilConfig` if `member` is already on the council or the
+    ///   council would exceed `MAX_COUNCIL_MEMBERS`.
+    fn add_member(env: Env, member: Address) -> Result<(), ContractError> {
+        let mut council = storage::get_admin_council(&env);
+        council.add_member(&member)?;
+        storage::set_admin_council(&env, &council);
+
+        EVENTS.member_added(&env, &member, council.members.len());
+
+        Ok(())
+    }
+
+    /// Remove a member from the admin council.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::RemoveMember`).
+    ///
+    /// # Errors
+    /// - `ContractError::NotCouncilMember` if `member` is not on the council.
+    /// - `ContractError::InvalidCouncilConfig` if the threshold would exceed the remaining members.
+    fn remove_member(env: Env, member: Address) -> Result<(), ContractError> {
+        let mut council = storage::get_admin_council(&env);
+        council.remove_member(&member)?;
+        storage::set_admin_council(&env, &council);
+
+        EVENTS.member_removed(&env, &member, council.members.len());
+
+        Ok(())
+    }
+
+    /// Swap a council member for a new address, e.g. after a key loss or compromise.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::ReplaceMember`).
+    ///
+    /// # Errors
+    /// - `ContractError::NotCouncilMember` if `old_member` is not on the council.
+    /// - `ContractError::InvalidCouncilConfig` if `new_member` is already on the council.
+    fn replace_member(
+        env: Env,
+        old_member: Address,
+        new_member: Address,
+    ) -> Result<(), ContractError> {
+        let mut council = storage::get_admin_council(&env);
+        council.replace_member(&old_member, &new_member)?;
+        storage::set_admin_council(&env, &council);
+
+        EVENTS.member_replaced(&env, &old_member, &new_member);
+
+        Ok(())
+    }
+
+    /// Change the number of approvals required to execute a council proposal.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::SetThreshold`).
+    ///
+    /// # Errors
+    /// - `ContractError::InvalidCouncilConfig` if `threshold` is zero or exceeds the member count.
+    fn set_threshold(env: Env, threshold: u32) -> Result<(), ContractError> {
+        let mut council = storage::get_admin_council(&env);
+        council.set_threshold(threshold)?;
+        storage::set_admin_council(&env, &council);
+
+        EVENTS.threshold_changed(&env, threshold);
+
+        Ok(())
+    }
+
+    /// Replace the contract's WASM with a previously uploaded build.
+    ///
+    /// The new code takes effect once this invocation completes; `migrate` must
+    /// then be called to bring storage up to the new build's `SCHEMA_VERSION`.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::Upgrade`).
+    fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), ContractError> {
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        EVENTS.upgraded(&env, &new_wasm_hash, storage::get_schema_version(&env));
+
+        Ok(())
+    }
+
+    /// Immediately halt one or more entrypoints in an emergency.
+    ///
+    /// Any single council member or guardian may pause, so a halt does not wait
+    /// for a proposal to collect approvals. After the handover to governance the
+    /// council can no longer pause, but guardians and the governance contract
+    /// can. Lifting a pause requires an executed `ProposalAction::Unpause`.
+    /// Entrypoints that are not listed keep working, so users can still exit
+    /// while the affected path is halted.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `member`: Council member, guardian or governance contract triggering the pause.
+    ///   Must authorize this call.
+    /// - `functions`: Entrypoints to pause.
+    ///
+    /// # Errors
+    /// - `ContractError::CouncilRetired` if authority has moved to the governance contract
+    ///   and `member` is neither a guardian nor the governance contract.
+    /// - `ContractError::NotCouncilMember` if `member` is not on the admin council or a guardian.
+    pub fn pause(
+        env: Env,
+        member: Address,
+        functions: Vec<PausableFn>,
+    ) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        member.require_auth();
+        require_pauser(&env, &member)?;
+
+        for function in functions.iter() {
+            storage::set_paused(&env, &function, true);
+        }
+
+        EVENTS.paused(&env, &member, functions);
+
+        Ok(())
+    }
+
+    /// Lift an emergency pause on the listed entrypoints.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::Unpause`).
+    fn unpause(env: Env, functions: Vec<PausableFn>) -> Result<(), ContractError> {
+        for function in functions.iter() {
+            storage::set_paused(&env, &function, false);
+        }
+
+        EVENTS.unpaused(&env, functions);
+
+        Ok(())
+    }
+
+    /// Drop a queued timelocked action before it is executed.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::CancelQueued`).
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    /// - `ContractError::ProposalNotQueued` if the proposal is not waiting in the queue.
+    fn cancel_queued(env: Env, proposal_id: u64) -> Result<(), ContractError> {
+        let mut proposal =
+            storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+        if proposal.status != ProposalStatus::Queued {
+            return Err(ContractError::ProposalNotQueued);
+        }
+
+        proposal.status = ProposalStatus::Cancelled;
+        storage::set_proposal(&env, &proposal);
+
+        EVENTS.queued_cancelled(&env, proposal_id);
+
+        Ok(())
+    }
+
+    /// Grant `role` to `account`.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::GrantRole`).
+    ///
+    /// # Errors
+    /// - `ContractError::RoleAlreadyGranted` if `account` already holds the role.
+    fn grant_role(env: Env, role: Role, account: Address) -> Result<(), ContractError> {
+        let mut holders = storage::get_role_holders(&env, &role);
+        roles::grant_role(&mut holders, &account)?;
+        storage::set_role_holders(&env, &role, &holders);
+
+        EVENTS.role_granted(&env, role, &account);
+
+        Ok(())
+    }
+
+    /// Revoke `role` from `account`.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::RevokeRole`).
+    ///
+    /// # Errors
+    /// - `ContractError::RoleNotHeld` if `account` does not hold the role.
+    fn revoke_role(env: Env, role: Role, account: Address) -> Result<(), ContractError> {
+        let mut holders = storage::get_role_holders(&env, &role);
+        roles::revoke_role(&mut holders, &account)?;
+        storage::set_role_holders(&env, &role, &holders);
+
+        EVENTS.role_revoked(&env, role, &account);
+
+        Ok(())
+    }
+
+    /// Replace the guardian set that may veto council proposals.
+    ///
+    /// Only reachable through an executed council proposal (`ProposalAction::SetGuardians`),
+    /// which is timelocked so the outgoing guardians can veto their own removal.
+    ///
+    /// # Errors
+    /// - `ContractError::InvalidGuardians` if the set has duplicates or more than `MAX_GUARDIANS` addresses.
+    fn set_guardians(env: Env, guardians: Vec<Address>) -> Result<(), ContractError> {
+        guardians::validate_guardians(&guardians)?;
+        storage::set_guardians(&env, &guardians);
+
+        EVENTS.guardians_set(&env, &guardians);
+
+        Ok(())
+    }
+
+    /// Addresses currently holding `role`.
+    pub fn get_role_holders(env: Env, role: Role) -> Vec<Address> {
+        storage::extend_instance_ttl(&env);
+        storage::get_role_holders(&env, &role)
+    }
+
+    /// Whether `account` currently holds `role`.
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        storage::extend_instance_ttl(&env);
+        storage::has_role(&env, &role, &account)
+    }
+
+    /// Addresses that may veto council proposals.
+    pub fn get_guardians(env: Env) -> Vec<Address> {
+        storage::extend_instance_ttl(&env);
+        storage::get_guardians(&env)
+    }
+
+    /// The guardian veto recorded for `proposal_id`, if it was vetoed.
+    pub fn get_veto(env: Env, proposal_id: u64) -> Option<VetoRecord> {
+        storage::extend_instance_ttl(&env);
+        storage::get_veto(&env, proposal_id)
+    }
+
+    /// A page of the action log, oldest first, starting at entry `start_id`.
+    ///
+    /// Returns at most `limit` entries, capped at `MAX_LOG_PAGE_SIZE`; an empty
+    /// page means `start_id` is past the end of the log.
+    pub fn get_action_log(env: Env, start_id: u64, limit: u32) -> Vec<ActionLogEntry> {
+        storage::extend_instance_ttl(&env);
+        let mut page = Vec::new(&env);
+        for id in log_page(start_id, limit, storage::get_action_log_count(&env)) {
+            if let Some(entry) = storage::get_action_log_entry(&env, id) {
+                page.push_back(entry);
+            }
+        }
+        page
+    }
+
+    /// Number of entries in the action log.
+    pub fn get_action_log_count(env: Env) -> u64 {
+        storage::extend_instance_ttl(&env);
+        storage::get_action_log_count(&env)
+    }
+
+    /// Whether `function` is currently paused. Intended for off-chain relays to poll.
+    pub fn is_paused(env: Env, function: PausableFn) -> bool {
+        storage::extend_instance_ttl(&env);
+        storage::is_paused(&env, &function)
+    }
+
+    /// Open a council proposal for a privileged action.
+    ///
+    /// The proposer's approval is recorded immediately, so with a threshold of 1
+    /// the proposal can be executed right away. Holders of the role covering
+    /// `action` (see `ProposalAction::required_role`) may also propose it; such a
+    /// proposal needs no further approvals.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `proposer`: Council member or role holder creating the proposal. Must authorize this call.
+    /// - `action`: The privileged action to apply once the proposal is approved.
+    ///
+    /// # Returns
+    /// The newly assigned `proposal_id`.
+    ///
+    /// # Errors
+    /// - `ContractError::CouncilRetired` if authority has moved to the governance contract
+    ///   and `proposer` does not hold the role covering `action`.
+    /// - `ContractError::NotCouncilMember` if `proposer` is neither on the admin council
+    ///   nor holds the role covering `action`.
+    pub fn propose(
+        env: Env,
+        proposer: Address,
+        action: ProposalAction,
+    ) -> Result<u64, ContractError> {
+        storage::extend_instance_ttl(&env);
+        proposer.require_auth();
+        if !has_role_for(&env, &action, &proposer) {
+            require_council_authority(&env)?;
+            storage::get_admin_council(&env).require_member(&proposer)?;
+        }
+
+        let (created_at, expires_at) = proposal_window(&env);
+        let proposal = Proposal {
+            id: storage::next_proposal_id(&env),
+            proposer: proposer.clone(),
+            action,
+            approvals: Vec::from_array(&env, [proposer.clone()]),
+            status: ProposalStatus::Pending,
+            created_at,
+            expires_at,
+        };
+        storage::set_proposal(&env, &proposal);
+
+        EVENTS.proposal_created(&env, &proposer, proposal.id, proposal.action);
+
+        Ok(proposal.id)
+    }
+
+    /// Approve a pending council proposal.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `approver`: Council member approving the proposal. Must authorize this call.
+    /// - `proposal_id`: ID of the proposal to approve.
+    ///
+    /// # Errors
+    /// - `ContractError::CouncilRetired` if authority has moved to the governance contract.
+    /// - `ContractError::NotCouncilMember` if `approver` is not on the admin council.
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    /// - `ContractError::ProposalNotPending` if the proposal was executed or cancelled.
+    /// - `ContractError::ProposalExpired` if the approval window has passed.
+    /// - `ContractError::AlreadyApproved` if `approver` already approved this proposal.
+    pub fn approve(env: Env, approver: Address, proposal_id: u64) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        require_council_authority(&env)?;
+        approver.require_auth();
+        let council = storage::get_admin_council(&env);
+        council.require_member(&approver)?;
+
+        let mut proposal = load_pending_proposal(&env, proposal_id)?;
+        council.record_approval(&mut proposal.approvals, &approver)?;
+        storage::set_proposal(&env, &proposal);
+
+        EVENTS.proposal_approved(&env, &approver, proposal_id, proposal.approvals.len());
+
+        Ok(())
+    }
+
+    /// Apply a council proposal once enough distinct members have approved it.
+    ///
+    /// Anyone may call this; only approvals from addresses that are still on the
+    /// council count towards the threshold. A proposal whose proposer still holds
+    /// the role covering its action skips the threshold check. Timelocked actions (see
+    /// `ProposalAction::is_timelocked`) take two calls: the first queues the
+    /// proposal with an eta `TIMELOCK_DELAY` ledgers out, and a call at or after
+    /// the eta applies it. Actions queued by `governance_execute` are applied the
+    /// same way once their eta is reached.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `proposal_id`: ID of the proposal to execute.
+    ///
+    /// # Errors
+    /// - `ContractError::CouncilRetired` if authority has moved to the governance contract
+    ///   and the proposer does not hold the role covering the action.
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    /// - `ContractError::ProposalNotPending` if the proposal was executed or cancelled.
+    /// - `ContractError::ProposalExpired` if the approval window has passed.
+    /// - `ContractError::InsufficientApprovals` if the council threshold is not met and
+    ///   the proposer does not hold the role covering the action.
+    /// - `ContractError::TimelockActive` if the proposal is queued and its eta has not been reached.
+    /// - Any error returned by the underlying action.
+    pub fn execute(env: Env, proposal_id: u64) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        let mut proposal =
+            storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+        let by_role = has_role_for(&env, &proposal.action, &proposal.proposer)
+            || is_governance(&env, &proposal.proposer);
+        if !by_role {
+            require_council_authority(&env)?;
+        }
+
+        if proposal.status == ProposalStatus::Queued {
+            let eta = storage::get_proposal_eta(&env, proposal_id)
+                .ok_or(ContractError::ProposalNotQueued)?;
+            if (env.ledger().sequence() as u64) < eta {
+                return Err(ContractError::TimelockActive);
+            }
+        } else {
+            require_open(&env, &proposal.status, proposal.expires_at)?;
+            if !by_role {
+                storage::get_admin_council(&env).require_threshold(&proposal.approvals)?;
+            }
+
+            if proposal.action.is_timelocked() {
+                let eta = timelock_eta(&env);
+                proposal.status = ProposalStatus::Queued;
+                storage::set_proposal(&env, &proposal);
+                storage::set_proposal_eta(&env, proposal_id, eta);
+
+                EVENTS.proposal_queued(&env, proposal_id, eta);
+
+                return Ok(());
+            }
+        }
+
+        proposal.status = ProposalStatus::Executed;
+        storage::set_proposal(&env, &proposal);
+
+        Self::apply_action(env.clone(), proposal.action.clone())?;
+        log_action(&env, Some(proposal_id), proposal.action, proposal.approvals);
+
+        EVENTS.proposal_executed(&env, proposal_id);
+
+        Ok(())
+    }
+
+    /// Apply a privileged action approved by the council or the governance contract.
+    fn apply_action(env: Env, action: ProposalAction) -> Result<(), ContractError> {
+        match action {
+            ProposalAction::Withdraw(to, amount, memo) => {
+                Self::withdraw(env.clone(), to, amount, memo)?
+            }
+            ProposalAction::CreateProgram(name, budget) => {
+                Self::create_program(env.clone(), name, budget)?;
+            }
+            ProposalAction::UpdateProgramBudget(program_id, new_budget) => {
+                Self::update_program_budget(env.clone(), program_id, new_budget)?
+            }
+            ProposalAction::DeactivateProgram(program_id) => {
+                Self::deactivate_program(env.clone(), program_id)?
+            }
+            ProposalAction::Allocate(program_id, amount) => {
+                Self::allocate(env.clone(), program_id, amount)?
+            }
+            ProposalAction::AddMember(member) => Self::add_member(env.clone(), member)?,
+            ProposalAction::RemoveMember(member) => Self::remove_member(env.clone(), member)?,
+            ProposalAction::ReplaceMember(old_member, new_member) => {
+                Self::replace_member(env.clone(), old_member, new_member)?
+            }
+            ProposalAction::SetThreshold(threshold) => Self::set_threshold(env.clone(), threshold)?,
+            ProposalAction::Upgrade(new_wasm_hash) => Self::upgrade(env.clone(), new_wasm_hash)?,
+            ProposalAction::Unpause(functions) => Self::unpause(env.clone(), functions)?,
+            ProposalAction::CancelQueued(queued_id) => Self::cancel_queued(env.clone(), queued_id)?,
+            ProposalAction::GrantRole(role, account) => {
+                Self::grant_role(env.clone(), role, account)?
+            }
+            ProposalAction::RevokeRole(role, account) => {
+                Self::revoke_role(env.clone(), role, account)?
+            }
+            ProposalAction::SetGuardians(guardians) => Self::set_guardians(env.clone(), guardians)?,
+            ProposalAction::SetGovernance(governance) => {
+                Self::set_governance(env.clone(), governance)?
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Hand treasury authority to a governance contract.
+    ///
+    /// From then on the council can no longer propose, approve, execute or
+    /// pause; every privileged action goes through `governance_execute`. A
+    /// governance contract can pass authority on to a successor the same way.
+    /// Roles granted by the outgoing authority are revoked, so the new one
+    /// starts with no delegated permissions and grants its own.
+    ///
+    /// Only reachable through an executed council proposal
+    /// (`ProposalAction::SetGovernance`) or through `governance_execute`.
+    fn set_governance(env: Env, governance: Address) -> Result<(), ContractError> {
+        storage::set_governance(&env, &governance);
+
+        let role = Role::GrantsManager;
+        for account in storage::get_role_holders(&env, &role).iter() {
+            EVENTS.role_revoked(&env, role.clone(), &account);
+        }
+        storage::set_role_holders(&env, &role, &Vec::new(&env));
+
+        EVENTS.publish(&env, "set_governance", (governance,));
+
+        Ok(())
+    }
+
+    /// Apply a privileged action on behalf of the governance contract.
+    ///
+    /// The governance contract runs its own voting rules, so no council
+    /// approvals are needed. The action is recorded as a proposal with the
+    /// governance contract as proposer. Timelocked actions (see
+    /// `ProposalAction::is_timelocked`) are queued like council proposals: the
+    /// guardians can veto them until the eta, after which anyone applies them
+    /// with `execute`. Other actions are applied immediately.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `action`: The privileged action to apply or queue.
+    ///
+    /// # Returns
+    /// The `proposal_id` recording the action.
+    ///
+    /// # Errors
+    /// - `ContractError::Unauthorized` if authority has not been handed to a governance contract.
+    /// - Any error returned by the underlying action.
+    pub fn governance_execute(env: Env, action: ProposalAction) -> Result<u64, ContractError> {
+        storage::extend_instance_ttl(&env);
+        let governance = storage::get_governance(&env).ok_or(ContractError::Unauthorized)?;
+        governance.require_auth();
+
+        let (created_at, expires_at) = proposal_window(&env);
+        let mut proposal = Proposal {
+            id: storage::next_proposal_id(&env),
+            proposer: governance.clone(),
+            action: action.clone(),
+            approvals: Vec::from_array(&env, [governance.clone()]),
+            status: ProposalStatus::Executed,
+            created_at,
+            expires_at,
+        };
+
+        if action.is_timelocked() {
+            let eta = timelock_eta(&env);
+            proposal.status = ProposalStatus::Queued;
+            storage::set_proposal(&env, &proposal);
+            storage::set_proposal_eta(&env, proposal.id, eta);
+
+            EVENTS.proposal_created(&env, &governance, proposal.id, action);
+            EVENTS.proposal_queued(&env, proposal.id, eta);
+
+            return Ok(proposal.id);
+        }
+
+        storage::set_proposal(&env, &proposal);
+
+        Self::apply_action(env.clone(), action.clone())?;
+        log_action(&env, Some(proposal.id), action.clone(), proposal.approvals);
+
+        EVENTS.publish(&env, "governance_execute", (governance, action));
+
+        Ok(proposal.id)
+    }
+
+    /// Governance contract holding treasury authority, or `None` while the council does.
+    pub fn get_governance(env: Env) -> Option<Address> {
+        storage::extend_instance_ttl(&env);
+        storage::get_governance(&env)
+    }
+
+    /// Veto a council proposal before its action is applied.
+    ///
+    /// Guardians cannot propose or approve; they can only stop a proposal while it
+    /// is pending and unexpired, or while it is queued and its eta has not been
+    /// reached. The proposal is marked `Vetoed` and can no longer be executed.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `guardian`: Guardian vetoing the proposal. Must authorize this call.
+    /// - `proposal_id`: ID of the proposal to veto.
+    /// - `reason`: Why the proposal is vetoed; stored with the veto and emitted as an event.
+    ///
+    /// # Errors
+    /// - `ContractError::NotGuardian` if `guardian` is not in the guardian set.
+    /// - `ContractError::InvalidVetoReason` if `reason` is empty or longer than `MAX_VETO_REASON_LEN`.
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    /// - `ContractError::VetoWindowClosed` if the proposal can no longer be vetoed.
+    pub fn veto(
+        env: Env,
+        guardian: Address,
+        proposal_id: u64,
+        reason: String,
+    ) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        guardian.require_auth();
+        guardians::require_guardian(&storage::get_guardians(&env), &guardian)?;
+        guardians::validate_reason(&reason)?;
+
+        let mut proposal =
+            storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+        guardians::require_vetoable(
+            &env,
+            &proposal.status,
+            proposal.expires_at,
+            storage::get_proposal_eta(&env, proposal_id),
+        )?;
+
+        proposal.status = ProposalStatus::Vetoed;
+        storage::set_proposal(&env, &proposal);
+        storage::set_veto(
+            &env,
+            proposal_id,
+            &VetoRecord {
+                guardian: guardian.clone(),
+                reason: reason.clone(),
+                vetoed_at: env.ledger().sequence() as u64,
+            },
         );
 
+        EVENTS.proposal_vetoed(&env, &guardian, proposal_id, &reason);
+
+        Ok(())
+    }
+
+    /// Withdraw a pending proposal. Only the original proposer may cancel it.
+    ///
+    /// # Parameters
+    /// - `env`: Soroban environment for the current contract invocation.
+    /// - `proposer`: The address that created the proposal. Must authorize this call.
+    /// - `proposal_id`: ID of the proposal to cancel.
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    /// - `ContractError::ProposalNotPending` if the proposal was executed or cancelled.
+    /// - `ContractError::Unauthorized` if `proposer` did not create the proposal.
+    pub fn cancel(env: Env, proposer: Address, proposal_id: u64) -> Result<(), ContractError> {
+        storage::extend_instance_ttl(&env);
+        proposer.require_auth();
+
+        let mut proposal =
+            storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+        if proposal.status != ProposalStatus::Pending {
+            return Err(ContractError::ProposalNotPending);
+        }
+        if proposal.proposer != proposer {
+            return Err(ContractError::Unauthorized);
+        }
+
+        proposal.status = ProposalStatus::Cancelled;
+        storage::set_proposal(&env, &proposal);
+
+        EVENTS.proposal_cancelled(&env, &proposer, proposal_id);
+
         Ok(())
     }
 
+    /// Fetch a council proposal by its ID.
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotFound` if no proposal exists for this ID.
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Result<Proposal, ContractError> {
+        storage::extend_instance_ttl(&env);
+        storage::get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)
+    }
+
+    /// Earliest ledger sequence at which a queued proposal can be executed.
+    ///
+    /// # Errors
+    /// - `ContractError::ProposalNotQueued` if the proposal was never queued.
+    pub fn get_proposal_eta(env: Env, proposal_id: u64) -> Result<u64, ContractError> {
+        storage::extend_instance_ttl(&env);
+        storage::get_proposal_eta(&env, proposal_id).ok_or(ContractError::ProposalNotQueued)
+    }
+
+    /// Bring stored data up to this build's `SCHEMA_VERSION` after an upgrade.
+    ///
+    /// Permissionless and idempotent: each step rewrites one schema version into
+    /// the next, and calling it on an up-to-date contract changes nothing.
+    ///
+    /// # Returns
+    /// The schema version after migration.
+    ///
+    /// # Errors
+    /// - `ContractError::UnsupportedSchemaVersion` if storage was written by a newer build.
+    pub fn migrate(env: Env) -> Result<u32, ContractError> {
+        storage::extend_instance_ttl(&env);
+        let from = storage::get_schema_version(&env);
+        if from > storage::SCHEMA_VERSION {
+            return Err(ContractError::UnsupportedSchemaVersion);
+        }
+
+        let mut version = from;
+        while version < storage::SCHEMA_VERSION {
+            migrate_step(&env, version)?;
+            version += 1;
+        }
+
+        if version != from {
+            storage::set_schema_version(&env, version);
+            EVENTS.migrated(&env, from, version);
+        }
+
+        Ok(version)
+    }
+
+    /// Storage schema version currently recorded by the contract.
+    pub fn version(env: Env) -> u32 {
+        storage::extend_instance_ttl(&env);
+        storage::get_schema_version(&env)
+    }
+
     /// Returns aggregate statistics for the treasury.
     ///
     /// This is a read-only view function intended for dashboard integration.
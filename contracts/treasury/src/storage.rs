@@ -10,6 +10,7 @@
 //! - `DataKey::Allocation(String)` — An `AllocationRecord` keyed by program name
 //! - `DataKey::Admin` — Address authorized to perform withdrawals and allocations
 //! - `DataKey::TokenAddress` — The SAC (Stellar Asset Contract) address for the treasury token
+//! - `DataKey::Proposal(u64)` — A council `Proposal` keyed by proposal_id
 //!
 //! ## Functions to implement
 //! - `get_balance(env) -> i128` — Load the current treasury balance
@@ -24,20 +25,19 @@
 //!
 //! implementation tracked in GitHub issue
 
-use soroban_sdk::{contracttype, Address, Env, String};
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
 
-use crate::types::{AdminCouncil, AllocationRecord, SpendingProgram, TreasuryEntry, TreasuryStats};
+use crate::types::{
+    ActionLogEntry, AdminCouncil, AllocationRecord, PausableFn, Proposal, Role, SpendingProgram,
+    TreasuryEntry, TreasuryStats, VetoRecord,
+};
 
-// Bump by ~30 days (assuming ~5 seconds per ledger)
-const LEDGER_BUMP_AMOUNT: u32 = 518_400;
-// Bump if remaining life is less than ~15 days
-const LEDGER_BUMP_THRESHOLD: u32 = 259_200;
+pub use conduit_common::ttl::extend_instance_ttl;
+use conduit_common::ttl::extend_persistent_ttl;
 
-pub fn extend_instance_ttl(env: &Env) {
-    env.storage()
-        .instance()
-        .extend_ttl(LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
-}
+/// Storage layout version written by this build. Bump it together with a new
+/// step in `migrate_step` whenever a persisted layout changes.
+pub const SCHEMA_VERSION: u32 = 1;
 
 /// Storage keys for the treasury contract.
 #[contracttype]
@@ -61,6 +61,28 @@ pub enum DataKey {
     TokenAddress,
     /// Aggregate treasury statistics for dashboard integration.
     Stats,
+    /// A council Proposal keyed by proposal_id.
+    Proposal(u64),
+    /// Total number of created proposals.
+    ProposalCount,
+    /// Earliest execution ledger of a queued proposal, keyed by proposal_id.
+    ProposalEta(u64),
+    /// Storage schema version of the persisted data.
+    SchemaVersion,
+    /// Set while the given entrypoint is paused.
+    Paused(PausableFn),
+    /// Addresses holding the given role.
+    RoleHolders(Role),
+    /// Addresses that may veto council proposals.
+    Guardians,
+    /// A guardian's veto, keyed by proposal_id.
+    Veto(u64),
+    /// Governance contract holding treasury authority after handover.
+    Governance,
+    /// An applied privileged action, keyed by its position in the action log.
+    ActionLog(u64),
+    /// Number of entries in the action log.
+    ActionLogCount,
 }
 
 pub fn get_balance(env: &Env) -> i128 {
@@ -74,9 +96,7 @@ pub fn set_balance(env: &Env, balance: i128) {
 pub fn get_entry(env: &Env, entry_id: u64) -> Option<TreasuryEntry> {
     let key = DataKey::Entry(entry_id);
     if let Some(entry) = env.storage().persistent().get::<_, TreasuryEntry>(&key) {
-        env.storage()
-            .persistent()
-            .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+        extend_persistent_ttl(env, &key);
         Some(entry)
     } else {
         None
@@ -89,9 +109,7 @@ pub fn set_entry(env: &Env, entry: TreasuryEntry) {
     let next_id = count + 1;
     let key = DataKey::Entry(next_id);
     env.storage().persistent().set(&key, &entry);
-    env.storage()
-        .persistent()
-        .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+    extend_persistent_ttl(env, &key);
     env.storage().instance().set(&DataKey::EntryCount, &next_id);
 }
 
@@ -113,18 +131,14 @@ pub fn append_entry(env: &Env, entry: &TreasuryEntry) {
         .expect("entry count overflow");
     let key = DataKey::Entry(next_id);
     env.storage().persistent().set(&key, entry);
-    env.storage()
-        .persistent()
-        .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+    extend_persistent_ttl(env, &key);
     set_entry_count(env, next_id);
 }
 
 pub fn get_allocation(env: &Env, program: &String) -> Option<AllocationRecord> {
     let key = DataKey::Allocation(program.clone());
     if let Some(record) = env.storage().persistent().get::<_, AllocationRecord>(&key) {
-        env.storage()
-            .persistent()
-            .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+        extend_persistent_ttl(env, &key);
         Some(record)
     } else {
         None
@@ -134,18 +148,14 @@ pub fn get_allocation(env: &Env, program: &String) -> Option<AllocationRecord> {
 pub fn set_allocation(env: &Env, program: &String, record: &AllocationRecord) {
     let key = DataKey::Allocation(program.clone());
     env.storage().persistent().set(&key, record);
-    env.storage()
-        .persistent()
-        .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+    extend_persistent_ttl(env, &key);
 }
 
 /// Load a spending program by ID.
 pub fn get_spending_program(env: &Env, program_id: u64) -> Option<SpendingProgram> {
     let key = DataKey::SpendingProgram(program_id);
     if let Some(program) = env.storage().persistent().get::<_, SpendingProgram>(&key) {
-        env.storage()
-            .persistent()
-            .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+        extend_persistent_ttl(env, &key);
         Some(program)
     } else {
         None
@@ -172,9 +182,7 @@ pub fn increment_program_count(env: &Env) -> u64 {
 pub fn set_spending_program(env: &Env, program_id: u64, program: SpendingProgram) {
     let key = DataKey::SpendingProgram(program_id);
     env.storage().persistent().set(&key, &program);
-    env.storage()
-        .persistent()
-        .extend_ttl(&key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+    extend_persistent_ttl(env, &key);
 }
 
 /// Load the treasury admin council.
@@ -229,3 +237,166 @@ pub fn get_stats(env: &Env) -> TreasuryStats {
 pub fn set_stats(env: &Env, stats: &TreasuryStats) {
     env.storage().instance().set(&DataKey::Stats, stats);
 }
+
+/// Load a council proposal by ID.
+pub fn get_proposal(env: &Env, proposal_id: u64) -> Option<Proposal> {
+    let key = DataKey::Proposal(proposal_id);
+    if let Some(proposal) = env.storage().persistent().get::<_, Proposal>(&key) {
+        extend_persistent_ttl(env, &key);
+        Some(proposal)
+    } else {
+        None
+    }
+}
+
+/// Persist a council proposal under its own ID.
+pub fn set_proposal(env: &Env, proposal: &Proposal) {
+    let key = DataKey::Proposal(proposal.id);
+    env.storage().persistent().set(&key, proposal);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn get_proposal_eta(env: &Env, proposal_id: u64) -> Option<u64> {
+    let key = DataKey::ProposalEta(proposal_id);
+    let eta = env.storage().persistent().get::<_, u64>(&key);
+    if eta.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    eta
+}
+
+pub fn set_proposal_eta(env: &Env, proposal_id: u64, eta: u64) {
+    let key = DataKey::ProposalEta(proposal_id);
+    env.storage().persistent().set(&key, &eta);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Increment and return the next available proposal ID.
+pub fn next_proposal_id(env: &Env) -> u64 {
+    let next = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&DataKey::ProposalCount)
+        .unwrap_or(0)
+        .checked_add(1)
+        .expect("proposal count overflow");
+    env.storage().instance().set(&DataKey::ProposalCount, &next);
+    next
+}
+
+/// Schema version of the stored data. Deployments that predate version
+/// tracking use the version 1 layout.
+pub fn get_schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(1)
+}
+
+pub fn set_schema_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SchemaVersion, &version);
+}
+
+pub fn is_paused(env: &Env, function: &PausableFn) -> bool {
+    env.storage()
+        .instance()
+        .has(&DataKey::Paused(function.clone()))
+}
+
+pub fn set_paused(env: &Env, function: &PausableFn, paused: bool) {
+    let key = DataKey::Paused(function.clone());
+    if paused {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+}
+
+/// Governance contract that holds treasury authority, if the council handed it over.
+pub fn get_governance(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Governance)
+}
+
+pub fn set_governance(env: &Env, governance: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Governance, governance);
+}
+
+/// Addresses holding `role`, in the order they were granted it.
+pub fn get_role_holders(env: &Env, role: &Role) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::RoleHolders(role.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_role_holders(env: &Env, role: &Role, holders: &Vec<Address>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RoleHolders(role.clone()), holders);
+}
+
+pub fn has_role(env: &Env, role: &Role, account: &Address) -> bool {
+    get_role_holders(env, role).contains(account)
+}
+
+pub fn get_guardians(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Guardians)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_guardians(env: &Env, guardians: &Vec<Address>) {
+    env.storage().instance().set(&DataKey::Guardians, guardians);
+}
+
+pub fn get_veto(env: &Env, proposal_id: u64) -> Option<VetoRecord> {
+    let key = DataKey::Veto(proposal_id);
+    let veto = env.storage().persistent().get::<_, VetoRecord>(&key);
+    if veto.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    veto
+}
+
+pub fn set_veto(env: &Env, proposal_id: u64, veto: &VetoRecord) {
+    let key = DataKey::Veto(proposal_id);
+    env.storage().persistent().set(&key, veto);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn get_action_log_entry(env: &Env, id: u64) -> Option<ActionLogEntry> {
+    let key = DataKey::ActionLog(id);
+    let entry = env.storage().persistent().get::<_, ActionLogEntry>(&key);
+    if entry.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    entry
+}
+
+pub fn set_action_log_entry(env: &Env, entry: &ActionLogEntry) {
+    let key = DataKey::ActionLog(entry.id);
+    env.storage().persistent().set(&key, entry);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn get_action_log_count(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ActionLogCount)
+        .unwrap_or(0)
+}
+
+pub fn next_action_log_id(env: &Env) -> u64 {
+    let next = get_action_log_count(env)
+        .checked_add(1)
+        .expect("action log count overflow");
+    env.storage()
+        .instance()
+        .set(&DataKey::ActionLogCount, &next);
+    next
+}
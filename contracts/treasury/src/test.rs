@@ -4,13 +4,18 @@
 
 extern crate std;
 
+use conduit_common::council::TIMELOCK_DELAY;
 use soroban_sdk::{
-    testutils::{Address as _, Events as _},
+    testutils::{Address as _, Events as _, Ledger},
     Address, Env, String,
 };
 
 // The contract-under-test.
-use crate::{storage, types::SpendingProgram, TreasuryContract, TreasuryContractClient};
+use crate::{
+    storage,
+    types::{PausableFn, ProposalAction, ProposalStatus, Role, SpendingProgram},
+    TreasuryContract, TreasuryContractClient,
+};
 
 use soroban_sdk::token::StellarAssetClient;
 
@@ -26,6 +31,42 @@ fn create_treasury_contract<'a>(env: &Env) -> TreasuryContractClient<'a> {
     TreasuryContractClient::new(env, &contract_id)
 }
 
+/// Helper: propose `action` as `admin` and execute it (single-member council),
+/// waiting out the timelock if the action is queued.
+fn council_execute(client: &TreasuryContractClient, admin: &Address, action: &ProposalAction) {
+    let proposal_id = client.propose(admin, action);
+    client.execute(&proposal_id);
+    if client.get_proposal(&proposal_id).status == ProposalStatus::Queued {
+        client
+            .env
+            .ledger()
+            .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+        client.execute(&proposal_id);
+    }
+}
+
+/// Helper: like `council_execute`, but returns the contract error instead of panicking.
+fn try_council_execute(
+    client: &TreasuryContractClient,
+    admin: &Address,
+    action: &ProposalAction,
+) -> Result<(), crate::errors::ContractError> {
+    let proposal_id = client.propose(admin, action);
+    let mut result = client.try_execute(&proposal_id);
+    if result.is_ok() && client.get_proposal(&proposal_id).status == ProposalStatus::Queued {
+        client
+            .env
+            .ledger()
+            .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+        result = client.try_execute(&proposal_id);
+    }
+    match result {
+        Ok(_) => Ok(()),
+        Err(Ok(err)) => Err(err),
+        Err(Err(err)) => panic!("unexpected invoke error: {:?}", err),
+    }
+}
+
 /// Helper: read the treasury balance from inside the contract's storage context.
 fn balance_of(env: &Env, contract: &Address) -> i128 {
     env.as_contract(contract, || storage::get_balance(env))
@@ -109,7 +150,11 @@ fn test_withdraw_by_admin() {
     });
     client.deposit(&admin, &5000);
 
-    client.withdraw(&to, &1000, &String::from_str(&env, "test"));
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::Withdraw(to.clone(), 1000, String::from_str(&env, "test")),
+    );
     assert_eq!(balance_of(&env, &client.address), 4000);
 
     let token_balance = soroban_sdk::token::Client::new(&env, &token_address).balance(&to);
@@ -120,7 +165,7 @@ fn test_withdraw_by_admin() {
 #[should_panic]
 fn test_withdraw_unauthorized() {
     let env = Env::default();
-    // Deliberately no mock_all_auths — admin.require_auth() inside withdraw will panic.
+    // Deliberately no mock_all_auths — admin.require_auth() inside propose will panic.
     let client = create_treasury_contract(&env);
     let admin = Address::generate(&env);
     let to = Address::generate(&env);
@@ -135,7 +180,11 @@ fn test_withdraw_unauthorized() {
         storage::set_admin_council(&env, &council);
         storage::set_token_address(&env, &token_address);
     });
-    client.withdraw(&to, &1000, &String::from_str(&env, "test"));
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::Withdraw(to.clone(), 1000, String::from_str(&env, "test")),
+    );
 }
 
 #[test]
@@ -161,11 +210,12 @@ fn test_withdraw_insufficient_balance() {
     });
     client.deposit(&admin, &100);
 
-    let res = client.try_withdraw(&to, &200, &String::from_str(&env, "test"));
-    assert_eq!(
-        res,
-        Err(Ok(crate::errors::ContractError::InsufficientBalance))
+    let res = try_council_execute(
+        &client,
+        &admin,
+        &ProposalAction::Withdraw(to.clone(), 200, String::from_str(&env, "test")),
     );
+    assert_eq!(res, Err(crate::errors::ContractError::InsufficientBalance));
 }
 
 #[test]
@@ -198,7 +248,7 @@ fn test_allocate_by_admin() {
     });
     client.deposit(&admin, &10000);
 
-    client.allocate(&1, &2000);
+    council_execute(&client, &admin, &ProposalAction::Allocate(1, 2000));
 
     let (spent, bal) = env.as_contract(&client.address, || {
         let prog = storage::get_spending_program(&env, 1).unwrap();
@@ -230,8 +280,8 @@ fn test_allocate_program_not_found() {
     });
     client.deposit(&admin, &10000);
 
-    let res = client.try_allocate(&99, &1000);
-    assert_eq!(res, Err(Ok(crate::errors::ContractError::ProgramNotFound)));
+    let res = try_council_execute(&client, &admin, &ProposalAction::Allocate(99, 1000));
+    assert_eq!(res, Err(crate::errors::ContractError::ProgramNotFound));
 }
 
 #[test]
@@ -264,8 +314,8 @@ fn test_allocate_program_inactive() {
     });
     client.deposit(&admin, &10000);
 
-    let res = client.try_allocate(&1, &1000);
-    assert_eq!(res, Err(Ok(crate::errors::ContractError::ProgramInactive)));
+    let res = try_council_execute(&client, &admin, &ProposalAction::Allocate(1, 1000));
+    assert_eq!(res, Err(crate::errors::ContractError::ProgramInactive));
 }
 
 #[test]
@@ -298,11 +348,8 @@ fn test_allocate_over_budget() {
     });
     client.deposit(&admin, &10000);
 
-    let res = client.try_allocate(&1, &1500);
-    assert_eq!(
-        res,
-        Err(Ok(crate::errors::ContractError::ProgramOverBudget))
-    );
+    let res = try_council_execute(&client, &admin, &ProposalAction::Allocate(1, 1500));
+    assert_eq!(res, Err(crate::errors::ContractError::ProgramOverBudget));
 }
 
 #[test]
@@ -335,11 +382,8 @@ fn test_allocate_insufficient_treasury_balance() {
     });
     client.deposit(&admin, &3000); // Not enough for the allocation
 
-    let res = client.try_allocate(&1, &5000);
-    assert_eq!(
-        res,
-        Err(Ok(crate::errors::ContractError::InsufficientBalance))
-    );
+    let res = try_council_execute(&client, &admin, &ProposalAction::Allocate(1, 5000));
+    assert_eq!(res, Err(crate::errors::ContractError::InsufficientBalance));
 }
 
 #[test]
@@ -407,7 +451,11 @@ fn test_get_treasury_stats_after_withdraw() {
     });
 
     client.deposit(&admin, &5000);
-    client.withdraw(&to, &1500, &String::from_str(&env, "test withdraw"));
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::Withdraw(to.clone(), 1500, String::from_str(&env, "test withdraw")),
+    );
 
     let stats = client.get_treasury_stats();
     assert_eq!(stats.current_balance, 3500);
@@ -446,7 +494,7 @@ fn test_get_treasury_stats_after_allocate() {
     });
 
     client.deposit(&admin, &10000);
-    client.allocate(&1, &2000);
+    council_execute(&client, &admin, &ProposalAction::Allocate(1, 2000));
 
     let stats = client.get_treasury_stats();
     assert_eq!(stats.current_balance, 8000);
@@ -490,10 +538,14 @@ fn test_get_treasury_stats_full_lifecycle() {
     client.deposit(&admin, &5000);
 
     // Withdrawal
-    client.withdraw(&to, &3000, &String::from_str(&env, "grant"));
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::Withdraw(to.clone(), 3000, String::from_str(&env, "grant")),
+    );
 
     // Allocation
-    client.allocate(&1, &4000);
+    council_execute(&client, &admin, &ProposalAction::Allocate(1, 4000));
 
     // Final stats check
     let stats = client.get_treasury_stats();
@@ -502,3 +554,455 @@ fn test_get_treasury_stats_full_lifecycle() {
     assert_eq!(stats.lifetime_withdrawn, 3000);
     assert_eq!(stats.lifetime_allocated, 4000);
 }
+
+#[test]
+fn test_remove_member_below_threshold_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_treasury_contract(&env);
+    let admin = Address::generate(&env);
+    let second = Address::generate(&env);
+    let (_, token_address) = create_token_contract(&env, &admin);
+
+    let council = crate::types::AdminCouncil {
+        members: soroban_sdk::Vec::from_array(&env, [admin.clone(), second.clone()]),
+        threshold: 2,
+    };
+    client.initialize(&council, &token_address);
+
+    let proposal_id = client.propose(&admin, &ProposalAction::RemoveMember(second.clone()));
+    client.approve(&second, &proposal_id);
+    client.execute(&proposal_id);
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    assert_eq!(
+        client.try_execute(&proposal_id),
+        Err(Ok(crate::errors::ContractError::InvalidCouncilConfig))
+    );
+
+    let proposal_id = client.propose(&admin, &ProposalAction::SetThreshold(1));
+    client.approve(&second, &proposal_id);
+    client.execute(&proposal_id);
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    client.execute(&proposal_id);
+    assert_eq!(
+        try_council_execute(&client, &admin, &ProposalAction::RemoveMember(second)),
+        Ok(())
+    );
+    let council = env.as_contract(&client.address, || storage::get_admin_council(&env));
+    assert_eq!(council.members.len(), 1);
+}
+
+#[test]
+fn test_pause_withdraw_blocks_proposal_execution() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_treasury_contract(&env);
+    let admin = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    let (token_client, token_address) = create_token_contract(&env, &admin);
+    token_client.mint(&admin, &10000);
+    let council = crate::types::AdminCouncil {
+        members: soroban_sdk::Vec::from_array(&env, [admin.clone()]),
+        threshold: 1,
+    };
+    client.initialize(&council, &token_address);
+    client.deposit(&admin, &5000);
+
+    let halted = soroban_sdk::Vec::from_array(&env, [PausableFn::Withdraw]);
+    client.pause(&admin, &halted);
+
+    let withdraw = ProposalAction::Withdraw(to.clone(), 1000, String::from_str(&env, "test"));
+    assert_eq!(
+        try_council_execute(&client, &admin, &withdraw),
+        Err(crate::errors::ContractError::Paused)
+    );
+    // Deposits are unaffected.
+    client.deposit(&admin, &1000);
+
+    council_execute(&client, &admin, &ProposalAction::Unpause(halted));
+    council_execute(&client, &admin, &withdraw);
+    assert_eq!(balance_of(&env, &client.address), 5000);
+}
+
+#[test]
+fn test_withdraw_waits_for_timelock_and_can_be_cancelled() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_treasury_contract(&env);
+    let admin = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    let (token_client, token_address) = create_token_contract(&env, &admin);
+    token_client.mint(&admin, &10000);
+    let council = crate::types::AdminCouncil {
+        members: soroban_sdk::Vec::from_array(&env, [admin.clone()]),
+        threshold: 1,
+    };
+    client.initialize(&council, &token_address);
+    client.deposit(&admin, &5000);
+
+    let withdraw = ProposalAction::Withdraw(to.clone(), 1000, String::from_str(&env, "test"));
+    let queued = client.propose(&admin, &withdraw);
+    client.execute(&queued);
+    assert_eq!(client.get_proposal(&queued).status, ProposalStatus::Queued);
+    assert_eq!(
+        client.get_proposal_eta(&queued),
+        env.ledger().sequence() as u64 + TIMELOCK_DELAY as u64
+    );
+    assert_eq!(
+        client.try_execute(&queued),
+        Err(Ok(crate::errors::ContractError::TimelockActive))
+    );
+    assert_eq!(balance_of(&env, &client.address), 5000);
+
+    // The council cancels the queued withdrawal before the delay elapses.
+    council_execute(&client, &admin, &ProposalAction::CancelQueued(queued));
+    assert_eq!(
+        client.get_proposal(&queued).status,
+        ProposalStatus::Cancelled
+    );
+
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    assert_eq!(
+        client.try_execute(&queued),
+        Err(Ok(crate::errors::ContractError::ProposalNotPending))
+    );
+    assert_eq!(balance_of(&env, &client.address), 5000);
+
+    // A fresh withdrawal goes through once its own delay has passed.
+    council_execute(&client, &admin, &withdraw);
+    assert_eq!(balance_of(&env, &client.address), 4000);
+}
+
+#[test]
+fn test_set_governance_hands_authority_to_governance_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_treasury_contract(&env);
+    let admin = Address::generate(&env);
+    let governance = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    let (token_client, token_address) = create_token_contract(&env, &admin);
+    token_client.mint(&admin, &10000);
+    let council = crate::types::AdminCouncil {
+        members: soroban_sdk::Vec::from_array(&env, [admin.clone()]),
+        threshold: 1,
+    };
+    client.initialize(&council, &token_address);
+    client.deposit(&admin, &5000);
+
+    let withdraw = ProposalAction::Withdraw(to.clone(), 1000, String::from_str(&env, "grant"));
+    assert_eq!(
+        client.try_governance_execute(&withdraw),
+        Err(Ok(crate::errors::ContractError::Unauthorized))
+    );
+
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::SetGovernance(governance.clone()),
+    );
+    assert_eq!(client.get_governance(), Some(governance.clone()));
+
+    // The council is retired once governance holds authority.
+    assert_eq!(
+        client.try_propose(&admin, &withdraw),
+        Err(Ok(crate::errors::ContractError::CouncilRetired))
+    );
+    assert_eq!(
+        client.try_pause(
+            &admin,
+            &soroban_sdk::Vec::from_array(&env, [PausableFn::Deposit])
+        ),
+        Err(Ok(crate::errors::ContractError::CouncilRetired))
+    );
+
+    // The governance contract keeps an emergency stop.
+    let halted = soroban_sdk::Vec::from_array(&env, [PausableFn::Deposit]);
+    client.pause(&governance, &halted);
+    assert!(client.is_paused(&PausableFn::Deposit));
+    client.governance_execute(&ProposalAction::Unpause(halted));
+    assert!(!client.is_paused(&PausableFn::Deposit));
+
+    // Withdrawals wait out the same timelock as council proposals.
+    let proposal_id = client.governance_execute(&withdraw);
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        ProposalStatus::Queued
+    );
+    assert_eq!(
+        client.try_execute(&proposal_id),
+        Err(Ok(crate::errors::ContractError::TimelockActive))
+    );
+    assert_eq!(client.get_balance(), 5000);
+
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    client.execute(&proposal_id);
+    assert_eq!(balance_of(&env, &client.address), 4000);
+    assert_eq!(client.get_balance(), 4000);
+}
+
+#[test]
+fn test_guardians_veto_and_pause_after_governance_handover() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_treasury_contract(&env);
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let governance = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    let (token_client, token_address) = create_token_contract(&env, &admin);
+    token_client.mint(&admin, &10000);
+    let council = crate::types::AdminCouncil {
+        members: soroban_sdk::Vec::from_array(&env, [admin.clone()]),
+        threshold: 1,
+    };
+    client.initialize(&council, &token_address);
+    client.deposit(&admin, &5000);
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::SetGuardians(soroban_sdk::Vec::from_array(&env, [guardian.clone()])),
+    );
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::SetGovernance(governance.clone()),
+    );
+
+    let reason = String::from_str(&env, "drain");
+    let withdraw_id = client.governance_execute(&ProposalAction::Withdraw(
+        to.clone(),
+        5000,
+        String::from_str(&env, "grant"),
+    ));
+    client.veto(&guardian, &withdraw_id, &reason);
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    assert_eq!(
+        client.try_execute(&withdraw_id),
+        Err(Ok(crate::errors::ContractError::ProposalNotPending))
+    );
+    assert_eq!(client.get_balance(), 5000);
+
+    // Replacing the guardians or the governance contract can be vetoed too.
+    let handover_id =
+        client.governance_execute(&ProposalAction::SetGovernance(Address::generate(&env)));
+    client.veto(&guardian, &handover_id, &reason);
+    assert_eq!(client.get_governance(), Some(governance));
+
+    let halted = soroban_sdk::Vec::from_array(&env, [PausableFn::Deposit]);
+    client.pause(&guardian, &halted);
+    assert!(client.is_paused(&PausableFn::Deposit));
+    assert_eq!(
+        client.try_pause(&Address::generate(&env), &halted),
+        Err(Ok(crate::errors::ContractError::CouncilRetired))
+    );
+}
+
+#[test]
+fn test_grants_manager_role_funds_programs_after_governance_handover() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_treasury_contract(&env);
+    let admin = Address::generate(&env);
+    let manager = Address::generate(&env);
+    let governance = Address::generate(&env);
+
+    let (token_client, token_address) = create_token_contract(&env, &admin);
+    token_client.mint(&admin, &10000);
+    let council = crate::types::AdminCouncil {
+        members: soroban_sdk::Vec::from_array(&env, [admin.clone()]),
+        threshold: 1,
+    };
+    client.initialize(&council, &token_address);
+    client.deposit(&admin, &5000);
+
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::GrantRole(Role::GrantsManager, manager.clone()),
+    );
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::SetGovernance(governance.clone()),
+    );
+
+    // Roles granted by the council end with the handover.
+    assert!(!client.has_role(&Role::GrantsManager, &manager));
+    assert_eq!(
+        client.try_propose(&manager, &ProposalAction::Allocate(1, 1000)),
+        Err(Ok(crate::errors::ContractError::CouncilRetired))
+    );
+
+    // Governance grants its own, behind the timelock.
+    let grant_id = client.governance_execute(&ProposalAction::GrantRole(
+        Role::GrantsManager,
+        manager.clone(),
+    ));
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    client.execute(&grant_id);
+    assert!(client.has_role(&Role::GrantsManager, &manager));
+
+    let create_id = client.propose(
+        &manager,
+        &ProposalAction::CreateProgram(String::from_str(&env, "Grants"), 3000),
+    );
+    client.execute(&create_id);
+    let allocate_id = client.propose(&manager, &ProposalAction::Allocate(1, 1000));
+    client.execute(&allocate_id);
+    assert_eq!(client.get_program(&1).spent, 1000);
+    assert_eq!(client.get_balance(), 4000);
+
+    // Withdrawals are not covered by the role.
+    assert_eq!(
+        client.try_propose(
+            &manager,
+            &ProposalAction::Withdraw(manager.clone(), 100, String::from_str(&env, "x"))
+        ),
+        Err(Ok(crate::errors::ContractError::CouncilRetired))
+    );
+
+    client.governance_execute(&ProposalAction::RevokeRole(
+        Role::GrantsManager,
+        manager.clone(),
+    ));
+    assert!(!client.has_role(&Role::GrantsManager, &manager));
+    assert_eq!(
+        client.try_propose(&manager, &ProposalAction::Allocate(1, 1000)),
+        Err(Ok(crate::errors::ContractError::CouncilRetired))
+    );
+}
+
+#[test]
+fn test_guardian_vetoes_withdraw_and_own_removal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_treasury_contract(&env);
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    let (token_client, token_address) = create_token_contract(&env, &admin);
+    token_client.mint(&admin, &10000);
+    let council = crate::types::AdminCouncil {
+        members: soroban_sdk::Vec::from_array(&env, [admin.clone()]),
+        threshold: 1,
+    };
+    client.initialize(&council, &token_address);
+    client.deposit(&admin, &5000);
+
+    assert_eq!(
+        try_council_execute(
+            &client,
+            &admin,
+            &ProposalAction::SetGuardians(soroban_sdk::Vec::from_array(
+                &env,
+                [guardian.clone(), guardian.clone()]
+            )),
+        ),
+        Err(crate::errors::ContractError::InvalidGuardians)
+    );
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::SetGuardians(soroban_sdk::Vec::from_array(&env, [guardian.clone()])),
+    );
+
+    let reason = String::from_str(&env, "unannounced drain");
+    let withdraw_id = client.propose(
+        &admin,
+        &ProposalAction::Withdraw(to.clone(), 5000, String::from_str(&env, "grant")),
+    );
+    client.veto(&guardian, &withdraw_id, &reason);
+    assert_eq!(
+        client.try_execute(&withdraw_id),
+        Err(Ok(crate::errors::ContractError::ProposalNotPending))
+    );
+    assert_eq!(
+        client.try_veto(&guardian, &withdraw_id, &reason),
+        Err(Ok(crate::errors::ContractError::VetoWindowClosed))
+    );
+
+    // Replacing the guardians is timelocked, so they can veto their own removal.
+    let remove_id = client.propose(
+        &admin,
+        &ProposalAction::SetGuardians(soroban_sdk::Vec::new(&env)),
+    );
+    client.execute(&remove_id);
+    client.veto(&guardian, &remove_id, &reason);
+    assert_eq!(
+        client.get_proposal(&remove_id).status,
+        ProposalStatus::Vetoed
+    );
+    assert_eq!(client.get_guardians().len(), 1);
+    assert_eq!(client.get_balance(), 5000);
+}
+
+#[test]
+fn test_action_log_records_council_and_governance_actions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_treasury_contract(&env);
+    let admin = Address::generate(&env);
+    let governance = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    let (token_client, token_address) = create_token_contract(&env, &admin);
+    token_client.mint(&admin, &10000);
+    let council = crate::types::AdminCouncil {
+        members: soroban_sdk::Vec::from_array(&env, [admin.clone()]),
+        threshold: 1,
+    };
+    client.initialize(&council, &token_address);
+    client.deposit(&admin, &5000);
+
+    let create = ProposalAction::CreateProgram(String::from_str(&env, "Grants"), 3000);
+    council_execute(&client, &admin, &create);
+    council_execute(&client, &admin, &ProposalAction::Allocate(1, 1000));
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::SetGovernance(governance.clone()),
+    );
+    let withdraw = ProposalAction::Withdraw(to, 500, String::from_str(&env, "audit"));
+    let withdraw_id = client.governance_execute(&withdraw);
+    env.ledger()
+        .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+    client.execute(&withdraw_id);
+    client.governance_execute(&ProposalAction::DeactivateProgram(1));
+
+    assert_eq!(client.get_action_log_count(), 5);
+    let log = client.get_action_log(&0, &50);
+    assert_eq!(log.len(), 5);
+
+    let first = log.get(0).unwrap();
+    assert_eq!(first.proposal_id, Some(1));
+    assert_eq!(first.action, create);
+    assert_eq!(
+        first.approvals,
+        soroban_sdk::Vec::from_array(&env, [admin.clone()])
+    );
+
+    let by_governance = log.get(3).unwrap();
+    assert_eq!(by_governance.id, 4);
+    assert_eq!(by_governance.proposal_id, Some(withdraw_id));
+    assert_eq!(by_governance.action, withdraw);
+    assert_eq!(
+        by_governance.approvals,
+        soroban_sdk::Vec::from_array(&env, [governance])
+    );
+    assert_eq!(
+        log.get(4).unwrap().action,
+        ProposalAction::DeactivateProgram(1)
+    );
+}
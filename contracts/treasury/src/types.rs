@@ -22,19 +22,14 @@
 //!   - `ProtocolDevelopment` — Development and infrastructure expenses
 //!   - `Custom(String)` — Governance-defined custom programs
 //!
+//! - `Proposal` — A council proposal collecting M-of-N approvals for a privileged action
+//!
 //! implementation tracked in GitHub issue
 
-use soroban_sdk::{contracttype, Address, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
 
-/// A multi-signature admin council requiring threshold approvals.
-#[contracttype]
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct AdminCouncil {
-    /// List of council member addresses (max 10)
-    pub members: Vec<Address>,
-    /// Minimum number of members required to authorize a sensitive action
-    pub threshold: u32,
-}
+pub use conduit_common::council::{AdminCouncil, ProposalStatus, MAX_COUNCIL_MEMBERS};
+pub use conduit_common::guardians::VetoRecord;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -103,3 +98,129 @@ pub struct TreasuryStats {
     /// Total tokens allocated to spending programs over the treasury's lifetime.
     pub lifetime_allocated: i128,
 }
+
+/// An entrypoint that the council can pause in an emergency.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PausableFn {
+    /// `deposit`: Deposit tokens into the treasury.
+    Deposit,
+    /// `withdraw`: Withdraw tokens from the treasury (proposal action).
+    Withdraw,
+    /// `allocate`: Allocate funds to a spending program (proposal action).
+    Allocate,
+}
+
+/// A narrow permission the council can grant so that an address can perform one
+/// kind of privileged action without a council vote.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Role {
+    /// May create spending programs and allocate funds to them
+    /// (`CreateProgram`, `Allocate`).
+    GrantsManager,
+}
+
+/// A privileged action that can only be performed through an approved council proposal.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProposalAction {
+    /// Withdraw `amount` to the recipient, with a memo for the history entry.
+    Withdraw(Address, i128, String),
+    /// Create a new spending program with a name and budget.
+    CreateProgram(String, i128),
+    /// Change the budget of an existing spending program.
+    UpdateProgramBudget(u64, i128),
+    /// Deactivate a spending program.
+    DeactivateProgram(u64),
+    /// Allocate treasury funds to a spending program.
+    Allocate(u64, i128),
+    /// Add a member to the admin council.
+    AddMember(Address),
+    /// Remove a member from the admin council.
+    RemoveMember(Address),
+    /// Replace an existing council member with a new address.
+    ReplaceMember(Address, Address),
+    /// Change the number of approvals required to execute a proposal.
+    SetThreshold(u32),
+    /// Replace the contract WASM with an uploaded build, identified by its hash.
+    Upgrade(BytesN<32>),
+    /// Lift an emergency pause on the listed entrypoints.
+    Unpause(Vec<PausableFn>),
+    /// Cancel a queued timelocked action before it is executed.
+    CancelQueued(u64),
+    /// Grant a role to an address.
+    GrantRole(Role, Address),
+    /// Revoke a role from an address.
+    RevokeRole(Role, Address),
+    /// Replace the guardian set that may veto proposals.
+    SetGuardians(Vec<Address>),
+    /// Hand treasury authority to a governance contract, retiring the council.
+    SetGovernance(Address),
+}
+
+impl ProposalAction {
+    /// Role whose holders may perform the action without council approvals, if any.
+    pub fn required_role(&self) -> Option<Role> {
+        match self {
+            ProposalAction::CreateProgram(..) | ProposalAction::Allocate(..) => {
+                Some(Role::GrantsManager)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the action waits out `TIMELOCK_DELAY` after approval before it applies.
+    pub fn is_timelocked(&self) -> bool {
+        matches!(
+            self,
+            ProposalAction::Withdraw(..)
+                | ProposalAction::Upgrade(_)
+                | ProposalAction::GrantRole(..)
+                | ProposalAction::AddMember(_)
+                | ProposalAction::RemoveMember(_)
+                | ProposalAction::ReplaceMember(..)
+                | ProposalAction::SetThreshold(_)
+                | ProposalAction::SetGovernance(_)
+                | ProposalAction::SetGuardians(_)
+        )
+    }
+}
+
+/// A council proposal for a privileged action, approved by distinct council members.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proposal {
+    /// Unique monotonic identifier for this proposal.
+    pub id: u64,
+    /// Council member that created the proposal.
+    pub proposer: Address,
+    /// The action applied when the proposal is executed.
+    pub action: ProposalAction,
+    /// Distinct council members that approved the proposal (includes the proposer).
+    pub approvals: Vec<Address>,
+    /// Current lifecycle status of the proposal.
+    pub status: ProposalStatus,
+    /// Ledger sequence when the proposal was created.
+    pub created_at: u64,
+    /// Last ledger sequence at which the proposal can be approved or executed.
+    pub expires_at: u64,
+}
+
+/// A privileged action the contract has applied, kept as an append-only audit record.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ActionLogEntry {
+    /// Position in the action log, starting at 1.
+    pub id: u64,
+    /// Proposal that carried the action. `None` on entries written before
+    /// `governance_execute` recorded its actions as proposals.
+    pub proposal_id: Option<u64>,
+    /// The applied action, including its arguments.
+    pub action: ProposalAction,
+    /// Addresses that authorized the action: the proposal's approvals (proposer
+    /// included), or the governance contract.
+    pub approvals: Vec<Address>,
+    /// Ledger sequence at which the action was applied.
+    pub ledger: u64,
+}
@@ -1,7 +1,16 @@
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
-use treasury::{types::SpendingProgram, TreasuryContract, TreasuryContractClient};
+use conduit_common::council::TIMELOCK_DELAY;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+use treasury::{
+    errors::ContractError,
+    storage,
+    types::{ProposalAction, ProposalStatus, SpendingProgram},
+    TreasuryContract, TreasuryContractClient,
+};
 
 /// Sets up the test environment with a mocked SAC token contract.
 fn setup<'a>() -> (
@@ -36,6 +45,20 @@ fn setup<'a>() -> (
     (env, client, admin, token_address.address(), token_client)
 }
 
+/// Propose `action` as `admin` and execute it (single-member council),
+/// waiting out the timelock if the action is queued.
+fn council_execute(client: &TreasuryContractClient, admin: &Address, action: &ProposalAction) {
+    let proposal_id = client.propose(admin, action);
+    client.execute(&proposal_id);
+    if client.get_proposal(&proposal_id).status == ProposalStatus::Queued {
+        client
+            .env
+            .ledger()
+            .with_mut(|l| l.sequence_number += TIMELOCK_DELAY);
+        client.execute(&proposal_id);
+    }
+}
+
 /// Helper to create and persist a spending program directly into storage.
 /// This sidesteps the lack of a public `create_program` function by invoking
 /// the private storage helpers within the contract environment context.
@@ -181,7 +204,7 @@ fn test_deposit_auth_required() {
 
 #[test]
 fn test_withdraw_success() {
-    let (env, client, _admin, token_id, token_client) = setup();
+    let (env, client, admin, token_id, token_client) = setup();
     let user = Address::generate(&env);
 
     // First deposit 10,000 into treasury
@@ -189,7 +212,11 @@ fn test_withdraw_success() {
     client.deposit(&user, &10_000);
 
     let recipient = Address::generate(&env);
-    client.withdraw(&recipient, &3_000, &String::from_str(&env, "grant"));
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::Withdraw(recipient.clone(), 3_000, String::from_str(&env, "grant")),
+    );
 
     assert_eq!(client.get_balance(), 7_000);
 
@@ -201,7 +228,7 @@ fn test_withdraw_success() {
 #[test]
 #[should_panic(expected = "Error(Contract, #1)")]
 fn test_withdraw_insufficient_balance() {
-    let (env, client, _admin, _token_id, token_client) = setup();
+    let (env, client, admin, _token_id, token_client) = setup();
     let user = Address::generate(&env);
 
     // Deposit only 2,000
@@ -210,15 +237,23 @@ fn test_withdraw_insufficient_balance() {
 
     let recipient = Address::generate(&env);
     // Try to withdraw 5,000
-    client.withdraw(&recipient, &5_000, &String::from_str(&env, "grant"));
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::Withdraw(recipient.clone(), 5_000, String::from_str(&env, "grant")),
+    );
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #3)")]
 fn test_withdraw_zero_amount() {
-    let (env, client, _admin, _token_id, _) = setup();
+    let (env, client, admin, _token_id, _) = setup();
     let recipient = Address::generate(&env);
-    client.withdraw(&recipient, &0, &String::from_str(&env, "grant"));
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::Withdraw(recipient.clone(), 0, String::from_str(&env, "grant")),
+    );
 }
 
 #[test]
@@ -241,14 +276,17 @@ fn test_withdraw_unauthorized() {
 
     let recipient = Address::generate(&env);
     // Fails because admin.require_auth() is called, but auth is not mocked
-    client.withdraw(&recipient, &100, &String::from_str(&env, "grant"));
+    client.propose(
+        &admin,
+        &ProposalAction::Withdraw(recipient.clone(), 100, String::from_str(&env, "grant")),
+    );
 }
 
 // ── allocate() tests ──────────────────────────────────────────────────────────
 
 #[test]
 fn test_allocate_success() {
-    let (env, client, _admin, _token_id, token_client) = setup();
+    let (env, client, admin, _token_id, token_client) = setup();
     let user = Address::generate(&env);
 
     // Deposit 10,000
@@ -259,7 +297,7 @@ fn test_allocate_success() {
     create_spending_program(&env, &client, 1, 5_000);
 
     // Allocate 2000
-    client.allocate(&1, &2_000);
+    council_execute(&client, &admin, &ProposalAction::Allocate(1, 2_000));
 
     assert_eq!(client.get_balance(), 8_000);
     // In actual contract, allocate() increments spent
@@ -272,20 +310,20 @@ fn test_allocate_success() {
 #[test]
 #[should_panic(expected = "Error(Contract, #4)")]
 fn test_allocate_program_not_found() {
-    let (env, client, _admin, _token_id, token_client) = setup();
+    let (env, client, admin, _token_id, token_client) = setup();
     let user = Address::generate(&env);
 
     token_client.mint(&user, &10_000);
     client.deposit(&user, &10_000);
 
     // Program 1 does not exist
-    client.allocate(&1, &2_000);
+    council_execute(&client, &admin, &ProposalAction::Allocate(1, 2_000));
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #11)")]
 fn test_allocate_program_inactive() {
-    let (env, client, _admin, _token_id, token_client) = setup();
+    let (env, client, admin, _token_id, token_client) = setup();
     let user = Address::generate(&env);
 
     token_client.mint(&user, &10_000);
@@ -303,13 +341,13 @@ fn test_allocate_program_inactive() {
         treasury::storage::set_spending_program(&env, 1, program);
     });
 
-    client.allocate(&1, &2_000);
+    council_execute(&client, &admin, &ProposalAction::Allocate(1, 2_000));
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #12)")]
 fn test_allocate_over_budget() {
-    let (env, client, _admin, _token_id, token_client) = setup();
+    let (env, client, admin, _token_id, token_client) = setup();
     let user = Address::generate(&env);
 
     token_client.mint(&user, &10_000);
@@ -319,13 +357,13 @@ fn test_allocate_over_budget() {
     create_spending_program(&env, &client, 1, 5_000);
 
     // Try to allocate 6_000
-    client.allocate(&1, &6_000);
+    council_execute(&client, &admin, &ProposalAction::Allocate(1, 6_000));
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #1)")]
 fn test_allocate_insufficient_treasury_balance() {
-    let (env, client, _admin, _token_id, token_client) = setup();
+    let (env, client, admin, _token_id, token_client) = setup();
     let user = Address::generate(&env);
 
     // Deposit only 2,000
@@ -336,7 +374,7 @@ fn test_allocate_insufficient_treasury_balance() {
     create_spending_program(&env, &client, 1, 5_000);
 
     // Try to allocate 3_000 (below budget, but exceeds treasury balance)
-    client.allocate(&1, &3_000);
+    council_execute(&client, &admin, &ProposalAction::Allocate(1, 3_000));
 }
 
 #[test]
@@ -358,7 +396,7 @@ fn test_allocate_unauthorized() {
     client.initialize(&council, &token_address.address());
 
     // Fails because admin.require_auth() is called, but auth is not mocked
-    client.allocate(&1, &100);
+    client.propose(&admin, &ProposalAction::Allocate(1, 100));
 }
 
 // ── get_balance() tests ───────────────────────────────────────────────────────
@@ -382,14 +420,18 @@ fn test_get_balance_after_deposit() {
 
 #[test]
 fn test_get_balance_after_withdraw() {
-    let (env, client, _admin, _token_id, token_client) = setup();
+    let (env, client, admin, _token_id, token_client) = setup();
     let user = Address::generate(&env);
 
     token_client.mint(&user, &5_000);
     client.deposit(&user, &5_000);
 
     let recipient = Address::generate(&env);
-    client.withdraw(&recipient, &2_000, &String::from_str(&env, "grant"));
+    council_execute(
+        &client,
+        &admin,
+        &ProposalAction::Withdraw(recipient.clone(), 2_000, String::from_str(&env, "grant")),
+    );
 
     assert_eq!(client.get_balance(), 3_000);
 }
@@ -420,3 +462,55 @@ fn test_get_history_not_found() {
     // No history yet, requesting ID 999 should error
     client.get_history(&999);
 }
+
+// ── council proposal tests ────────────────────────────────────────────────────
+
+#[test]
+fn test_create_program_requires_threshold_approvals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(TreasuryContract, ());
+    let client = TreasuryContractClient::new(&env, &contract_id);
+    let mut members = soroban_sdk::Vec::new(&env);
+    for _ in 0..3 {
+        members.push_back(Address::generate(&env));
+    }
+    let council = treasury::types::AdminCouncil {
+        members: members.clone(),
+        threshold: 2,
+    };
+    let token_admin = Address::generate(&env);
+    let token_address = env.register_stellar_asset_contract_v2(token_admin.clone());
+    client.initialize(&council, &token_address.address());
+
+    let proposal_id = client.propose(
+        &members.get(2).unwrap(),
+        &ProposalAction::CreateProgram(String::from_str(&env, "Relay Incentives"), 5_000),
+    );
+    assert_eq!(
+        client.try_execute(&proposal_id),
+        Err(Ok(treasury::errors::ContractError::InsufficientApprovals))
+    );
+
+    client.approve(&members.get(1).unwrap(), &proposal_id);
+    client.execute(&proposal_id);
+
+    let program = client.get_program(&1);
+    assert_eq!(program.budget, 5_000);
+    assert!(program.active);
+}
+
+#[test]
+fn test_version_and_migrate() {
+    let (env, client, _, _, _) = setup();
+    assert_eq!(client.version(), storage::SCHEMA_VERSION);
+    assert_eq!(client.migrate(), storage::SCHEMA_VERSION);
+
+    env.as_contract(&client.address, || {
+        storage::set_schema_version(&env, storage::SCHEMA_VERSION + 1)
+    });
+    assert_eq!(
+        client.try_migrate(),
+        Err(Ok(ContractError::UnsupportedSchemaVersion))
+    );
+}
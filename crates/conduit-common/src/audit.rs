@@ -0,0 +1,25 @@
+//! # Conduit Common — `audit.rs`
+//!
+//! Every contract keeps an append-only log of the privileged actions it has
+//! applied, so governance history can be reconstructed from contract state after
+//! the matching events have aged out. Entries are numbered from 1 in the order
+//! they were applied and are read back in pages of at most `MAX_LOG_PAGE_SIZE`.
+//!
+//! Each contract defines its own `ActionLogEntry`, since it embeds that
+//! contract's `ProposalAction`; this module only fixes the paging rules.
+
+use core::ops::Range;
+
+/// Maximum number of action log entries returned by a single page.
+pub const MAX_LOG_PAGE_SIZE: u32 = 50;
+
+/// IDs of the log page that starts at `start_id` and holds up to `limit`
+/// entries (capped at `MAX_LOG_PAGE_SIZE`), given `count` entries numbered
+/// `1..=count`. A `start_id` of 0 is treated as 1; the range is empty when
+/// `start_id` is past the end of the log.
+pub fn log_page(start_id: u64, limit: u32, count: u64) -> Range<u64> {
+    let first = start_id.max(1);
+    let len = limit.min(MAX_LOG_PAGE_SIZE) as u64;
+    let end = first.saturating_add(len).min(count.saturating_add(1));
+    first..end.max(first)
+}
@@ -0,0 +1,205 @@
+//! # Conduit Common — `council.rs`
+//!
+//! The M-of-N admin council shared by all contracts, plus the rules every
+//! contract applies to council proposals:
+//! - A council has 1..=`MAX_COUNCIL_MEMBERS` distinct members and
+//!   `1 <= threshold <= members.len()`.
+//! - Only council members may propose or approve, and each member approves once.
+//! - A proposal is executable while `Pending` and not past `expires_at`, once
+//!   approvals from *current* members reach the threshold.
+//! - Timelocked actions are first `Queued` and only applied `TIMELOCK_DELAY`
+//!   ledgers later. Every contract timelocks the membership and threshold
+//!   changes below, so a council majority cannot reshape itself in one ledger.
+//!
+//! Each contract keeps its own `ProposalAction` and `Proposal` types and maps
+//! `CouncilError` onto its `ContractError` codes.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// Maximum number of members on the admin council.
+pub const MAX_COUNCIL_MEMBERS: u32 = 10;
+
+/// Number of ledgers a council proposal stays open for approval (~7 days).
+pub const PROPOSAL_LIFETIME: u32 = 120_960;
+
+/// Number of ledgers an approved timelocked action waits before it can be
+/// executed (~1 day), giving operators advance notice of the change.
+pub const TIMELOCK_DELAY: u32 = 17_280;
+
+/// A multi-signature admin council requiring threshold approvals.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AdminCouncil {
+    /// List of council member addresses (max 10)
+    pub members: Vec<Address>,
+    /// Minimum number of members required to authorize a sensitive action
+    pub threshold: u32,
+}
+
+/// Lifecycle status of a council proposal.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProposalStatus {
+    /// Proposal is collecting approvals.
+    Pending,
+    /// Proposal was approved and its timelocked action is waiting out the delay.
+    Queued,
+    /// Proposal reached the council threshold and its action was applied.
+    Executed,
+    /// Proposal was withdrawn by its proposer.
+    Cancelled,
+    /// Proposal was vetoed by a guardian before its action was applied.
+    Vetoed,
+}
+
+/// Failures of the shared council rules. Each contract converts these into
+/// the `ContractError` variant of the same name.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CouncilError {
+    /// Council config is invalid (threshold > members, duplicates, etc.)
+    InvalidCouncilConfig,
+    /// Address is not a member of the admin council.
+    NotCouncilMember,
+    /// The council member has already approved this proposal.
+    AlreadyApproved,
+    /// Not enough current council members approved the proposal.
+    InsufficientApprovals,
+    /// The proposal has already been executed or cancelled.
+    ProposalNotPending,
+    /// The proposal's approval window has passed.
+    ProposalExpired,
+}
+
+impl AdminCouncil {
+    /// Check the council invariants: 1..=10 distinct members and
+    /// `1 <= threshold <= members.len()`.
+    pub fn validate(&self) -> Result<(), CouncilError> {
+        let size = self.members.len();
+        if self.threshold == 0 || size < self.threshold || size > MAX_COUNCIL_MEMBERS {
+            return Err(CouncilError::InvalidCouncilConfig);
+        }
+        for (i, member) in self.members.iter().enumerate() {
+            if self.members.last_index_of(&member) != Some(i as u32) {
+                return Err(CouncilError::InvalidCouncilConfig);
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensure `address` is a member of the council.
+    pub fn require_member(&self, address: &Address) -> Result<(), CouncilError> {
+        if self.members.contains(address) {
+            Ok(())
+        } else {
+            Err(CouncilError::NotCouncilMember)
+        }
+    }
+
+    /// Record `approver`'s approval, rejecting non-members and repeat approvals.
+    pub fn record_approval(
+        &self,
+        approvals: &mut Vec<Address>,
+        approver: &Address,
+    ) -> Result<(), CouncilError> {
+        self.require_member(approver)?;
+        if approvals.contains(approver) {
+            return Err(CouncilError::AlreadyApproved);
+        }
+        approvals.push_back(approver.clone());
+        Ok(())
+    }
+
+    /// Number of `approvals` that come from addresses still on the council.
+    pub fn count_approvals(&self, approvals: &Vec<Address>) -> u32 {
+        approvals
+            .iter()
+            .filter(|member| self.members.contains(member))
+            .count() as u32
+    }
+
+    /// Ensure enough current members approved to meet the threshold.
+    pub fn require_threshold(&self, approvals: &Vec<Address>) -> Result<(), CouncilError> {
+        if self.count_approvals(approvals) < self.threshold {
+            return Err(CouncilError::InsufficientApprovals);
+        }
+        Ok(())
+    }
+
+    /// Add `member`, rejecting duplicates and oversized councils.
+    pub fn add_member(&mut self, member: &Address) -> Result<(), CouncilError> {
+        if self.members.contains(member) {
+            return Err(CouncilError::InvalidCouncilConfig);
+        }
+        let mut next = self.clone();
+        next.members.push_back(member.clone());
+        self.apply(next)
+    }
+
+    /// Remove `member`, rejecting removals that leave fewer members than the threshold.
+    pub fn remove_member(&mut self, member: &Address) -> Result<(), CouncilError> {
+        let index = self
+            .members
+            .first_index_of(member)
+            .ok_or(CouncilError::NotCouncilMember)?;
+        let mut next = self.clone();
+        next.members.remove(index);
+        self.apply(next)
+    }
+
+    /// Swap `old_member` for `new_member` in place.
+    pub fn replace_member(
+        &mut self,
+        old_member: &Address,
+        new_member: &Address,
+    ) -> Result<(), CouncilError> {
+        let index = self
+            .members
+            .first_index_of(old_member)
+            .ok_or(CouncilError::NotCouncilMember)?;
+        if self.members.contains(new_member) {
+            return Err(CouncilError::InvalidCouncilConfig);
+        }
+        self.members.set(index, new_member.clone());
+        Ok(())
+    }
+
+    /// Change the approval threshold.
+    pub fn set_threshold(&mut self, threshold: u32) -> Result<(), CouncilError> {
+        let mut next = self.clone();
+        next.threshold = threshold;
+        self.apply(next)
+    }
+
+    /// Replace `self` with `next` only if `next` satisfies the invariants.
+    fn apply(&mut self, next: AdminCouncil) -> Result<(), CouncilError> {
+        next.validate()?;
+        *self = next;
+        Ok(())
+    }
+}
+
+/// `(created_at, expires_at)` ledger sequences for a proposal opened now.
+pub fn proposal_window(env: &Env) -> (u64, u64) {
+    let created_at = env.ledger().sequence() as u64;
+    (created_at, created_at + PROPOSAL_LIFETIME as u64)
+}
+
+/// Earliest ledger sequence at which an action queued now may be executed.
+pub fn timelock_eta(env: &Env) -> u64 {
+    env.ledger().sequence() as u64 + TIMELOCK_DELAY as u64
+}
+
+/// Ensure a proposal can still collect approvals or be executed.
+pub fn require_open(
+    env: &Env,
+    status: &ProposalStatus,
+    expires_at: u64,
+) -> Result<(), CouncilError> {
+    if *status != ProposalStatus::Pending {
+        return Err(CouncilError::ProposalNotPending);
+    }
+    if env.ledger().sequence() as u64 > expires_at {
+        return Err(CouncilError::ProposalExpired);
+    }
+    Ok(())
+}
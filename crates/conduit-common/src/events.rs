@@ -0,0 +1,157 @@
+//! # Conduit Common — `events.rs`
+//!
+//! Every contract event uses the topic pair `(contract_symbol, action_symbol)`,
+//! e.g. `("relay_registry", "stake")`. `ContractEvents` fixes the contract half
+//! once per contract and provides typed helpers for the council events that all
+//! contracts emit with the same payload shape.
+
+use soroban_sdk::{Address, BytesN, Env, IntoVal, String, Symbol, Val, Vec};
+
+/// Publishes events under a fixed contract topic.
+#[derive(Clone, Copy, Debug)]
+pub struct ContractEvents {
+    contract: &'static str,
+}
+
+impl ContractEvents {
+    /// Create an emitter for the contract identified by `contract`
+    /// (e.g. `"relay_registry"`).
+    pub const fn new(contract: &'static str) -> Self {
+        Self { contract }
+    }
+
+    /// The `(contract, action)` topic pair for an event.
+    pub fn topics(&self, env: &Env, action: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, self.contract), Symbol::new(env, action))
+    }
+
+    /// Publish `data` under the `(contract, action)` topics.
+    pub fn publish<D>(&self, env: &Env, action: &str, data: D)
+    where
+        D: IntoVal<Env, Val>,
+    {
+        env.events().publish(self.topics(env, action), data);
+    }
+
+    /// `propose`: `(proposer, proposal_id, action)`.
+    pub fn proposal_created<A>(&self, env: &Env, proposer: &Address, proposal_id: u64, action: A)
+    where
+        A: IntoVal<Env, Val>,
+    {
+        let action: Val = action.into_val(env);
+        self.publish(env, "propose", (proposer.clone(), proposal_id, action));
+    }
+
+    /// `approve`: `(approver, proposal_id, approval_count)`.
+    pub fn proposal_approved(&self, env: &Env, approver: &Address, proposal_id: u64, count: u32) {
+        self.publish(env, "approve", (approver.clone(), proposal_id, count));
+    }
+
+    /// `execute`: `(proposal_id,)`.
+    pub fn proposal_executed(&self, env: &Env, proposal_id: u64) {
+        self.publish(env, "execute", (proposal_id,));
+    }
+
+    /// `queue`: `(proposal_id, eta)`, where `eta` is the earliest ledger at
+    /// which the timelocked action can be executed.
+    pub fn proposal_queued(&self, env: &Env, proposal_id: u64, eta: u64) {
+        self.publish(env, "queue", (proposal_id, eta));
+    }
+
+    /// `cancel_queued`: `(proposal_id,)`.
+    pub fn queued_cancelled(&self, env: &Env, proposal_id: u64) {
+        self.publish(env, "cancel_queued", (proposal_id,));
+    }
+
+    /// `cancel`: `(proposer, proposal_id)`.
+    pub fn proposal_cancelled(&self, env: &Env, proposer: &Address, proposal_id: u64) {
+        self.publish(env, "cancel", (proposer.clone(), proposal_id));
+    }
+
+    /// `add_member`: `(member, council_size)`.
+    pub fn member_added(&self, env: &Env, member: &Address, council_size: u32) {
+        self.publish(env, "add_member", (member.clone(), council_size));
+    }
+
+    /// `remove_member`: `(member, council_size)`.
+    pub fn member_removed(&self, env: &Env, member: &Address, council_size: u32) {
+        self.publish(env, "remove_member", (member.clone(), council_size));
+    }
+
+    /// `replace_member`: `(old_member, new_member)`.
+    pub fn member_replaced(&self, env: &Env, old_member: &Address, new_member: &Address) {
+        self.publish(
+            env,
+            "replace_member",
+            (old_member.clone(), new_member.clone()),
+        );
+    }
+
+    /// `set_threshold`: `(threshold,)`.
+    pub fn threshold_changed(&self, env: &Env, threshold: u32) {
+        self.publish(env, "set_threshold", (threshold,));
+    }
+
+    /// `upgrade`: `(new_wasm_hash, schema_version)`, with the schema version
+    /// recorded before the new code runs.
+    pub fn upgraded(&self, env: &Env, new_wasm_hash: &BytesN<32>, schema_version: u32) {
+        self.publish(env, "upgrade", (new_wasm_hash.clone(), schema_version));
+    }
+
+    /// `migrate`: `(from_version, to_version)`.
+    pub fn migrated(&self, env: &Env, from_version: u32, to_version: u32) {
+        self.publish(env, "migrate", (from_version, to_version));
+    }
+
+    /// `pause`: `(member, functions)`.
+    pub fn paused<F>(&self, env: &Env, member: &Address, functions: F)
+    where
+        F: IntoVal<Env, Val>,
+    {
+        let functions: Val = functions.into_val(env);
+        self.publish(env, "pause", (member.clone(), functions));
+    }
+
+    /// `grant_role`: `(role, account)`.
+    pub fn role_granted<R>(&self, env: &Env, role: R, account: &Address)
+    where
+        R: IntoVal<Env, Val>,
+    {
+        let role: Val = role.into_val(env);
+        self.publish(env, "grant_role", (role, account.clone()));
+    }
+
+    /// `revoke_role`: `(role, account)`.
+    pub fn role_revoked<R>(&self, env: &Env, role: R, account: &Address)
+    where
+        R: IntoVal<Env, Val>,
+    {
+        let role: Val = role.into_val(env);
+        self.publish(env, "revoke_role", (role, account.clone()));
+    }
+
+    /// `set_guardians`: `(guardians,)`.
+    pub fn guardians_set(&self, env: &Env, guardians: &Vec<Address>) {
+        self.publish(env, "set_guardians", (guardians.clone(),));
+    }
+
+    /// `veto`: `(guardian, proposal_id, reason)`.
+    pub fn proposal_vetoed(
+        &self,
+        env: &Env,
+        guardian: &Address,
+        proposal_id: u64,
+        reason: &String,
+    ) {
+        self.publish(env, "veto", (guardian.clone(), proposal_id, reason.clone()));
+    }
+
+    /// `unpause`: `(functions,)`.
+    pub fn unpaused<F>(&self, env: &Env, functions: F)
+    where
+        F: IntoVal<Env, Val>,
+    {
+        let functions: Val = functions.into_val(env);
+        self.publish(env, "unpause", (functions,));
+    }
+}
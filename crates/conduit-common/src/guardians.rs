@@ -0,0 +1,98 @@
+//! # Conduit Common — `guardians.rs`
+//!
+//! Guardians are a small set of addresses, separate from the council, that can
+//! veto a council proposal but never open or approve one. They guard against a
+//! compromised council majority:
+//! - A guardian set has at most `MAX_GUARDIANS` distinct addresses; an empty
+//!   set disables vetoes.
+//! - A proposal can be vetoed while it is `Pending` and unexpired, or while it
+//!   is `Queued` and its eta has not been reached. For timelocked actions the
+//!   veto window is therefore the whole `TIMELOCK_DELAY`.
+//! - Every veto carries a non-empty reason of at most `MAX_VETO_REASON_LEN`
+//!   bytes, stored as a `VetoRecord`.
+
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+use crate::council::ProposalStatus;
+
+/// Maximum number of guardians.
+pub const MAX_GUARDIANS: u32 = 10;
+
+/// Maximum length in bytes of a veto reason.
+pub const MAX_VETO_REASON_LEN: u32 = 256;
+
+/// A guardian's veto of a council proposal.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VetoRecord {
+    /// Guardian that vetoed the proposal.
+    pub guardian: Address,
+    /// Why the proposal was vetoed.
+    pub reason: String,
+    /// Ledger sequence at which the veto was recorded.
+    pub vetoed_at: u64,
+}
+
+/// Failures of the guardian rules. Each contract converts these into the
+/// `ContractError` variant of the same name.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum GuardianError {
+    /// Guardian set has duplicates or more than `MAX_GUARDIANS` addresses.
+    InvalidGuardians,
+    /// Address is not a guardian.
+    NotGuardian,
+    /// The proposal is no longer pending, has expired or its eta has passed.
+    VetoWindowClosed,
+    /// The veto reason is empty or longer than `MAX_VETO_REASON_LEN`.
+    InvalidVetoReason,
+}
+
+/// Check that `guardians` has at most `MAX_GUARDIANS` distinct addresses.
+pub fn validate_guardians(guardians: &Vec<Address>) -> Result<(), GuardianError> {
+    if guardians.len() > MAX_GUARDIANS {
+        return Err(GuardianError::InvalidGuardians);
+    }
+    for (i, guardian) in guardians.iter().enumerate() {
+        if guardians.last_index_of(&guardian) != Some(i as u32) {
+            return Err(GuardianError::InvalidGuardians);
+        }
+    }
+    Ok(())
+}
+
+/// Fail with `GuardianError::NotGuardian` unless `account` is in `guardians`.
+pub fn require_guardian(guardians: &Vec<Address>, account: &Address) -> Result<(), GuardianError> {
+    if !guardians.contains(account) {
+        return Err(GuardianError::NotGuardian);
+    }
+    Ok(())
+}
+
+/// Fail with `GuardianError::InvalidVetoReason` unless `reason` is non-empty
+/// and at most `MAX_VETO_REASON_LEN` bytes.
+pub fn validate_reason(reason: &String) -> Result<(), GuardianError> {
+    if reason.is_empty() || reason.len() > MAX_VETO_REASON_LEN {
+        return Err(GuardianError::InvalidVetoReason);
+    }
+    Ok(())
+}
+
+/// Fail with `GuardianError::VetoWindowClosed` unless a proposal with this
+/// `status`, `expires_at` and queue `eta` can still be vetoed.
+pub fn require_vetoable(
+    env: &Env,
+    status: &ProposalStatus,
+    expires_at: u64,
+    eta: Option<u64>,
+) -> Result<(), GuardianError> {
+    let now = env.ledger().sequence() as u64;
+    let open = match status {
+        ProposalStatus::Pending => now <= expires_at,
+        ProposalStatus::Queued => eta.is_some_and(|eta| now < eta),
+        _ => false,
+    };
+    if !open {
+        return Err(GuardianError::VetoWindowClosed);
+    }
+    Ok(())
+}
@@ -0,0 +1,32 @@
+//! # Conduit Common — `lib.rs`
+//!
+//! Shared `no_std` building blocks used by every StellarConduit contract, so
+//! that council authorization, TTL policy and event shapes are defined once.
+//!
+//! ## Modules
+//! - `council` — `AdminCouncil`, its invariants, and the proposal approval rules
+//! - `ttl` — Ledger TTL constants and instance/persistent bump helpers
+//! - `events` — `ContractEvents`, which publishes `(contract, action)` topics
+//! - `roles` — Holder lists for narrow roles granted by the council
+//! - `guardians` — Guardian sets and the rules for vetoing council proposals
+//! - `audit` — Paging rules for the append-only action log
+//!
+//! Contracts convert `CouncilError`, `RoleError` and `GuardianError` into their own
+//! `ContractError` via `From`, so the shared helpers can be used with `?` inside entrypoints.
+
+#![no_std]
+
+pub mod audit;
+pub mod council;
+pub mod events;
+pub mod guardians;
+pub mod roles;
+pub mod ttl;
+
+#[cfg(test)]
+mod test;
+
+pub use council::{AdminCouncil, CouncilError, ProposalStatus, MAX_COUNCIL_MEMBERS};
+pub use events::ContractEvents;
+pub use guardians::{GuardianError, VetoRecord};
+pub use roles::RoleError;
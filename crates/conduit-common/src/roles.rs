@@ -0,0 +1,38 @@
+//! # Conduit Common — `roles.rs`
+//!
+//! Narrow roles that let an address perform one kind of privileged action
+//! without a council vote, e.g. a slasher or a fee setter. Each contract
+//! defines its own `Role` enum and maps each `ProposalAction` to the role that
+//! may perform it; the council grants and revokes roles through proposals.
+//!
+//! This module keeps the holder list of a single role consistent.
+
+use soroban_sdk::{Address, Vec};
+
+/// Failures of role assignment. Each contract converts these into the
+/// `ContractError` variant of the same name.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RoleError {
+    /// The address already holds the role.
+    RoleAlreadyGranted,
+    /// The address does not hold the role.
+    RoleNotHeld,
+}
+
+/// Add `account` to a role's `holders`.
+pub fn grant_role(holders: &mut Vec<Address>, account: &Address) -> Result<(), RoleError> {
+    if holders.contains(account) {
+        return Err(RoleError::RoleAlreadyGranted);
+    }
+    holders.push_back(account.clone());
+    Ok(())
+}
+
+/// Remove `account` from a role's `holders`.
+pub fn revoke_role(holders: &mut Vec<Address>, account: &Address) -> Result<(), RoleError> {
+    let index = holders
+        .first_index_of(account)
+        .ok_or(RoleError::RoleNotHeld)?;
+    holders.remove(index);
+    Ok(())
+}
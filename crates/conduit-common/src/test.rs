@@ -0,0 +1,203 @@
+//! # Conduit Common — Unit Tests
+
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, String,
+};
+
+use crate::audit::{log_page, MAX_LOG_PAGE_SIZE};
+use crate::council::{
+    proposal_window, require_open, AdminCouncil, CouncilError, ProposalStatus, PROPOSAL_LIFETIME,
+};
+use crate::guardians::{require_vetoable, validate_guardians, validate_reason, GuardianError};
+use crate::roles::{grant_role, revoke_role, RoleError};
+
+fn council(env: &Env, size: u32, threshold: u32) -> AdminCouncil {
+    let mut members = vec![env];
+    for _ in 0..size {
+        members.push_back(Address::generate(env));
+    }
+    AdminCouncil { members, threshold }
+}
+
+#[test]
+fn test_validate_accepts_valid_council() {
+    let env = Env::default();
+    assert_eq!(council(&env, 3, 2).validate(), Ok(()));
+    assert_eq!(council(&env, 10, 10).validate(), Ok(()));
+}
+
+#[test]
+fn test_validate_rejects_bad_threshold_and_size() {
+    let env = Env::default();
+    let err = Err(CouncilError::InvalidCouncilConfig);
+    assert_eq!(council(&env, 3, 0).validate(), err);
+    assert_eq!(council(&env, 2, 3).validate(), err);
+    assert_eq!(council(&env, 11, 1).validate(), err);
+}
+
+#[test]
+fn test_validate_rejects_duplicate_members() {
+    let env = Env::default();
+    let mut c = council(&env, 2, 1);
+    c.members.push_back(c.members.get(0).unwrap());
+    assert_eq!(c.validate(), Err(CouncilError::InvalidCouncilConfig));
+}
+
+#[test]
+fn test_record_approval_rules() {
+    let env = Env::default();
+    let c = council(&env, 2, 2);
+    let member = c.members.get(0).unwrap();
+    let mut approvals = vec![&env];
+
+    assert_eq!(
+        c.record_approval(&mut approvals, &Address::generate(&env)),
+        Err(CouncilError::NotCouncilMember)
+    );
+    assert_eq!(c.record_approval(&mut approvals, &member), Ok(()));
+    assert_eq!(
+        c.record_approval(&mut approvals, &member),
+        Err(CouncilError::AlreadyApproved)
+    );
+    assert_eq!(
+        c.require_threshold(&approvals),
+        Err(CouncilError::InsufficientApprovals)
+    );
+}
+
+#[test]
+fn test_count_approvals_ignores_former_members() {
+    let env = Env::default();
+    let mut c = council(&env, 3, 2);
+    let approvals = vec![&env, c.members.get(0).unwrap(), c.members.get(1).unwrap()];
+    assert_eq!(c.require_threshold(&approvals), Ok(()));
+
+    let former = c.members.get(1).unwrap();
+    c.replace_member(&former, &Address::generate(&env)).unwrap();
+    assert_eq!(c.count_approvals(&approvals), 1);
+    assert_eq!(
+        c.require_threshold(&approvals),
+        Err(CouncilError::InsufficientApprovals)
+    );
+}
+
+#[test]
+fn test_membership_changes_keep_invariants() {
+    let env = Env::default();
+    let mut c = council(&env, 2, 2);
+    let first = c.members.get(0).unwrap();
+    let second = c.members.get(1).unwrap();
+
+    assert_eq!(
+        c.add_member(&first),
+        Err(CouncilError::InvalidCouncilConfig)
+    );
+    assert_eq!(
+        c.remove_member(&first),
+        Err(CouncilError::InvalidCouncilConfig)
+    );
+    assert_eq!(
+        c.replace_member(&Address::generate(&env), &first),
+        Err(CouncilError::NotCouncilMember)
+    );
+    assert_eq!(
+        c.replace_member(&first, &second),
+        Err(CouncilError::InvalidCouncilConfig)
+    );
+    assert_eq!(c.set_threshold(3), Err(CouncilError::InvalidCouncilConfig));
+}
+
+#[test]
+fn test_require_open_checks_status_and_expiry() {
+    let env = Env::default();
+    let (created_at, expires_at) = proposal_window(&env);
+    assert_eq!(expires_at - created_at, PROPOSAL_LIFETIME as u64);
+
+    assert_eq!(
+        require_open(&env, &ProposalStatus::Pending, expires_at),
+        Ok(())
+    );
+    assert_eq!(
+        require_open(&env, &ProposalStatus::Executed, expires_at),
+        Err(CouncilError::ProposalNotPending)
+    );
+
+    env.ledger()
+        .with_mut(|li| li.sequence_number = expires_at as u32 + 1);
+    assert_eq!(
+        require_open(&env, &ProposalStatus::Pending, expires_at),
+        Err(CouncilError::ProposalExpired)
+    );
+}
+
+#[test]
+fn test_grant_and_revoke_role() {
+    let env = Env::default();
+    let account = Address::generate(&env);
+    let mut holders = vec![&env];
+
+    assert_eq!(
+        revoke_role(&mut holders, &account),
+        Err(RoleError::RoleNotHeld)
+    );
+    assert_eq!(grant_role(&mut holders, &account), Ok(()));
+    assert_eq!(
+        grant_role(&mut holders, &account),
+        Err(RoleError::RoleAlreadyGranted)
+    );
+    assert_eq!(revoke_role(&mut holders, &account), Ok(()));
+    assert!(holders.is_empty());
+}
+
+#[test]
+fn test_guardian_veto_rules() {
+    let env = Env::default();
+    let guardian = Address::generate(&env);
+    assert_eq!(validate_guardians(&vec![&env]), Ok(()));
+    assert_eq!(
+        validate_guardians(&vec![&env, guardian.clone(), guardian]),
+        Err(GuardianError::InvalidGuardians)
+    );
+
+    assert_eq!(
+        validate_reason(&String::from_str(&env, "")),
+        Err(GuardianError::InvalidVetoReason)
+    );
+    assert_eq!(validate_reason(&String::from_str(&env, "drain")), Ok(()));
+
+    let closed = Err(GuardianError::VetoWindowClosed);
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    assert_eq!(
+        require_vetoable(&env, &ProposalStatus::Pending, 100, None),
+        Ok(())
+    );
+    assert_eq!(
+        require_vetoable(&env, &ProposalStatus::Pending, 99, None),
+        closed
+    );
+    assert_eq!(
+        require_vetoable(&env, &ProposalStatus::Queued, 0, Some(101)),
+        Ok(())
+    );
+    assert_eq!(
+        require_vetoable(&env, &ProposalStatus::Queued, 0, Some(100)),
+        closed
+    );
+    assert_eq!(
+        require_vetoable(&env, &ProposalStatus::Executed, 200, None),
+        closed
+    );
+}
+
+#[test]
+fn test_log_page_bounds() {
+    assert_eq!(log_page(1, 10, 3), 1..4);
+    assert_eq!(log_page(0, 2, 3), 1..3);
+    assert_eq!(log_page(3, 10, 3), 3..4);
+    assert!(log_page(4, 10, 3).is_empty());
+    assert!(log_page(1, 0, 3).is_empty());
+    assert_eq!(log_page(1, 500, 1000), 1..1 + MAX_LOG_PAGE_SIZE as u64);
+}
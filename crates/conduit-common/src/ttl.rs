@@ -0,0 +1,28 @@
+//! # Conduit Common — `ttl.rs`
+//!
+//! Ledger TTL policy shared by all contracts. Instance storage is bumped at the
+//! start of every entrypoint; persistent entries are bumped on read and write.
+
+use soroban_sdk::{Env, IntoVal, Val};
+
+/// Bump by ~30 days (assuming ~5 seconds per ledger).
+pub const LEDGER_BUMP_AMOUNT: u32 = 518_400;
+/// Bump if remaining life is less than ~15 days.
+pub const LEDGER_BUMP_THRESHOLD: u32 = 259_200;
+
+/// Extend the TTL of the contract instance and its instance storage.
+pub fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+}
+
+/// Extend the TTL of a persistent storage entry.
+pub fn extend_persistent_ttl<K>(env: &Env, key: &K)
+where
+    K: IntoVal<Env, Val>,
+{
+    env.storage()
+        .persistent()
+        .extend_ttl(key, LEDGER_BUMP_THRESHOLD, LEDGER_BUMP_AMOUNT);
+}